@@ -0,0 +1,213 @@
+//! Rendering of the constructors and setters of the method structs and of the
+//! objects sent with them: `new` takes the required fields, each optional
+//! field gets a chainable setter.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::limits::Limit;
+
+/// Objects and unions whose constructors and conversions are hand-written in
+/// the passport module, where they check the element types.
+const HAND_WRITTEN: &[&str] = &[
+    "SetPassportDataErrors",
+    "PassportElementError",
+    "PassportElementErrorDataField",
+    "PassportElementErrorFrontSide",
+    "PassportElementErrorReverseSide",
+    "PassportElementErrorSelfie",
+    "PassportElementErrorFile",
+    "PassportElementErrorFiles",
+    "PassportElementErrorTranslationFile",
+    "PassportElementErrorTranslationFiles",
+    "PassportElementErrorUnspecified",
+];
+
+/// A struct as rendered into `src/models.rs`.
+pub(crate) struct Struct {
+    pub name: String,
+    pub is_object: bool,
+    pub fields: Vec<Member>,
+}
+
+/// A field of a rendered struct.
+pub(crate) struct Member {
+    pub name: String,
+    pub ident: String,
+    /// Rust type of the field, without the `Option` of the optional fields.
+    pub ty: String,
+    pub optional: bool,
+    /// Types of the spec the field holds, arrays unwrapped.
+    pub bases: Vec<String>,
+    /// Whether the field is documented as `True`, the only value it can have.
+    pub always_true: bool,
+    pub limits: Vec<Limit>,
+    /// Whether the limits apply to the text once its markup is parsed.
+    pub after_parsing: bool,
+}
+
+/// A union as rendered into `src/models.rs`, with its tag field and the value of
+/// the tag for each subtype.
+pub(crate) struct Union {
+    pub name: String,
+    pub subtypes: Vec<String>,
+    pub tag: Option<(String, HashMap<String, Option<String>>)>,
+}
+
+pub(crate) fn render(structs: &[Struct], unions: &[Union], polymorphs: &[(String, Vec<String>)]) -> String {
+    let sent = sent(structs, unions);
+    let convertible: HashSet<&str> = polymorphs
+        .iter()
+        .map(|(name, _)| &name[..])
+        .chain(unions.iter().filter(|union| sent.contains(&union.name[..])).map(|union| &union.name[..]))
+        .filter(|name| !HAND_WRITTEN.contains(name))
+        // Converts from the `Message` it points to.
+        .chain(vec!["MessageTarget", "MessageTarget<i64>"])
+        .collect();
+    let tags: HashMap<&str, (&str, &str)> = unions
+        .iter()
+        .filter_map(|union| union.tag.as_ref())
+        .flat_map(|(field, values)| {
+            values.iter().filter_map(move |(ty, value)| value.as_ref().map(|value| (&ty[..], (&field[..], &value[..]))))
+        })
+        .collect();
+
+    let mut items = Vec::new();
+    for (name, bases) in polymorphs {
+        for base in bases {
+            let ty = match &base[..] {
+                "Integer" => "i64",
+                "String" => "String",
+                _ => base,
+            };
+            items.push(render_from(name, base, ty));
+            if ty == "String" {
+                items.push(format!(
+                    "impl<'a> From<&'a str> for {} {{\n    fn from(value: &'a str) -> Self {{\n        {}::String(value.to_string())\n    }}\n}}",
+                    name, name
+                ));
+            }
+        }
+    }
+    for union in unions {
+        if convertible.contains(&union.name[..]) {
+            items.extend(union.subtypes.iter().map(|ty| render_from(&union.name, ty, ty)));
+        }
+    }
+    for item in structs {
+        if HAND_WRITTEN.contains(&&item.name[..]) || (item.is_object && !sent.contains(&item.name[..])) {
+            continue;
+        }
+        items.push(render_builder(item, tags.get(&item.name[..]).copied(), &convertible));
+    }
+    items.join("\n\n")
+}
+
+/// Names of the objects and unions that method parameters hold, directly or
+/// through other objects.
+pub(crate) fn sent<'s>(structs: &'s [Struct], unions: &'s [Union]) -> HashSet<&'s str> {
+    let bases = |item: &'s Struct| item.fields.iter().flat_map(|field| field.bases.iter().map(|base| &base[..]));
+    let mut queue: Vec<&str> = structs.iter().filter(|item| !item.is_object).flat_map(bases).collect();
+    let mut sent = HashSet::new();
+    while let Some(name) = queue.pop() {
+        if !sent.insert(name) {
+            continue;
+        }
+        if let Some(union) = unions.iter().find(|union| union.name == name) {
+            queue.extend(union.subtypes.iter().map(|ty| &ty[..]));
+        }
+        if let Some(item) = structs.iter().find(|item| item.is_object && item.name == name) {
+            queue.extend(bases(item));
+        }
+    }
+    sent
+}
+
+fn render_from(name: &str, variant: &str, ty: &str) -> String {
+    format!(
+        "impl From<{}> for {} {{\n    fn from(value: {}) -> Self {{\n        {}::{}(value)\n    }}\n}}",
+        ty, name, ty, name, variant
+    )
+}
+
+/// `new` and the setters of a struct. The tag field of a union subtype and
+/// the fields that can only be `True` are filled in by `new`.
+fn render_builder(item: &Struct, tag: Option<(&str, &str)>, convertible: &HashSet<&str>) -> String {
+    let mut params = Vec::new();
+    let mut inits = Vec::new();
+    for field in &item.fields {
+        let constant = tag.filter(|&(name, _)| name == field.name && field.ty == "String");
+        let value = if field.optional {
+            "None".to_string()
+        } else if let Some((_, constant)) = constant {
+            format!("\"{}\".to_string()", constant)
+        } else if field.always_true {
+            "true".to_string()
+        } else {
+            let (ty, into) = param(&field.ty, convertible);
+            params.push(format!("{}: {}", field.ident, ty));
+            if into {
+                format!("{}.into()", field.ident)
+            } else {
+                field.ident.clone()
+            }
+        };
+        inits.push(if value == field.ident {
+            format!("            {},", value)
+        } else {
+            format!("            {}: {},", field.ident, value)
+        });
+    }
+    if item.is_object {
+        inits.push("            extra: Default::default(),".to_string());
+    }
+
+    let mut lines = vec![format!("impl {} {{", item.name)];
+    lines.push(if item.is_object {
+        "    /// Creates the object from its required fields.".to_string()
+    } else {
+        "    /// Creates the request from its required parameters.".to_string()
+    });
+    if params.len() > 7 {
+        lines.push("    #[allow(clippy::too_many_arguments)]".to_string());
+    }
+    lines.push(format!("    pub fn new({}) -> Self {{", params.join(", ")));
+    lines.push(format!("        {} {{", item.name));
+    lines.extend(inits);
+    lines.push("        }".to_string());
+    lines.push("    }".to_string());
+    for field in item.fields.iter().filter(|field| field.optional) {
+        let (ty, into) = param(&field.ty, convertible);
+        lines.push(String::new());
+        lines.push(format!("    /// Sets `{}`.", field.name));
+        lines.push(format!("    pub fn {}(mut self, {}: {}) -> Self {{", field.ident, field.ident, ty));
+        lines.push(format!(
+            "        self.{} = Some({}{});",
+            field.ident,
+            field.ident,
+            if into { ".into()" } else { "" }
+        ));
+        lines.push("        self".to_string());
+        lines.push("    }".to_string());
+    }
+    lines.push("}".to_string());
+
+    if params.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("impl Default for {} {{", item.name));
+        lines.push("    fn default() -> Self {".to_string());
+        lines.push(format!("        {}::new()", item.name));
+        lines.push("    }".to_string());
+        lines.push("}".to_string());
+    }
+    lines.join("\n")
+}
+
+/// Type of the parameter setting a field of type `ty`, and whether it has to
+/// be converted into `ty`.
+fn param(ty: &str, convertible: &HashSet<&str>) -> (String, bool) {
+    if ty == "String" || convertible.contains(ty) {
+        (format!("impl Into<{}>", ty), true)
+    } else {
+        (ty.to_string(), false)
+    }
+}
@@ -0,0 +1,21 @@
+//! Generates `src/models.rs`, `src/method.rs`, `src/builders.rs` and
+//! `src/limits.rs` of `tg_bot_models` from the Bot API spec snapshot in
+//! `api/botapi.json`.
+//!
+//! Updating to a new API version means updating the snapshot and running
+//! `cargo run -p codegen`; `cargo run -p codegen -- --check` fails when the
+//! checked-in files differ from what the snapshot generates.
+
+#[macro_use]
+extern crate serde_derive;
+extern crate serde;
+extern crate serde_json;
+
+mod builders;
+mod emit;
+mod limits;
+mod spec;
+mod wrap;
+
+pub use emit::{generate, Output};
+pub use spec::Spec;
@@ -0,0 +1,242 @@
+//! Limits of the fields, as the documentation words them (“1-4096 characters”,
+//! “must include 2-10 items”, “Must be between 1 and 360”), and rendering of the
+//! `Validate` impls checking them.
+
+use std::collections::HashSet;
+
+use crate::builders::{Member, Struct, Union};
+use crate::spec::Field;
+
+/// Limits documented in the description of the method rather than of the
+/// field.
+const METHOD_LIMITS: &[(&str, &str, Limit)] = &[("AnswerInlineQuery", "results", Limit::Items(0, 50))];
+
+/// Methods with checks written by hand, run after those of their fields.
+const CHECKED_BY_HAND: &[(&str, &str)] = &[("SendInvoice", "payments::check_invoice")];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Limit {
+    Characters(u64, u64),
+    Bytes(u64, u64),
+    Items(u64, u64),
+    Range(u64, u64),
+    /// Only A-Z, a-z, 0-9, “_” and “-”.
+    SafeCharacters,
+}
+
+/// The limits of `field` of `owner`.
+pub(crate) fn limits(owner: &str, field: &Field) -> Vec<Limit> {
+    let text = &field.description[..];
+    let mut limits = Vec::new();
+    if field.types.len() == 1 && field.types[0].starts_with("Array of ") {
+        let items = ranges(text).first().map(|&(min, max, _)| (min, max)).or_else(|| {
+            let max = number_after(text, "up to ").or_else(|| number_after(text, "At most "))?;
+            Some((0, max))
+        });
+        limits.extend(items.map(|(min, max)| Limit::Items(min, max)));
+    } else if field.types == ["Integer"] || field.types == ["Float"] {
+        // “0x7FFFFFFF” lifts the limit of the live periods.
+        if !text.contains("0x7FFFFFFF") {
+            let range = ranges(text).first().map(|&(min, max, _)| (min, max)).or_else(|| {
+                let rest = text.split("between ").nth(1)?;
+                Some((leading_number(rest)?, number_after(rest, " and ")?))
+            });
+            limits.extend(range.map(|(min, max)| Limit::Range(min, max)));
+        }
+    } else if field.types == ["String"] {
+        for (min, max, unit) in ranges(text) {
+            match unit {
+                "characters" => limits.push(Limit::Characters(min, max)),
+                "bytes" => limits.push(Limit::Bytes(min, max)),
+                _ => {}
+            }
+        }
+        if let Some(max) = number_after(text, "can't exceed ") {
+            limits.push(Limit::Bytes(0, max));
+        }
+        if text.contains("only A-Z, a-z, 0-9") || text.contains("Only characters A-Z, a-z, 0-9") {
+            limits.push(Limit::SafeCharacters);
+        }
+    }
+    limits.extend(METHOD_LIMITS.iter().filter(|&&(o, f, _)| o == owner && f == field.name).map(|&(_, _, limit)| limit));
+    limits
+}
+
+/// The “min-max unit” ranges of a description, `min` not above `max`.
+fn ranges(text: &str) -> Vec<(u64, u64, &str)> {
+    let mut ranges = Vec::new();
+    for (i, _) in text.match_indices('-') {
+        let before = &text[..i];
+        let digits = before.len() - before.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        let (min, after) = (&before[before.len() - digits..], &text[i + 1..]);
+        let (min, max) = match (min.parse::<u64>(), leading_number(after)) {
+            (Ok(min), Some(max)) if min <= max => (min, max),
+            _ => continue,
+        };
+        let unit = after.trim_start_matches(|c: char| c.is_ascii_digit()).trim_start();
+        let unit = unit.split(|c: char| !c.is_ascii_alphabetic()).next().unwrap_or("");
+        ranges.push((min, max, unit));
+    }
+    ranges
+}
+
+fn leading_number(text: &str) -> Option<u64> {
+    let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+fn number_after(text: &str, prefix: &str) -> Option<u64> {
+    leading_number(text.split(prefix).nth(1)?)
+}
+
+/// `Validate` impls for every method struct and for the sent objects, unions
+/// and `Polymorph*` enums that have something to check.
+pub(crate) fn render(
+    structs: &[Struct],
+    unions: &[Union],
+    polymorphs: &[(String, Vec<String>)],
+    sent: &HashSet<&str>,
+) -> String {
+    let validated = validated(structs, unions, polymorphs, sent);
+    let mut items = Vec::new();
+    for (name, bases) in polymorphs {
+        if validated.contains(&name[..]) {
+            items.push(render_match(name, bases, &validated, false));
+        }
+    }
+    for union in unions {
+        if validated.contains(&union.name[..]) {
+            items.push(render_match(&union.name, &union.subtypes, &validated, true));
+        }
+    }
+    for item in structs {
+        if !item.is_object || validated.contains(&item.name[..]) {
+            items.push(render_struct(item, &validated));
+        }
+    }
+    items.join("\n\n")
+}
+
+/// Names of the sent types with limits of their own or holding such types.
+fn validated<'s>(
+    structs: &'s [Struct],
+    unions: &'s [Union],
+    polymorphs: &'s [(String, Vec<String>)],
+    sent: &HashSet<&str>,
+) -> HashSet<&'s str> {
+    let mut validated: HashSet<&str> = HashSet::new();
+    loop {
+        let before = validated.len();
+        for item in structs.iter().filter(|item| item.is_object && sent.contains(&item.name[..])) {
+            if item.fields.iter().any(|field| !field.limits.is_empty() || holds(field, &validated)) {
+                validated.insert(&item.name);
+            }
+        }
+        for union in unions.iter().filter(|union| sent.contains(&union.name[..])) {
+            if union.subtypes.iter().any(|ty| validated.contains(&ty[..])) {
+                validated.insert(&union.name);
+            }
+        }
+        for (name, bases) in polymorphs {
+            if bases.iter().any(|base| validated.contains(&base[..])) {
+                validated.insert(name);
+            }
+        }
+        if validated.len() == before {
+            return validated;
+        }
+    }
+}
+
+fn holds(field: &Member, validated: &HashSet<&str>) -> bool {
+    field.bases.iter().any(|base| validated.contains(&base[..]))
+}
+
+fn render_match(name: &str, variants: &[String], validated: &HashSet<&str>, unknown: bool) -> String {
+    let mut lines = vec![
+        format!("impl Validate for {} {{", name),
+        "    fn validate(&self) -> Result<(), ValidationError> {".to_string(),
+        "        match self {".to_string(),
+    ];
+    for variant in variants {
+        if validated.contains(&variant[..]) {
+            lines.push(format!("            {}::{}(value) => value.validate(),", name, variant));
+        } else {
+            lines.push(format!("            {}::{}(_) => Ok(()),", name, variant));
+        }
+    }
+    if unknown {
+        lines.push(format!("            {}::Unknown(_) => Ok(()),", name));
+    }
+    lines.push("        }".to_string());
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn render_struct(item: &Struct, validated: &HashSet<&str>) -> String {
+    let mut body = Vec::new();
+    for field in &item.fields {
+        let ident = &field.ident;
+        let by_value = field.ty == "i64" || field.ty == "f64";
+        let value = match (field.optional, by_value) {
+            (true, _) => ident.clone(),
+            (false, true) => format!("self.{}", ident),
+            (false, false) => format!("&self.{}", ident),
+        };
+        let checks: Vec<String> = field.limits.iter().map(|limit| check(&field.name, &value, *limit)).collect();
+        if !checks.is_empty() {
+            // The limits “after entities parsing” can't be checked on text with markup.
+            let parse_mode = item.fields.iter().map(|f| &f.name[..]).find(|&name| {
+                field.after_parsing && (name == format!("{}_parse_mode", field.name) || name == "parse_mode")
+            });
+            let mut indent = "        ";
+            let condition = match (field.optional, parse_mode) {
+                (true, Some(parse_mode)) => {
+                    Some(format!("if let (Some({}), None) = (&self.{}, &self.{}) {{", ident, ident, parse_mode))
+                }
+                (true, None) if by_value => Some(format!("if let Some({}) = self.{} {{", ident, ident)),
+                (true, None) => Some(format!("if let Some({}) = &self.{} {{", ident, ident)),
+                (false, Some(parse_mode)) => Some(format!("if self.{}.is_none() {{", parse_mode)),
+                (false, None) => None,
+            };
+            if let Some(condition) = &condition {
+                body.push(format!("        {}", condition));
+                indent = "            ";
+            }
+            body.extend(checks.iter().map(|check| format!("{}{}?;", indent, check)));
+            if condition.is_some() {
+                body.push("        }".to_string());
+            }
+        }
+        if holds(field, validated) {
+            body.push(format!("        validate::nested(\"{}\", &self.{})?;", field.name, ident));
+        }
+    }
+    let hand = CHECKED_BY_HAND.iter().find(|&&(name, _)| name == item.name);
+    if body.is_empty() && hand.is_none() {
+        return format!("impl Validate for {} {{}}", item.name);
+    }
+    let mut lines = vec![
+        format!("impl Validate for {} {{", item.name),
+        "    fn validate(&self) -> Result<(), ValidationError> {".to_string(),
+    ];
+    lines.extend(body);
+    lines.push(match hand {
+        Some((_, check)) => format!("        {}(self)", check),
+        None => "        Ok(())".to_string(),
+    });
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn check(field: &str, value: &str, limit: Limit) -> String {
+    match limit {
+        Limit::Characters(min, max) => format!("validate::characters(\"{}\", {}, {}, {})", field, value, min, max),
+        Limit::Bytes(min, max) => format!("validate::bytes(\"{}\", {}, {}, {})", field, value, min, max),
+        Limit::Items(min, max) => format!("validate::items(\"{}\", {}, {}, {})", field, value, min, max),
+        Limit::Range(min, max) => format!("validate::range(\"{}\", {}, {}, {})", field, value, min, max),
+        Limit::SafeCharacters => format!("validate::safe_characters(\"{}\", {})", field, value),
+    }
+}
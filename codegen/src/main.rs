@@ -0,0 +1,30 @@
+extern crate codegen;
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use codegen::Spec;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let check = env::args().skip(1).any(|arg| arg == "--check");
+    let spec = Spec::load(&root.join("api/botapi.json"))?;
+
+    let mut stale = false;
+    for (path, contents) in codegen::generate(&spec).files() {
+        let path = root.join(path);
+        if !check {
+            fs::write(&path, contents)?;
+        } else if fs::read_to_string(&path).ok().as_deref() != Some(contents) {
+            eprintln!("{} is out of date, run `cargo run -p codegen`", path.display());
+            stale = true;
+        }
+    }
+    if stale {
+        process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Deserialization of the Bot API spec snapshot (`api/botapi.json`).
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// The whole spec: objects and methods in the order of the documentation.
+#[derive(Debug, Deserialize)]
+pub struct Spec {
+    pub version: String,
+    pub release_date: String,
+    pub types: Vec<Type>,
+    pub methods: Vec<Method>,
+}
+
+/// An object of the API. Objects listing `subtypes` are unions of those
+/// objects and have no fields of their own.
+#[derive(Debug, Deserialize)]
+pub struct Type {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    #[serde(default)]
+    pub subtypes: Vec<String>,
+}
+
+/// A method of the API, named as in the request URL (e.g. `sendMessage`).
+#[derive(Debug, Deserialize)]
+pub struct Method {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    pub returns: Vec<String>,
+}
+
+/// A field of an object or a parameter of a method. `types` holds the
+/// alternatives of a “A or B” type, each possibly prefixed with “Array of ”.
+#[derive(Debug, Deserialize)]
+pub struct Field {
+    pub name: String,
+    pub types: Vec<String>,
+    /// Only set for method parameters, object fields are optional when their
+    /// description starts with “Optional.”.
+    pub required: Option<bool>,
+    pub description: String,
+}
+
+impl Spec {
+    pub fn load(path: &Path) -> Result<Spec, Box<dyn Error>> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+impl Field {
+    pub fn is_optional(&self) -> bool {
+        match self.required {
+            Some(required) => !required,
+            None => self.description.starts_with("Optional."),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! A port of Python's `textwrap.wrap`, which produced the doc comments of the
+//! original models. Keeping its exact line breaks (including the ones after
+//! hyphens) means that regenerating from an unchanged spec changes nothing.
+
+const WHITESPACE: &[char] = &['\t', '\n', '\x0b', '\x0c', '\r', ' '];
+
+/// Wraps `text` into lines of at most `width` characters.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let text: Vec<char> = text.chars().map(|c| if WHITESPACE.contains(&c) { ' ' } else { c }).collect();
+    let mut chunks = split(&text);
+    chunks.reverse();
+
+    let mut lines = Vec::new();
+    while !chunks.is_empty() {
+        let mut line: Vec<Vec<char>> = Vec::new();
+        let mut len = 0;
+
+        if !lines.is_empty() && is_blank(&chunks[chunks.len() - 1]) {
+            chunks.pop();
+        }
+        while let Some(chunk) = chunks.last() {
+            if len + chunk.len() > width {
+                break;
+            }
+            len += chunk.len();
+            line.push(chunks.pop().unwrap());
+        }
+        if chunks.last().is_some_and(|chunk| chunk.len() > width) {
+            break_long_word(&mut chunks, &mut line, len, width);
+        }
+        if line.last().is_some_and(|chunk| is_blank(chunk)) {
+            line.pop();
+        }
+        if !line.is_empty() {
+            lines.push(line.concat().into_iter().collect());
+        }
+    }
+    lines
+}
+
+/// Splits text into runs of whitespace and words, where words containing
+/// hyphens are further split after the hyphens that separate letters.
+fn split(text: &[char]) -> Vec<Vec<char>> {
+    let mut chunks = Vec::new();
+    let mut i = 0;
+    while i < text.len() {
+        let start = i;
+        if is_space(text[i]) {
+            while i < text.len() && is_space(text[i]) {
+                i += 1;
+            }
+        } else {
+            i += 1;
+            while i < text.len() && !is_space(text[i]) {
+                i += 1;
+                if text[i - 1] == '-' && breaks_at(text, i - 1) {
+                    break;
+                }
+            }
+        }
+        chunks.push(text[start..i].to_vec());
+    }
+    chunks
+}
+
+/// Whether the hyphen at `i` ends a chunk: it must follow two letters (or a
+/// letter, a hyphen and a letter) and precede a letter, an optional hyphen and
+/// a letter.
+fn breaks_at(text: &[char], i: usize) -> bool {
+    let letter = |j: usize| text.get(j).is_some_and(|&c| is_letter(c));
+    let hyphen = |j: usize| text.get(j) == Some(&'-');
+    let before = (i >= 2 && letter(i - 2) && letter(i - 1)) || (i >= 3 && letter(i - 3) && hyphen(i - 2) && letter(i - 1));
+    let after = letter(i + 1) && (letter(i + 2) || (hyphen(i + 2) && letter(i + 3)));
+    before && after
+}
+
+fn break_long_word(chunks: &mut [Vec<char>], line: &mut Vec<Vec<char>>, len: usize, width: usize) {
+    let space_left = if width < 1 { 1 } else { width - len };
+    let chunk = chunks.last_mut().unwrap();
+    let mut end = space_left;
+    if chunk.len() > space_left {
+        if let Some(hyphen) = chunk[..space_left].iter().rposition(|&c| c == '-') {
+            if hyphen > 0 && chunk[..hyphen].iter().any(|&c| c != '-') {
+                end = hyphen + 1;
+            }
+        }
+    }
+    let rest = chunk.split_off(end);
+    line.push(std::mem::replace(chunk, rest));
+}
+
+fn is_space(c: char) -> bool {
+    WHITESPACE.contains(&c)
+}
+
+fn is_blank(chunk: &[char]) -> bool {
+    chunk.iter().all(|c| c.is_whitespace())
+}
+
+fn is_letter(c: char) -> bool {
+    (c.is_alphanumeric() && !c.is_numeric()) || c == '_'
+}
@@ -0,0 +1,16 @@
+extern crate codegen;
+
+use std::fs;
+use std::path::Path;
+
+use codegen::Spec;
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let spec = Spec::load(&root.join("api/botapi.json")).unwrap();
+    for (path, contents) in codegen::generate(&spec).files() {
+        let current = fs::read_to_string(root.join(path)).unwrap();
+        assert!(current == contents, "{} is out of date, run `cargo run -p codegen`", path);
+    }
+}
@@ -0,0 +1,7322 @@
+// Generated by `cargo run -p codegen` from `api/botapi.json` (Bot API 7.10,
+// September 6, 2024), do not edit by hand.
+
+//! Constructors taking the required fields of the requests and of the
+//! objects sent with them, and setters for their optional fields.
+
+use super::*;
+
+impl From<i64> for PolymorphChatId {
+    fn from(value: i64) -> Self {
+        PolymorphChatId::Integer(value)
+    }
+}
+
+impl From<String> for PolymorphChatId {
+    fn from(value: String) -> Self {
+        PolymorphChatId::String(value)
+    }
+}
+
+impl<'a> From<&'a str> for PolymorphChatId {
+    fn from(value: &'a str) -> Self {
+        PolymorphChatId::String(value.to_string())
+    }
+}
+
+impl From<InlineKeyboardMarkup> for PolymorphReplyMarkup {
+    fn from(value: InlineKeyboardMarkup) -> Self {
+        PolymorphReplyMarkup::InlineKeyboardMarkup(value)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for PolymorphReplyMarkup {
+    fn from(value: ReplyKeyboardMarkup) -> Self {
+        PolymorphReplyMarkup::ReplyKeyboardMarkup(value)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for PolymorphReplyMarkup {
+    fn from(value: ReplyKeyboardRemove) -> Self {
+        PolymorphReplyMarkup::ReplyKeyboardRemove(value)
+    }
+}
+
+impl From<ForceReply> for PolymorphReplyMarkup {
+    fn from(value: ForceReply) -> Self {
+        PolymorphReplyMarkup::ForceReply(value)
+    }
+}
+
+impl From<i64> for PolymorphFromChatId {
+    fn from(value: i64) -> Self {
+        PolymorphFromChatId::Integer(value)
+    }
+}
+
+impl From<String> for PolymorphFromChatId {
+    fn from(value: String) -> Self {
+        PolymorphFromChatId::String(value)
+    }
+}
+
+impl<'a> From<&'a str> for PolymorphFromChatId {
+    fn from(value: &'a str) -> Self {
+        PolymorphFromChatId::String(value.to_string())
+    }
+}
+
+impl From<ReactionTypeEmoji> for ReactionType {
+    fn from(value: ReactionTypeEmoji) -> Self {
+        ReactionType::ReactionTypeEmoji(value)
+    }
+}
+
+impl From<ReactionTypeCustomEmoji> for ReactionType {
+    fn from(value: ReactionTypeCustomEmoji) -> Self {
+        ReactionType::ReactionTypeCustomEmoji(value)
+    }
+}
+
+impl From<ReactionTypePaid> for ReactionType {
+    fn from(value: ReactionTypePaid) -> Self {
+        ReactionType::ReactionTypePaid(value)
+    }
+}
+
+impl From<BotCommandScopeDefault> for BotCommandScope {
+    fn from(value: BotCommandScopeDefault) -> Self {
+        BotCommandScope::BotCommandScopeDefault(value)
+    }
+}
+
+impl From<BotCommandScopeAllPrivateChats> for BotCommandScope {
+    fn from(value: BotCommandScopeAllPrivateChats) -> Self {
+        BotCommandScope::BotCommandScopeAllPrivateChats(value)
+    }
+}
+
+impl From<BotCommandScopeAllGroupChats> for BotCommandScope {
+    fn from(value: BotCommandScopeAllGroupChats) -> Self {
+        BotCommandScope::BotCommandScopeAllGroupChats(value)
+    }
+}
+
+impl From<BotCommandScopeAllChatAdministrators> for BotCommandScope {
+    fn from(value: BotCommandScopeAllChatAdministrators) -> Self {
+        BotCommandScope::BotCommandScopeAllChatAdministrators(value)
+    }
+}
+
+impl From<BotCommandScopeChat> for BotCommandScope {
+    fn from(value: BotCommandScopeChat) -> Self {
+        BotCommandScope::BotCommandScopeChat(value)
+    }
+}
+
+impl From<BotCommandScopeChatAdministrators> for BotCommandScope {
+    fn from(value: BotCommandScopeChatAdministrators) -> Self {
+        BotCommandScope::BotCommandScopeChatAdministrators(value)
+    }
+}
+
+impl From<BotCommandScopeChatMember> for BotCommandScope {
+    fn from(value: BotCommandScopeChatMember) -> Self {
+        BotCommandScope::BotCommandScopeChatMember(value)
+    }
+}
+
+impl From<MenuButtonCommands> for MenuButton {
+    fn from(value: MenuButtonCommands) -> Self {
+        MenuButton::MenuButtonCommands(value)
+    }
+}
+
+impl From<MenuButtonWebApp> for MenuButton {
+    fn from(value: MenuButtonWebApp) -> Self {
+        MenuButton::MenuButtonWebApp(value)
+    }
+}
+
+impl From<MenuButtonDefault> for MenuButton {
+    fn from(value: MenuButtonDefault) -> Self {
+        MenuButton::MenuButtonDefault(value)
+    }
+}
+
+impl From<InputMediaAnimation> for InputMedia {
+    fn from(value: InputMediaAnimation) -> Self {
+        InputMedia::InputMediaAnimation(value)
+    }
+}
+
+impl From<InputMediaDocument> for InputMedia {
+    fn from(value: InputMediaDocument) -> Self {
+        InputMedia::InputMediaDocument(value)
+    }
+}
+
+impl From<InputMediaAudio> for InputMedia {
+    fn from(value: InputMediaAudio) -> Self {
+        InputMedia::InputMediaAudio(value)
+    }
+}
+
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(value: InputMediaPhoto) -> Self {
+        InputMedia::InputMediaPhoto(value)
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(value: InputMediaVideo) -> Self {
+        InputMedia::InputMediaVideo(value)
+    }
+}
+
+impl From<InputPaidMediaPhoto> for InputPaidMedia {
+    fn from(value: InputPaidMediaPhoto) -> Self {
+        InputPaidMedia::InputPaidMediaPhoto(value)
+    }
+}
+
+impl From<InputPaidMediaVideo> for InputPaidMedia {
+    fn from(value: InputPaidMediaVideo) -> Self {
+        InputPaidMedia::InputPaidMediaVideo(value)
+    }
+}
+
+impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedAudio) -> Self {
+        InlineQueryResult::InlineQueryResultCachedAudio(value)
+    }
+}
+
+impl From<InlineQueryResultCachedDocument> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedDocument) -> Self {
+        InlineQueryResult::InlineQueryResultCachedDocument(value)
+    }
+}
+
+impl From<InlineQueryResultCachedGif> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedGif) -> Self {
+        InlineQueryResult::InlineQueryResultCachedGif(value)
+    }
+}
+
+impl From<InlineQueryResultCachedMpeg4Gif> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedMpeg4Gif) -> Self {
+        InlineQueryResult::InlineQueryResultCachedMpeg4Gif(value)
+    }
+}
+
+impl From<InlineQueryResultCachedPhoto> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedPhoto) -> Self {
+        InlineQueryResult::InlineQueryResultCachedPhoto(value)
+    }
+}
+
+impl From<InlineQueryResultCachedSticker> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedSticker) -> Self {
+        InlineQueryResult::InlineQueryResultCachedSticker(value)
+    }
+}
+
+impl From<InlineQueryResultCachedVideo> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedVideo) -> Self {
+        InlineQueryResult::InlineQueryResultCachedVideo(value)
+    }
+}
+
+impl From<InlineQueryResultCachedVoice> for InlineQueryResult {
+    fn from(value: InlineQueryResultCachedVoice) -> Self {
+        InlineQueryResult::InlineQueryResultCachedVoice(value)
+    }
+}
+
+impl From<InlineQueryResultArticle> for InlineQueryResult {
+    fn from(value: InlineQueryResultArticle) -> Self {
+        InlineQueryResult::InlineQueryResultArticle(value)
+    }
+}
+
+impl From<InlineQueryResultAudio> for InlineQueryResult {
+    fn from(value: InlineQueryResultAudio) -> Self {
+        InlineQueryResult::InlineQueryResultAudio(value)
+    }
+}
+
+impl From<InlineQueryResultContact> for InlineQueryResult {
+    fn from(value: InlineQueryResultContact) -> Self {
+        InlineQueryResult::InlineQueryResultContact(value)
+    }
+}
+
+impl From<InlineQueryResultGame> for InlineQueryResult {
+    fn from(value: InlineQueryResultGame) -> Self {
+        InlineQueryResult::InlineQueryResultGame(value)
+    }
+}
+
+impl From<InlineQueryResultDocument> for InlineQueryResult {
+    fn from(value: InlineQueryResultDocument) -> Self {
+        InlineQueryResult::InlineQueryResultDocument(value)
+    }
+}
+
+impl From<InlineQueryResultGif> for InlineQueryResult {
+    fn from(value: InlineQueryResultGif) -> Self {
+        InlineQueryResult::InlineQueryResultGif(value)
+    }
+}
+
+impl From<InlineQueryResultLocation> for InlineQueryResult {
+    fn from(value: InlineQueryResultLocation) -> Self {
+        InlineQueryResult::InlineQueryResultLocation(value)
+    }
+}
+
+impl From<InlineQueryResultMpeg4Gif> for InlineQueryResult {
+    fn from(value: InlineQueryResultMpeg4Gif) -> Self {
+        InlineQueryResult::InlineQueryResultMpeg4Gif(value)
+    }
+}
+
+impl From<InlineQueryResultPhoto> for InlineQueryResult {
+    fn from(value: InlineQueryResultPhoto) -> Self {
+        InlineQueryResult::InlineQueryResultPhoto(value)
+    }
+}
+
+impl From<InlineQueryResultVenue> for InlineQueryResult {
+    fn from(value: InlineQueryResultVenue) -> Self {
+        InlineQueryResult::InlineQueryResultVenue(value)
+    }
+}
+
+impl From<InlineQueryResultVideo> for InlineQueryResult {
+    fn from(value: InlineQueryResultVideo) -> Self {
+        InlineQueryResult::InlineQueryResultVideo(value)
+    }
+}
+
+impl From<InlineQueryResultVoice> for InlineQueryResult {
+    fn from(value: InlineQueryResultVoice) -> Self {
+        InlineQueryResult::InlineQueryResultVoice(value)
+    }
+}
+
+impl From<InputTextMessageContent> for InputMessageContent {
+    fn from(value: InputTextMessageContent) -> Self {
+        InputMessageContent::InputTextMessageContent(value)
+    }
+}
+
+impl From<InputLocationMessageContent> for InputMessageContent {
+    fn from(value: InputLocationMessageContent) -> Self {
+        InputMessageContent::InputLocationMessageContent(value)
+    }
+}
+
+impl From<InputVenueMessageContent> for InputMessageContent {
+    fn from(value: InputVenueMessageContent) -> Self {
+        InputMessageContent::InputVenueMessageContent(value)
+    }
+}
+
+impl From<InputContactMessageContent> for InputMessageContent {
+    fn from(value: InputContactMessageContent) -> Self {
+        InputMessageContent::InputContactMessageContent(value)
+    }
+}
+
+impl From<InputInvoiceMessageContent> for InputMessageContent {
+    fn from(value: InputInvoiceMessageContent) -> Self {
+        InputMessageContent::InputInvoiceMessageContent(value)
+    }
+}
+
+impl User {
+    /// Creates the object from its required fields.
+    pub fn new(id: i64, is_bot: bool, first_name: impl Into<String>) -> Self {
+        User {
+            id,
+            is_bot,
+            first_name: first_name.into(),
+            last_name: None,
+            username: None,
+            language_code: None,
+            is_premium: None,
+            added_to_attachment_menu: None,
+            can_join_groups: None,
+            can_read_all_group_messages: None,
+            supports_inline_queries: None,
+            can_connect_to_business: None,
+            has_main_web_app: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `last_name`.
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets `username`.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Sets `is_premium`.
+    pub fn is_premium(mut self, is_premium: bool) -> Self {
+        self.is_premium = Some(is_premium);
+        self
+    }
+
+    /// Sets `added_to_attachment_menu`.
+    pub fn added_to_attachment_menu(mut self, added_to_attachment_menu: bool) -> Self {
+        self.added_to_attachment_menu = Some(added_to_attachment_menu);
+        self
+    }
+
+    /// Sets `can_join_groups`.
+    pub fn can_join_groups(mut self, can_join_groups: bool) -> Self {
+        self.can_join_groups = Some(can_join_groups);
+        self
+    }
+
+    /// Sets `can_read_all_group_messages`.
+    pub fn can_read_all_group_messages(mut self, can_read_all_group_messages: bool) -> Self {
+        self.can_read_all_group_messages = Some(can_read_all_group_messages);
+        self
+    }
+
+    /// Sets `supports_inline_queries`.
+    pub fn supports_inline_queries(mut self, supports_inline_queries: bool) -> Self {
+        self.supports_inline_queries = Some(supports_inline_queries);
+        self
+    }
+
+    /// Sets `can_connect_to_business`.
+    pub fn can_connect_to_business(mut self, can_connect_to_business: bool) -> Self {
+        self.can_connect_to_business = Some(can_connect_to_business);
+        self
+    }
+
+    /// Sets `has_main_web_app`.
+    pub fn has_main_web_app(mut self, has_main_web_app: bool) -> Self {
+        self.has_main_web_app = Some(has_main_web_app);
+        self
+    }
+}
+
+impl MessageEntity {
+    /// Creates the object from its required fields.
+    pub fn new(ty: MessageEntityType, offset: i64, length: i64) -> Self {
+        MessageEntity {
+            ty,
+            offset,
+            length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `url`.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets `user`.
+    pub fn user(mut self, user: User) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sets `language`.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets `custom_emoji_id`.
+    pub fn custom_emoji_id(mut self, custom_emoji_id: impl Into<String>) -> Self {
+        self.custom_emoji_id = Some(custom_emoji_id.into());
+        self
+    }
+}
+
+impl ReplyParameters {
+    /// Creates the object from its required fields.
+    pub fn new(message_id: i64) -> Self {
+        ReplyParameters {
+            message_id,
+            chat_id: None,
+            allow_sending_without_reply: None,
+            quote: None,
+            quote_parse_mode: None,
+            quote_entities: None,
+            quote_position: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `chat_id`.
+    pub fn chat_id(mut self, chat_id: impl Into<PolymorphChatId>) -> Self {
+        self.chat_id = Some(chat_id.into());
+        self
+    }
+
+    /// Sets `allow_sending_without_reply`.
+    pub fn allow_sending_without_reply(mut self, allow_sending_without_reply: bool) -> Self {
+        self.allow_sending_without_reply = Some(allow_sending_without_reply);
+        self
+    }
+
+    /// Sets `quote`.
+    pub fn quote(mut self, quote: impl Into<String>) -> Self {
+        self.quote = Some(quote.into());
+        self
+    }
+
+    /// Sets `quote_parse_mode`.
+    pub fn quote_parse_mode(mut self, quote_parse_mode: impl Into<String>) -> Self {
+        self.quote_parse_mode = Some(quote_parse_mode.into());
+        self
+    }
+
+    /// Sets `quote_entities`.
+    pub fn quote_entities(mut self, quote_entities: Vec<MessageEntity>) -> Self {
+        self.quote_entities = Some(quote_entities);
+        self
+    }
+
+    /// Sets `quote_position`.
+    pub fn quote_position(mut self, quote_position: i64) -> Self {
+        self.quote_position = Some(quote_position);
+        self
+    }
+}
+
+impl InputPollOption {
+    /// Creates the object from its required fields.
+    pub fn new(text: impl Into<String>) -> Self {
+        InputPollOption {
+            text: text.into(),
+            text_parse_mode: None,
+            text_entities: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `text_parse_mode`.
+    pub fn text_parse_mode(mut self, text_parse_mode: impl Into<String>) -> Self {
+        self.text_parse_mode = Some(text_parse_mode.into());
+        self
+    }
+
+    /// Sets `text_entities`.
+    pub fn text_entities(mut self, text_entities: Vec<MessageEntity>) -> Self {
+        self.text_entities = Some(text_entities);
+        self
+    }
+}
+
+impl LinkPreviewOptions {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        LinkPreviewOptions {
+            is_disabled: None,
+            url: None,
+            prefer_small_media: None,
+            prefer_large_media: None,
+            show_above_text: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `is_disabled`.
+    pub fn is_disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = Some(is_disabled);
+        self
+    }
+
+    /// Sets `url`.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets `prefer_small_media`.
+    pub fn prefer_small_media(mut self, prefer_small_media: bool) -> Self {
+        self.prefer_small_media = Some(prefer_small_media);
+        self
+    }
+
+    /// Sets `prefer_large_media`.
+    pub fn prefer_large_media(mut self, prefer_large_media: bool) -> Self {
+        self.prefer_large_media = Some(prefer_large_media);
+        self
+    }
+
+    /// Sets `show_above_text`.
+    pub fn show_above_text(mut self, show_above_text: bool) -> Self {
+        self.show_above_text = Some(show_above_text);
+        self
+    }
+}
+
+impl Default for LinkPreviewOptions {
+    fn default() -> Self {
+        LinkPreviewOptions::new()
+    }
+}
+
+impl WebAppInfo {
+    /// Creates the object from its required fields.
+    pub fn new(url: impl Into<String>) -> Self {
+        WebAppInfo {
+            url: url.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl ReplyKeyboardMarkup {
+    /// Creates the object from its required fields.
+    pub fn new(keyboard: Vec<Vec<KeyboardButton>>) -> Self {
+        ReplyKeyboardMarkup {
+            keyboard,
+            is_persistent: None,
+            resize_keyboard: None,
+            one_time_keyboard: None,
+            input_field_placeholder: None,
+            selective: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `is_persistent`.
+    pub fn is_persistent(mut self, is_persistent: bool) -> Self {
+        self.is_persistent = Some(is_persistent);
+        self
+    }
+
+    /// Sets `resize_keyboard`.
+    pub fn resize_keyboard(mut self, resize_keyboard: bool) -> Self {
+        self.resize_keyboard = Some(resize_keyboard);
+        self
+    }
+
+    /// Sets `one_time_keyboard`.
+    pub fn one_time_keyboard(mut self, one_time_keyboard: bool) -> Self {
+        self.one_time_keyboard = Some(one_time_keyboard);
+        self
+    }
+
+    /// Sets `input_field_placeholder`.
+    pub fn input_field_placeholder(mut self, input_field_placeholder: impl Into<String>) -> Self {
+        self.input_field_placeholder = Some(input_field_placeholder.into());
+        self
+    }
+
+    /// Sets `selective`.
+    pub fn selective(mut self, selective: bool) -> Self {
+        self.selective = Some(selective);
+        self
+    }
+}
+
+impl KeyboardButton {
+    /// Creates the object from its required fields.
+    pub fn new(text: impl Into<String>) -> Self {
+        KeyboardButton {
+            text: text.into(),
+            request_users: None,
+            request_chat: None,
+            request_contact: None,
+            request_location: None,
+            request_poll: None,
+            web_app: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `request_users`.
+    pub fn request_users(mut self, request_users: KeyboardButtonRequestUsers) -> Self {
+        self.request_users = Some(request_users);
+        self
+    }
+
+    /// Sets `request_chat`.
+    pub fn request_chat(mut self, request_chat: KeyboardButtonRequestChat) -> Self {
+        self.request_chat = Some(request_chat);
+        self
+    }
+
+    /// Sets `request_contact`.
+    pub fn request_contact(mut self, request_contact: bool) -> Self {
+        self.request_contact = Some(request_contact);
+        self
+    }
+
+    /// Sets `request_location`.
+    pub fn request_location(mut self, request_location: bool) -> Self {
+        self.request_location = Some(request_location);
+        self
+    }
+
+    /// Sets `request_poll`.
+    pub fn request_poll(mut self, request_poll: KeyboardButtonPollType) -> Self {
+        self.request_poll = Some(request_poll);
+        self
+    }
+
+    /// Sets `web_app`.
+    pub fn web_app(mut self, web_app: WebAppInfo) -> Self {
+        self.web_app = Some(web_app);
+        self
+    }
+}
+
+impl KeyboardButtonRequestUsers {
+    /// Creates the object from its required fields.
+    pub fn new(request_id: i64) -> Self {
+        KeyboardButtonRequestUsers {
+            request_id,
+            user_is_bot: None,
+            user_is_premium: None,
+            max_quantity: None,
+            request_name: None,
+            request_username: None,
+            request_photo: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `user_is_bot`.
+    pub fn user_is_bot(mut self, user_is_bot: bool) -> Self {
+        self.user_is_bot = Some(user_is_bot);
+        self
+    }
+
+    /// Sets `user_is_premium`.
+    pub fn user_is_premium(mut self, user_is_premium: bool) -> Self {
+        self.user_is_premium = Some(user_is_premium);
+        self
+    }
+
+    /// Sets `max_quantity`.
+    pub fn max_quantity(mut self, max_quantity: i64) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    /// Sets `request_name`.
+    pub fn request_name(mut self, request_name: bool) -> Self {
+        self.request_name = Some(request_name);
+        self
+    }
+
+    /// Sets `request_username`.
+    pub fn request_username(mut self, request_username: bool) -> Self {
+        self.request_username = Some(request_username);
+        self
+    }
+
+    /// Sets `request_photo`.
+    pub fn request_photo(mut self, request_photo: bool) -> Self {
+        self.request_photo = Some(request_photo);
+        self
+    }
+}
+
+impl KeyboardButtonRequestChat {
+    /// Creates the object from its required fields.
+    pub fn new(request_id: i64, chat_is_channel: bool) -> Self {
+        KeyboardButtonRequestChat {
+            request_id,
+            chat_is_channel,
+            chat_is_forum: None,
+            chat_has_username: None,
+            chat_is_created: None,
+            user_administrator_rights: None,
+            bot_administrator_rights: None,
+            bot_is_member: None,
+            request_title: None,
+            request_username: None,
+            request_photo: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `chat_is_forum`.
+    pub fn chat_is_forum(mut self, chat_is_forum: bool) -> Self {
+        self.chat_is_forum = Some(chat_is_forum);
+        self
+    }
+
+    /// Sets `chat_has_username`.
+    pub fn chat_has_username(mut self, chat_has_username: bool) -> Self {
+        self.chat_has_username = Some(chat_has_username);
+        self
+    }
+
+    /// Sets `chat_is_created`.
+    pub fn chat_is_created(mut self, chat_is_created: bool) -> Self {
+        self.chat_is_created = Some(chat_is_created);
+        self
+    }
+
+    /// Sets `user_administrator_rights`.
+    pub fn user_administrator_rights(mut self, user_administrator_rights: ChatAdministratorRights) -> Self {
+        self.user_administrator_rights = Some(user_administrator_rights);
+        self
+    }
+
+    /// Sets `bot_administrator_rights`.
+    pub fn bot_administrator_rights(mut self, bot_administrator_rights: ChatAdministratorRights) -> Self {
+        self.bot_administrator_rights = Some(bot_administrator_rights);
+        self
+    }
+
+    /// Sets `bot_is_member`.
+    pub fn bot_is_member(mut self, bot_is_member: bool) -> Self {
+        self.bot_is_member = Some(bot_is_member);
+        self
+    }
+
+    /// Sets `request_title`.
+    pub fn request_title(mut self, request_title: bool) -> Self {
+        self.request_title = Some(request_title);
+        self
+    }
+
+    /// Sets `request_username`.
+    pub fn request_username(mut self, request_username: bool) -> Self {
+        self.request_username = Some(request_username);
+        self
+    }
+
+    /// Sets `request_photo`.
+    pub fn request_photo(mut self, request_photo: bool) -> Self {
+        self.request_photo = Some(request_photo);
+        self
+    }
+}
+
+impl KeyboardButtonPollType {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        KeyboardButtonPollType {
+            ty: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `type`.
+    pub fn ty(mut self, ty: impl Into<String>) -> Self {
+        self.ty = Some(ty.into());
+        self
+    }
+}
+
+impl Default for KeyboardButtonPollType {
+    fn default() -> Self {
+        KeyboardButtonPollType::new()
+    }
+}
+
+impl ReplyKeyboardRemove {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        ReplyKeyboardRemove {
+            remove_keyboard: true,
+            selective: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `selective`.
+    pub fn selective(mut self, selective: bool) -> Self {
+        self.selective = Some(selective);
+        self
+    }
+}
+
+impl Default for ReplyKeyboardRemove {
+    fn default() -> Self {
+        ReplyKeyboardRemove::new()
+    }
+}
+
+impl InlineKeyboardMarkup {
+    /// Creates the object from its required fields.
+    pub fn new(inline_keyboard: Vec<Vec<InlineKeyboardButton>>) -> Self {
+        InlineKeyboardMarkup {
+            inline_keyboard,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl InlineKeyboardButton {
+    /// Creates the object from its required fields.
+    pub fn new(text: impl Into<String>) -> Self {
+        InlineKeyboardButton {
+            text: text.into(),
+            url: None,
+            callback_data: None,
+            web_app: None,
+            login_url: None,
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            switch_inline_query_chosen_chat: None,
+            callback_game: None,
+            pay: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `url`.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets `callback_data`.
+    pub fn callback_data(mut self, callback_data: impl Into<String>) -> Self {
+        self.callback_data = Some(callback_data.into());
+        self
+    }
+
+    /// Sets `web_app`.
+    pub fn web_app(mut self, web_app: WebAppInfo) -> Self {
+        self.web_app = Some(web_app);
+        self
+    }
+
+    /// Sets `login_url`.
+    pub fn login_url(mut self, login_url: LoginUrl) -> Self {
+        self.login_url = Some(login_url);
+        self
+    }
+
+    /// Sets `switch_inline_query`.
+    pub fn switch_inline_query(mut self, switch_inline_query: impl Into<String>) -> Self {
+        self.switch_inline_query = Some(switch_inline_query.into());
+        self
+    }
+
+    /// Sets `switch_inline_query_current_chat`.
+    pub fn switch_inline_query_current_chat(mut self, switch_inline_query_current_chat: impl Into<String>) -> Self {
+        self.switch_inline_query_current_chat = Some(switch_inline_query_current_chat.into());
+        self
+    }
+
+    /// Sets `switch_inline_query_chosen_chat`.
+    pub fn switch_inline_query_chosen_chat(mut self, switch_inline_query_chosen_chat: SwitchInlineQueryChosenChat) -> Self {
+        self.switch_inline_query_chosen_chat = Some(switch_inline_query_chosen_chat);
+        self
+    }
+
+    /// Sets `callback_game`.
+    pub fn callback_game(mut self, callback_game: CallbackGame) -> Self {
+        self.callback_game = Some(callback_game);
+        self
+    }
+
+    /// Sets `pay`.
+    pub fn pay(mut self, pay: bool) -> Self {
+        self.pay = Some(pay);
+        self
+    }
+}
+
+impl LoginUrl {
+    /// Creates the object from its required fields.
+    pub fn new(url: impl Into<String>) -> Self {
+        LoginUrl {
+            url: url.into(),
+            forward_text: None,
+            bot_username: None,
+            request_write_access: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `forward_text`.
+    pub fn forward_text(mut self, forward_text: impl Into<String>) -> Self {
+        self.forward_text = Some(forward_text.into());
+        self
+    }
+
+    /// Sets `bot_username`.
+    pub fn bot_username(mut self, bot_username: impl Into<String>) -> Self {
+        self.bot_username = Some(bot_username.into());
+        self
+    }
+
+    /// Sets `request_write_access`.
+    pub fn request_write_access(mut self, request_write_access: bool) -> Self {
+        self.request_write_access = Some(request_write_access);
+        self
+    }
+}
+
+impl SwitchInlineQueryChosenChat {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        SwitchInlineQueryChosenChat {
+            query: None,
+            allow_user_chats: None,
+            allow_bot_chats: None,
+            allow_group_chats: None,
+            allow_channel_chats: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `query`.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Sets `allow_user_chats`.
+    pub fn allow_user_chats(mut self, allow_user_chats: bool) -> Self {
+        self.allow_user_chats = Some(allow_user_chats);
+        self
+    }
+
+    /// Sets `allow_bot_chats`.
+    pub fn allow_bot_chats(mut self, allow_bot_chats: bool) -> Self {
+        self.allow_bot_chats = Some(allow_bot_chats);
+        self
+    }
+
+    /// Sets `allow_group_chats`.
+    pub fn allow_group_chats(mut self, allow_group_chats: bool) -> Self {
+        self.allow_group_chats = Some(allow_group_chats);
+        self
+    }
+
+    /// Sets `allow_channel_chats`.
+    pub fn allow_channel_chats(mut self, allow_channel_chats: bool) -> Self {
+        self.allow_channel_chats = Some(allow_channel_chats);
+        self
+    }
+}
+
+impl Default for SwitchInlineQueryChosenChat {
+    fn default() -> Self {
+        SwitchInlineQueryChosenChat::new()
+    }
+}
+
+impl ForceReply {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        ForceReply {
+            force_reply: true,
+            input_field_placeholder: None,
+            selective: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `input_field_placeholder`.
+    pub fn input_field_placeholder(mut self, input_field_placeholder: impl Into<String>) -> Self {
+        self.input_field_placeholder = Some(input_field_placeholder.into());
+        self
+    }
+
+    /// Sets `selective`.
+    pub fn selective(mut self, selective: bool) -> Self {
+        self.selective = Some(selective);
+        self
+    }
+}
+
+impl Default for ForceReply {
+    fn default() -> Self {
+        ForceReply::new()
+    }
+}
+
+impl ChatAdministratorRights {
+    /// Creates the object from its required fields.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(is_anonymous: bool, can_manage_chat: bool, can_delete_messages: bool, can_manage_video_chats: bool, can_restrict_members: bool, can_promote_members: bool, can_change_info: bool, can_invite_users: bool, can_post_stories: bool, can_edit_stories: bool, can_delete_stories: bool) -> Self {
+        ChatAdministratorRights {
+            is_anonymous,
+            can_manage_chat,
+            can_delete_messages,
+            can_manage_video_chats,
+            can_restrict_members,
+            can_promote_members,
+            can_change_info,
+            can_invite_users,
+            can_post_stories,
+            can_edit_stories,
+            can_delete_stories,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_pin_messages: None,
+            can_manage_topics: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `can_post_messages`.
+    pub fn can_post_messages(mut self, can_post_messages: bool) -> Self {
+        self.can_post_messages = Some(can_post_messages);
+        self
+    }
+
+    /// Sets `can_edit_messages`.
+    pub fn can_edit_messages(mut self, can_edit_messages: bool) -> Self {
+        self.can_edit_messages = Some(can_edit_messages);
+        self
+    }
+
+    /// Sets `can_pin_messages`.
+    pub fn can_pin_messages(mut self, can_pin_messages: bool) -> Self {
+        self.can_pin_messages = Some(can_pin_messages);
+        self
+    }
+
+    /// Sets `can_manage_topics`.
+    pub fn can_manage_topics(mut self, can_manage_topics: bool) -> Self {
+        self.can_manage_topics = Some(can_manage_topics);
+        self
+    }
+}
+
+impl ChatPermissions {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        ChatPermissions {
+            can_send_messages: None,
+            can_send_audios: None,
+            can_send_documents: None,
+            can_send_photos: None,
+            can_send_videos: None,
+            can_send_video_notes: None,
+            can_send_voice_notes: None,
+            can_send_polls: None,
+            can_send_other_messages: None,
+            can_add_web_page_previews: None,
+            can_change_info: None,
+            can_invite_users: None,
+            can_pin_messages: None,
+            can_manage_topics: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `can_send_messages`.
+    pub fn can_send_messages(mut self, can_send_messages: bool) -> Self {
+        self.can_send_messages = Some(can_send_messages);
+        self
+    }
+
+    /// Sets `can_send_audios`.
+    pub fn can_send_audios(mut self, can_send_audios: bool) -> Self {
+        self.can_send_audios = Some(can_send_audios);
+        self
+    }
+
+    /// Sets `can_send_documents`.
+    pub fn can_send_documents(mut self, can_send_documents: bool) -> Self {
+        self.can_send_documents = Some(can_send_documents);
+        self
+    }
+
+    /// Sets `can_send_photos`.
+    pub fn can_send_photos(mut self, can_send_photos: bool) -> Self {
+        self.can_send_photos = Some(can_send_photos);
+        self
+    }
+
+    /// Sets `can_send_videos`.
+    pub fn can_send_videos(mut self, can_send_videos: bool) -> Self {
+        self.can_send_videos = Some(can_send_videos);
+        self
+    }
+
+    /// Sets `can_send_video_notes`.
+    pub fn can_send_video_notes(mut self, can_send_video_notes: bool) -> Self {
+        self.can_send_video_notes = Some(can_send_video_notes);
+        self
+    }
+
+    /// Sets `can_send_voice_notes`.
+    pub fn can_send_voice_notes(mut self, can_send_voice_notes: bool) -> Self {
+        self.can_send_voice_notes = Some(can_send_voice_notes);
+        self
+    }
+
+    /// Sets `can_send_polls`.
+    pub fn can_send_polls(mut self, can_send_polls: bool) -> Self {
+        self.can_send_polls = Some(can_send_polls);
+        self
+    }
+
+    /// Sets `can_send_other_messages`.
+    pub fn can_send_other_messages(mut self, can_send_other_messages: bool) -> Self {
+        self.can_send_other_messages = Some(can_send_other_messages);
+        self
+    }
+
+    /// Sets `can_add_web_page_previews`.
+    pub fn can_add_web_page_previews(mut self, can_add_web_page_previews: bool) -> Self {
+        self.can_add_web_page_previews = Some(can_add_web_page_previews);
+        self
+    }
+
+    /// Sets `can_change_info`.
+    pub fn can_change_info(mut self, can_change_info: bool) -> Self {
+        self.can_change_info = Some(can_change_info);
+        self
+    }
+
+    /// Sets `can_invite_users`.
+    pub fn can_invite_users(mut self, can_invite_users: bool) -> Self {
+        self.can_invite_users = Some(can_invite_users);
+        self
+    }
+
+    /// Sets `can_pin_messages`.
+    pub fn can_pin_messages(mut self, can_pin_messages: bool) -> Self {
+        self.can_pin_messages = Some(can_pin_messages);
+        self
+    }
+
+    /// Sets `can_manage_topics`.
+    pub fn can_manage_topics(mut self, can_manage_topics: bool) -> Self {
+        self.can_manage_topics = Some(can_manage_topics);
+        self
+    }
+}
+
+impl Default for ChatPermissions {
+    fn default() -> Self {
+        ChatPermissions::new()
+    }
+}
+
+impl ReactionTypeEmoji {
+    /// Creates the object from its required fields.
+    pub fn new(emoji: impl Into<String>) -> Self {
+        ReactionTypeEmoji {
+            ty: "emoji".to_string(),
+            emoji: emoji.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl ReactionTypeCustomEmoji {
+    /// Creates the object from its required fields.
+    pub fn new(custom_emoji_id: impl Into<String>) -> Self {
+        ReactionTypeCustomEmoji {
+            ty: "custom_emoji".to_string(),
+            custom_emoji_id: custom_emoji_id.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl ReactionTypePaid {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        ReactionTypePaid {
+            ty: "paid".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for ReactionTypePaid {
+    fn default() -> Self {
+        ReactionTypePaid::new()
+    }
+}
+
+impl BotCommand {
+    /// Creates the object from its required fields.
+    pub fn new(command: impl Into<String>, description: impl Into<String>) -> Self {
+        BotCommand {
+            command: command.into(),
+            description: description.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl BotCommandScopeDefault {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        BotCommandScopeDefault {
+            ty: "default".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for BotCommandScopeDefault {
+    fn default() -> Self {
+        BotCommandScopeDefault::new()
+    }
+}
+
+impl BotCommandScopeAllPrivateChats {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        BotCommandScopeAllPrivateChats {
+            ty: "all_private_chats".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for BotCommandScopeAllPrivateChats {
+    fn default() -> Self {
+        BotCommandScopeAllPrivateChats::new()
+    }
+}
+
+impl BotCommandScopeAllGroupChats {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        BotCommandScopeAllGroupChats {
+            ty: "all_group_chats".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for BotCommandScopeAllGroupChats {
+    fn default() -> Self {
+        BotCommandScopeAllGroupChats::new()
+    }
+}
+
+impl BotCommandScopeAllChatAdministrators {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        BotCommandScopeAllChatAdministrators {
+            ty: "all_chat_administrators".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for BotCommandScopeAllChatAdministrators {
+    fn default() -> Self {
+        BotCommandScopeAllChatAdministrators::new()
+    }
+}
+
+impl BotCommandScopeChat {
+    /// Creates the object from its required fields.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        BotCommandScopeChat {
+            ty: "chat".to_string(),
+            chat_id: chat_id.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl BotCommandScopeChatAdministrators {
+    /// Creates the object from its required fields.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        BotCommandScopeChatAdministrators {
+            ty: "chat_administrators".to_string(),
+            chat_id: chat_id.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl BotCommandScopeChatMember {
+    /// Creates the object from its required fields.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        BotCommandScopeChatMember {
+            ty: "chat_member".to_string(),
+            chat_id: chat_id.into(),
+            user_id,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl MenuButtonCommands {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        MenuButtonCommands {
+            ty: "commands".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for MenuButtonCommands {
+    fn default() -> Self {
+        MenuButtonCommands::new()
+    }
+}
+
+impl MenuButtonWebApp {
+    /// Creates the object from its required fields.
+    pub fn new(text: impl Into<String>, web_app: WebAppInfo) -> Self {
+        MenuButtonWebApp {
+            ty: "web_app".to_string(),
+            text: text.into(),
+            web_app,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl MenuButtonDefault {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        MenuButtonDefault {
+            ty: "default".to_string(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for MenuButtonDefault {
+    fn default() -> Self {
+        MenuButtonDefault::new()
+    }
+}
+
+impl InputMediaPhoto {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputMediaPhoto {
+            ty: "photo".to_string(),
+            media: media.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+}
+
+impl InputMediaVideo {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputMediaVideo {
+            ty: "video".to_string(),
+            media: media.into(),
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: None,
+            has_spoiler: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `width`.
+    pub fn width(mut self, width: i64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets `height`.
+    pub fn height(mut self, height: i64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `supports_streaming`.
+    pub fn supports_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = Some(supports_streaming);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+}
+
+impl InputMediaAnimation {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputMediaAnimation {
+            ty: "animation".to_string(),
+            media: media.into(),
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            width: None,
+            height: None,
+            duration: None,
+            has_spoiler: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `width`.
+    pub fn width(mut self, width: i64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets `height`.
+    pub fn height(mut self, height: i64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+}
+
+impl InputMediaAudio {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputMediaAudio {
+            ty: "audio".to_string(),
+            media: media.into(),
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            duration: None,
+            performer: None,
+            title: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `performer`.
+    pub fn performer(mut self, performer: impl Into<String>) -> Self {
+        self.performer = Some(performer.into());
+        self
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+impl InputMediaDocument {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputMediaDocument {
+            ty: "document".to_string(),
+            media: media.into(),
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_content_type_detection: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `disable_content_type_detection`.
+    pub fn disable_content_type_detection(mut self, disable_content_type_detection: bool) -> Self {
+        self.disable_content_type_detection = Some(disable_content_type_detection);
+        self
+    }
+}
+
+impl InputPaidMediaPhoto {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputPaidMediaPhoto {
+            ty: "photo".to_string(),
+            media: media.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+impl InputPaidMediaVideo {
+    /// Creates the object from its required fields.
+    pub fn new(media: impl Into<String>) -> Self {
+        InputPaidMediaVideo {
+            ty: "video".to_string(),
+            media: media.into(),
+            thumbnail: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `width`.
+    pub fn width(mut self, width: i64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets `height`.
+    pub fn height(mut self, height: i64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `supports_streaming`.
+    pub fn supports_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = Some(supports_streaming);
+        self
+    }
+}
+
+impl MaskPosition {
+    /// Creates the object from its required fields.
+    pub fn new(point: MaskPositionPoint, x_shift: f64, y_shift: f64, scale: f64) -> Self {
+        MaskPosition {
+            point,
+            x_shift,
+            y_shift,
+            scale,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl InputSticker {
+    /// Creates the object from its required fields.
+    pub fn new(sticker: impl Into<String>, format: InputStickerFormat, emoji_list: Vec<String>) -> Self {
+        InputSticker {
+            sticker: sticker.into(),
+            format,
+            emoji_list,
+            mask_position: None,
+            keywords: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `mask_position`.
+    pub fn mask_position(mut self, mask_position: MaskPosition) -> Self {
+        self.mask_position = Some(mask_position);
+        self
+    }
+
+    /// Sets `keywords`.
+    pub fn keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
+}
+
+impl InlineQueryResultsButton {
+    /// Creates the object from its required fields.
+    pub fn new(text: impl Into<String>) -> Self {
+        InlineQueryResultsButton {
+            text: text.into(),
+            web_app: None,
+            start_parameter: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `web_app`.
+    pub fn web_app(mut self, web_app: WebAppInfo) -> Self {
+        self.web_app = Some(web_app);
+        self
+    }
+
+    /// Sets `start_parameter`.
+    pub fn start_parameter(mut self, start_parameter: impl Into<String>) -> Self {
+        self.start_parameter = Some(start_parameter.into());
+        self
+    }
+}
+
+impl InlineQueryResultArticle {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, input_message_content: impl Into<InputMessageContent>) -> Self {
+        InlineQueryResultArticle {
+            ty: "article".to_string(),
+            id: id.into(),
+            title: title.into(),
+            input_message_content: input_message_content.into(),
+            reply_markup: None,
+            url: None,
+            hide_url: None,
+            description: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `url`.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets `hide_url`.
+    pub fn hide_url(mut self, hide_url: bool) -> Self {
+        self.hide_url = Some(hide_url);
+        self
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `thumbnail_url`.
+    pub fn thumbnail_url(mut self, thumbnail_url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(thumbnail_url.into());
+        self
+    }
+
+    /// Sets `thumbnail_width`.
+    pub fn thumbnail_width(mut self, thumbnail_width: i64) -> Self {
+        self.thumbnail_width = Some(thumbnail_width);
+        self
+    }
+
+    /// Sets `thumbnail_height`.
+    pub fn thumbnail_height(mut self, thumbnail_height: i64) -> Self {
+        self.thumbnail_height = Some(thumbnail_height);
+        self
+    }
+}
+
+impl InlineQueryResultPhoto {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, photo_url: impl Into<String>, thumbnail_url: impl Into<String>) -> Self {
+        InlineQueryResultPhoto {
+            ty: "photo".to_string(),
+            id: id.into(),
+            photo_url: photo_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `photo_width`.
+    pub fn photo_width(mut self, photo_width: i64) -> Self {
+        self.photo_width = Some(photo_width);
+        self
+    }
+
+    /// Sets `photo_height`.
+    pub fn photo_height(mut self, photo_height: i64) -> Self {
+        self.photo_height = Some(photo_height);
+        self
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultGif {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, gif_url: impl Into<String>, thumbnail_url: impl Into<String>) -> Self {
+        InlineQueryResultGif {
+            ty: "gif".to_string(),
+            id: id.into(),
+            gif_url: gif_url.into(),
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            thumbnail_url: thumbnail_url.into(),
+            thumbnail_mime_type: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `gif_width`.
+    pub fn gif_width(mut self, gif_width: i64) -> Self {
+        self.gif_width = Some(gif_width);
+        self
+    }
+
+    /// Sets `gif_height`.
+    pub fn gif_height(mut self, gif_height: i64) -> Self {
+        self.gif_height = Some(gif_height);
+        self
+    }
+
+    /// Sets `gif_duration`.
+    pub fn gif_duration(mut self, gif_duration: i64) -> Self {
+        self.gif_duration = Some(gif_duration);
+        self
+    }
+
+    /// Sets `thumbnail_mime_type`.
+    pub fn thumbnail_mime_type(mut self, thumbnail_mime_type: impl Into<String>) -> Self {
+        self.thumbnail_mime_type = Some(thumbnail_mime_type.into());
+        self
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultMpeg4Gif {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, mpeg4_url: impl Into<String>, thumbnail_url: impl Into<String>) -> Self {
+        InlineQueryResultMpeg4Gif {
+            ty: "mpeg".to_string(),
+            id: id.into(),
+            mpeg4_url: mpeg4_url.into(),
+            mpeg4_width: None,
+            mpeg4_height: None,
+            mpeg4_duration: None,
+            thumbnail_url: thumbnail_url.into(),
+            thumbnail_mime_type: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `mpeg4_width`.
+    pub fn mpeg4_width(mut self, mpeg4_width: i64) -> Self {
+        self.mpeg4_width = Some(mpeg4_width);
+        self
+    }
+
+    /// Sets `mpeg4_height`.
+    pub fn mpeg4_height(mut self, mpeg4_height: i64) -> Self {
+        self.mpeg4_height = Some(mpeg4_height);
+        self
+    }
+
+    /// Sets `mpeg4_duration`.
+    pub fn mpeg4_duration(mut self, mpeg4_duration: i64) -> Self {
+        self.mpeg4_duration = Some(mpeg4_duration);
+        self
+    }
+
+    /// Sets `thumbnail_mime_type`.
+    pub fn thumbnail_mime_type(mut self, thumbnail_mime_type: impl Into<String>) -> Self {
+        self.thumbnail_mime_type = Some(thumbnail_mime_type.into());
+        self
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultVideo {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, video_url: impl Into<String>, mime_type: impl Into<String>, thumbnail_url: impl Into<String>, title: impl Into<String>) -> Self {
+        InlineQueryResultVideo {
+            ty: "video".to_string(),
+            id: id.into(),
+            video_url: video_url.into(),
+            mime_type: mime_type.into(),
+            thumbnail_url: thumbnail_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `video_width`.
+    pub fn video_width(mut self, video_width: i64) -> Self {
+        self.video_width = Some(video_width);
+        self
+    }
+
+    /// Sets `video_height`.
+    pub fn video_height(mut self, video_height: i64) -> Self {
+        self.video_height = Some(video_height);
+        self
+    }
+
+    /// Sets `video_duration`.
+    pub fn video_duration(mut self, video_duration: i64) -> Self {
+        self.video_duration = Some(video_duration);
+        self
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultAudio {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, audio_url: impl Into<String>, title: impl Into<String>) -> Self {
+        InlineQueryResultAudio {
+            ty: "audio".to_string(),
+            id: id.into(),
+            audio_url: audio_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            performer: None,
+            audio_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `performer`.
+    pub fn performer(mut self, performer: impl Into<String>) -> Self {
+        self.performer = Some(performer.into());
+        self
+    }
+
+    /// Sets `audio_duration`.
+    pub fn audio_duration(mut self, audio_duration: i64) -> Self {
+        self.audio_duration = Some(audio_duration);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultVoice {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, voice_url: impl Into<String>, title: impl Into<String>) -> Self {
+        InlineQueryResultVoice {
+            ty: "voice".to_string(),
+            id: id.into(),
+            voice_url: voice_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            voice_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `voice_duration`.
+    pub fn voice_duration(mut self, voice_duration: i64) -> Self {
+        self.voice_duration = Some(voice_duration);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultDocument {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, document_url: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        InlineQueryResultDocument {
+            ty: "document".to_string(),
+            id: id.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            document_url: document_url.into(),
+            mime_type: mime_type.into(),
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Sets `thumbnail_url`.
+    pub fn thumbnail_url(mut self, thumbnail_url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(thumbnail_url.into());
+        self
+    }
+
+    /// Sets `thumbnail_width`.
+    pub fn thumbnail_width(mut self, thumbnail_width: i64) -> Self {
+        self.thumbnail_width = Some(thumbnail_width);
+        self
+    }
+
+    /// Sets `thumbnail_height`.
+    pub fn thumbnail_height(mut self, thumbnail_height: i64) -> Self {
+        self.thumbnail_height = Some(thumbnail_height);
+        self
+    }
+}
+
+impl InlineQueryResultLocation {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, latitude: f64, longitude: f64, title: impl Into<String>) -> Self {
+        InlineQueryResultLocation {
+            ty: "location".to_string(),
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `horizontal_accuracy`.
+    pub fn horizontal_accuracy(mut self, horizontal_accuracy: f64) -> Self {
+        self.horizontal_accuracy = Some(horizontal_accuracy);
+        self
+    }
+
+    /// Sets `live_period`.
+    pub fn live_period(mut self, live_period: i64) -> Self {
+        self.live_period = Some(live_period);
+        self
+    }
+
+    /// Sets `heading`.
+    pub fn heading(mut self, heading: i64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Sets `proximity_alert_radius`.
+    pub fn proximity_alert_radius(mut self, proximity_alert_radius: i64) -> Self {
+        self.proximity_alert_radius = Some(proximity_alert_radius);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Sets `thumbnail_url`.
+    pub fn thumbnail_url(mut self, thumbnail_url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(thumbnail_url.into());
+        self
+    }
+
+    /// Sets `thumbnail_width`.
+    pub fn thumbnail_width(mut self, thumbnail_width: i64) -> Self {
+        self.thumbnail_width = Some(thumbnail_width);
+        self
+    }
+
+    /// Sets `thumbnail_height`.
+    pub fn thumbnail_height(mut self, thumbnail_height: i64) -> Self {
+        self.thumbnail_height = Some(thumbnail_height);
+        self
+    }
+}
+
+impl InlineQueryResultVenue {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, latitude: f64, longitude: f64, title: impl Into<String>, address: impl Into<String>) -> Self {
+        InlineQueryResultVenue {
+            ty: "venue".to_string(),
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `foursquare_id`.
+    pub fn foursquare_id(mut self, foursquare_id: impl Into<String>) -> Self {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    /// Sets `foursquare_type`.
+    pub fn foursquare_type(mut self, foursquare_type: impl Into<String>) -> Self {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+
+    /// Sets `google_place_id`.
+    pub fn google_place_id(mut self, google_place_id: impl Into<String>) -> Self {
+        self.google_place_id = Some(google_place_id.into());
+        self
+    }
+
+    /// Sets `google_place_type`.
+    pub fn google_place_type(mut self, google_place_type: impl Into<String>) -> Self {
+        self.google_place_type = Some(google_place_type.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Sets `thumbnail_url`.
+    pub fn thumbnail_url(mut self, thumbnail_url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(thumbnail_url.into());
+        self
+    }
+
+    /// Sets `thumbnail_width`.
+    pub fn thumbnail_width(mut self, thumbnail_width: i64) -> Self {
+        self.thumbnail_width = Some(thumbnail_width);
+        self
+    }
+
+    /// Sets `thumbnail_height`.
+    pub fn thumbnail_height(mut self, thumbnail_height: i64) -> Self {
+        self.thumbnail_height = Some(thumbnail_height);
+        self
+    }
+}
+
+impl InlineQueryResultContact {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, phone_number: impl Into<String>, first_name: impl Into<String>) -> Self {
+        InlineQueryResultContact {
+            ty: "contact".to_string(),
+            id: id.into(),
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `last_name`.
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets `vcard`.
+    pub fn vcard(mut self, vcard: impl Into<String>) -> Self {
+        self.vcard = Some(vcard.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Sets `thumbnail_url`.
+    pub fn thumbnail_url(mut self, thumbnail_url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(thumbnail_url.into());
+        self
+    }
+
+    /// Sets `thumbnail_width`.
+    pub fn thumbnail_width(mut self, thumbnail_width: i64) -> Self {
+        self.thumbnail_width = Some(thumbnail_width);
+        self
+    }
+
+    /// Sets `thumbnail_height`.
+    pub fn thumbnail_height(mut self, thumbnail_height: i64) -> Self {
+        self.thumbnail_height = Some(thumbnail_height);
+        self
+    }
+}
+
+impl InlineQueryResultGame {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, game_short_name: impl Into<String>) -> Self {
+        InlineQueryResultGame {
+            ty: "game".to_string(),
+            id: id.into(),
+            game_short_name: game_short_name.into(),
+            reply_markup: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl InlineQueryResultCachedPhoto {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, photo_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedPhoto {
+            ty: "photo".to_string(),
+            id: id.into(),
+            photo_file_id: photo_file_id.into(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedGif {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, gif_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedGif {
+            ty: "gif".to_string(),
+            id: id.into(),
+            gif_file_id: gif_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedMpeg4Gif {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, mpeg4_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedMpeg4Gif {
+            ty: "mpeg".to_string(),
+            id: id.into(),
+            mpeg4_file_id: mpeg4_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedSticker {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, sticker_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedSticker {
+            ty: "sticker".to_string(),
+            id: id.into(),
+            sticker_file_id: sticker_file_id.into(),
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedDocument {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, document_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedDocument {
+            ty: "document".to_string(),
+            id: id.into(),
+            title: title.into(),
+            document_file_id: document_file_id.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedVideo {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, video_file_id: impl Into<String>, title: impl Into<String>) -> Self {
+        InlineQueryResultCachedVideo {
+            ty: "video".to_string(),
+            id: id.into(),
+            video_file_id: video_file_id.into(),
+            title: title.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedVoice {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, voice_file_id: impl Into<String>, title: impl Into<String>) -> Self {
+        InlineQueryResultCachedVoice {
+            ty: "voice".to_string(),
+            id: id.into(),
+            voice_file_id: voice_file_id.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedAudio {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, audio_file_id: impl Into<String>) -> Self {
+        InlineQueryResultCachedAudio {
+            ty: "audio".to_string(),
+            id: id.into(),
+            audio_file_id: audio_file_id.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+
+    /// Sets `input_message_content`.
+    pub fn input_message_content(mut self, input_message_content: impl Into<InputMessageContent>) -> Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InputTextMessageContent {
+    /// Creates the object from its required fields.
+    pub fn new(message_text: impl Into<String>) -> Self {
+        InputTextMessageContent {
+            message_text: message_text.into(),
+            parse_mode: None,
+            entities: None,
+            link_preview_options: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `entities`.
+    pub fn entities(mut self, entities: Vec<MessageEntity>) -> Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Sets `link_preview_options`.
+    pub fn link_preview_options(mut self, link_preview_options: LinkPreviewOptions) -> Self {
+        self.link_preview_options = Some(link_preview_options);
+        self
+    }
+}
+
+impl InputLocationMessageContent {
+    /// Creates the object from its required fields.
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        InputLocationMessageContent {
+            latitude,
+            longitude,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `horizontal_accuracy`.
+    pub fn horizontal_accuracy(mut self, horizontal_accuracy: f64) -> Self {
+        self.horizontal_accuracy = Some(horizontal_accuracy);
+        self
+    }
+
+    /// Sets `live_period`.
+    pub fn live_period(mut self, live_period: i64) -> Self {
+        self.live_period = Some(live_period);
+        self
+    }
+
+    /// Sets `heading`.
+    pub fn heading(mut self, heading: i64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Sets `proximity_alert_radius`.
+    pub fn proximity_alert_radius(mut self, proximity_alert_radius: i64) -> Self {
+        self.proximity_alert_radius = Some(proximity_alert_radius);
+        self
+    }
+}
+
+impl InputVenueMessageContent {
+    /// Creates the object from its required fields.
+    pub fn new(latitude: f64, longitude: f64, title: impl Into<String>, address: impl Into<String>) -> Self {
+        InputVenueMessageContent {
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `foursquare_id`.
+    pub fn foursquare_id(mut self, foursquare_id: impl Into<String>) -> Self {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    /// Sets `foursquare_type`.
+    pub fn foursquare_type(mut self, foursquare_type: impl Into<String>) -> Self {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+
+    /// Sets `google_place_id`.
+    pub fn google_place_id(mut self, google_place_id: impl Into<String>) -> Self {
+        self.google_place_id = Some(google_place_id.into());
+        self
+    }
+
+    /// Sets `google_place_type`.
+    pub fn google_place_type(mut self, google_place_type: impl Into<String>) -> Self {
+        self.google_place_type = Some(google_place_type.into());
+        self
+    }
+}
+
+impl InputContactMessageContent {
+    /// Creates the object from its required fields.
+    pub fn new(phone_number: impl Into<String>, first_name: impl Into<String>) -> Self {
+        InputContactMessageContent {
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `last_name`.
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets `vcard`.
+    pub fn vcard(mut self, vcard: impl Into<String>) -> Self {
+        self.vcard = Some(vcard.into());
+        self
+    }
+}
+
+impl InputInvoiceMessageContent {
+    /// Creates the object from its required fields.
+    pub fn new(title: impl Into<String>, description: impl Into<String>, payload: impl Into<String>, currency: impl Into<String>, prices: Vec<LabeledPrice>) -> Self {
+        InputInvoiceMessageContent {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: None,
+            need_phone_number: None,
+            need_email: None,
+            need_shipping_address: None,
+            send_phone_number_to_provider: None,
+            send_email_to_provider: None,
+            is_flexible: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// Sets `provider_token`.
+    pub fn provider_token(mut self, provider_token: impl Into<String>) -> Self {
+        self.provider_token = Some(provider_token.into());
+        self
+    }
+
+    /// Sets `max_tip_amount`.
+    pub fn max_tip_amount(mut self, max_tip_amount: i64) -> Self {
+        self.max_tip_amount = Some(max_tip_amount);
+        self
+    }
+
+    /// Sets `suggested_tip_amounts`.
+    pub fn suggested_tip_amounts(mut self, suggested_tip_amounts: Vec<i64>) -> Self {
+        self.suggested_tip_amounts = Some(suggested_tip_amounts);
+        self
+    }
+
+    /// Sets `provider_data`.
+    pub fn provider_data(mut self, provider_data: impl Into<String>) -> Self {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// Sets `photo_url`.
+    pub fn photo_url(mut self, photo_url: impl Into<String>) -> Self {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    /// Sets `photo_size`.
+    pub fn photo_size(mut self, photo_size: i64) -> Self {
+        self.photo_size = Some(photo_size);
+        self
+    }
+
+    /// Sets `photo_width`.
+    pub fn photo_width(mut self, photo_width: i64) -> Self {
+        self.photo_width = Some(photo_width);
+        self
+    }
+
+    /// Sets `photo_height`.
+    pub fn photo_height(mut self, photo_height: i64) -> Self {
+        self.photo_height = Some(photo_height);
+        self
+    }
+
+    /// Sets `need_name`.
+    pub fn need_name(mut self, need_name: bool) -> Self {
+        self.need_name = Some(need_name);
+        self
+    }
+
+    /// Sets `need_phone_number`.
+    pub fn need_phone_number(mut self, need_phone_number: bool) -> Self {
+        self.need_phone_number = Some(need_phone_number);
+        self
+    }
+
+    /// Sets `need_email`.
+    pub fn need_email(mut self, need_email: bool) -> Self {
+        self.need_email = Some(need_email);
+        self
+    }
+
+    /// Sets `need_shipping_address`.
+    pub fn need_shipping_address(mut self, need_shipping_address: bool) -> Self {
+        self.need_shipping_address = Some(need_shipping_address);
+        self
+    }
+
+    /// Sets `send_phone_number_to_provider`.
+    pub fn send_phone_number_to_provider(mut self, send_phone_number_to_provider: bool) -> Self {
+        self.send_phone_number_to_provider = Some(send_phone_number_to_provider);
+        self
+    }
+
+    /// Sets `send_email_to_provider`.
+    pub fn send_email_to_provider(mut self, send_email_to_provider: bool) -> Self {
+        self.send_email_to_provider = Some(send_email_to_provider);
+        self
+    }
+
+    /// Sets `is_flexible`.
+    pub fn is_flexible(mut self, is_flexible: bool) -> Self {
+        self.is_flexible = Some(is_flexible);
+        self
+    }
+}
+
+impl LabeledPrice {
+    /// Creates the object from its required fields.
+    pub fn new(label: impl Into<String>, amount: i64) -> Self {
+        LabeledPrice {
+            label: label.into(),
+            amount,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl ShippingOption {
+    /// Creates the object from its required fields.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, prices: Vec<LabeledPrice>) -> Self {
+        ShippingOption {
+            id: id.into(),
+            title: title.into(),
+            prices,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl CallbackGame {
+    /// Creates the object from its required fields.
+    pub fn new() -> Self {
+        CallbackGame {
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Default for CallbackGame {
+    fn default() -> Self {
+        CallbackGame::new()
+    }
+}
+
+impl GetUpdates {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetUpdates {
+            offset: None,
+            limit: None,
+            timeout: None,
+            allowed_updates: None,
+        }
+    }
+
+    /// Sets `offset`.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets `timeout`.
+    pub fn timeout(mut self, timeout: i64) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets `allowed_updates`.
+    pub fn allowed_updates(mut self, allowed_updates: Vec<String>) -> Self {
+        self.allowed_updates = Some(allowed_updates);
+        self
+    }
+}
+
+impl Default for GetUpdates {
+    fn default() -> Self {
+        GetUpdates::new()
+    }
+}
+
+impl SetWebhook {
+    /// Creates the request from its required parameters.
+    pub fn new(url: impl Into<String>) -> Self {
+        SetWebhook {
+            url: url.into(),
+            certificate: None,
+            ip_address: None,
+            max_connections: None,
+            allowed_updates: None,
+            drop_pending_updates: None,
+            secret_token: None,
+        }
+    }
+
+    /// Sets `certificate`.
+    pub fn certificate(mut self, certificate: impl Into<String>) -> Self {
+        self.certificate = Some(certificate.into());
+        self
+    }
+
+    /// Sets `ip_address`.
+    pub fn ip_address(mut self, ip_address: impl Into<String>) -> Self {
+        self.ip_address = Some(ip_address.into());
+        self
+    }
+
+    /// Sets `max_connections`.
+    pub fn max_connections(mut self, max_connections: i64) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    /// Sets `allowed_updates`.
+    pub fn allowed_updates(mut self, allowed_updates: Vec<String>) -> Self {
+        self.allowed_updates = Some(allowed_updates);
+        self
+    }
+
+    /// Sets `drop_pending_updates`.
+    pub fn drop_pending_updates(mut self, drop_pending_updates: bool) -> Self {
+        self.drop_pending_updates = Some(drop_pending_updates);
+        self
+    }
+
+    /// Sets `secret_token`.
+    pub fn secret_token(mut self, secret_token: impl Into<String>) -> Self {
+        self.secret_token = Some(secret_token.into());
+        self
+    }
+}
+
+impl DeleteWebhook {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        DeleteWebhook {
+            drop_pending_updates: None,
+        }
+    }
+
+    /// Sets `drop_pending_updates`.
+    pub fn drop_pending_updates(mut self, drop_pending_updates: bool) -> Self {
+        self.drop_pending_updates = Some(drop_pending_updates);
+        self
+    }
+}
+
+impl Default for DeleteWebhook {
+    fn default() -> Self {
+        DeleteWebhook::new()
+    }
+}
+
+impl GetWebhookInfo {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetWebhookInfo {
+        }
+    }
+}
+
+impl Default for GetWebhookInfo {
+    fn default() -> Self {
+        GetWebhookInfo::new()
+    }
+}
+
+impl GetMe {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMe {
+        }
+    }
+}
+
+impl Default for GetMe {
+    fn default() -> Self {
+        GetMe::new()
+    }
+}
+
+impl LogOut {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        LogOut {
+        }
+    }
+}
+
+impl Default for LogOut {
+    fn default() -> Self {
+        LogOut::new()
+    }
+}
+
+impl Close {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        Close {
+        }
+    }
+}
+
+impl Default for Close {
+    fn default() -> Self {
+        Close::new()
+    }
+}
+
+impl SendMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, text: impl Into<String>) -> Self {
+        SendMessage {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            text: text.into(),
+            parse_mode: None,
+            entities: None,
+            link_preview_options: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `entities`.
+    pub fn entities(mut self, entities: Vec<MessageEntity>) -> Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Sets `link_preview_options`.
+    pub fn link_preview_options(mut self, link_preview_options: LinkPreviewOptions) -> Self {
+        self.link_preview_options = Some(link_preview_options);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl ForwardMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, from_chat_id: impl Into<PolymorphFromChatId>, message_id: i64) -> Self {
+        ForwardMessage {
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            from_chat_id: from_chat_id.into(),
+            disable_notification: None,
+            protect_content: None,
+            message_id,
+        }
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+}
+
+impl ForwardMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, from_chat_id: impl Into<PolymorphFromChatId>, message_ids: Vec<i64>) -> Self {
+        ForwardMessages {
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            from_chat_id: from_chat_id.into(),
+            message_ids,
+            disable_notification: None,
+            protect_content: None,
+        }
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+}
+
+impl CopyMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, from_chat_id: impl Into<PolymorphFromChatId>, message_id: i64) -> Self {
+        CopyMessage {
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            from_chat_id: from_chat_id.into(),
+            message_id,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            disable_notification: None,
+            protect_content: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl CopyMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, from_chat_id: impl Into<PolymorphFromChatId>, message_ids: Vec<i64>) -> Self {
+        CopyMessages {
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            from_chat_id: from_chat_id.into(),
+            message_ids,
+            disable_notification: None,
+            protect_content: None,
+            remove_caption: None,
+        }
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `remove_caption`.
+    pub fn remove_caption(mut self, remove_caption: bool) -> Self {
+        self.remove_caption = Some(remove_caption);
+        self
+    }
+}
+
+impl SendPhoto {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, photo: impl Into<String>) -> Self {
+        SendPhoto {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            photo: photo.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendAudio {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, audio: impl Into<String>) -> Self {
+        SendAudio {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            audio: audio.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            duration: None,
+            performer: None,
+            title: None,
+            thumbnail: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `performer`.
+    pub fn performer(mut self, performer: impl Into<String>) -> Self {
+        self.performer = Some(performer.into());
+        self
+    }
+
+    /// Sets `title`.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendDocument {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, document: impl Into<String>) -> Self {
+        SendDocument {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            document: document.into(),
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_content_type_detection: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `disable_content_type_detection`.
+    pub fn disable_content_type_detection(mut self, disable_content_type_detection: bool) -> Self {
+        self.disable_content_type_detection = Some(disable_content_type_detection);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendVideo {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, video: impl Into<String>) -> Self {
+        SendVideo {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            video: video.into(),
+            duration: None,
+            width: None,
+            height: None,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            supports_streaming: None,
+            has_spoiler: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `width`.
+    pub fn width(mut self, width: i64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets `height`.
+    pub fn height(mut self, height: i64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `supports_streaming`.
+    pub fn supports_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = Some(supports_streaming);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendAnimation {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, animation: impl Into<String>) -> Self {
+        SendAnimation {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            animation: animation.into(),
+            duration: None,
+            width: None,
+            height: None,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `width`.
+    pub fn width(mut self, width: i64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets `height`.
+    pub fn height(mut self, height: i64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `has_spoiler`.
+    pub fn has_spoiler(mut self, has_spoiler: bool) -> Self {
+        self.has_spoiler = Some(has_spoiler);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendVoice {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, voice: impl Into<String>) -> Self {
+        SendVoice {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            voice: voice.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            duration: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendVideoNote {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, video_note: impl Into<String>) -> Self {
+        SendVideoNote {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            video_note: video_note.into(),
+            duration: None,
+            length: None,
+            thumbnail: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `duration`.
+    pub fn duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets `length`.
+    pub fn length(mut self, length: i64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendPaidMedia {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, star_count: i64, media: Vec<InputPaidMedia>) -> Self {
+        SendPaidMedia {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            star_count,
+            media,
+            payload: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            disable_notification: None,
+            protect_content: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `payload`.
+    pub fn payload(mut self, payload: impl Into<String>) -> Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendMediaGroup {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, media: Vec<InputMedia>) -> Self {
+        SendMediaGroup {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            media,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+}
+
+impl SendLocation {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, latitude: f64, longitude: f64) -> Self {
+        SendLocation {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            latitude,
+            longitude,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `horizontal_accuracy`.
+    pub fn horizontal_accuracy(mut self, horizontal_accuracy: f64) -> Self {
+        self.horizontal_accuracy = Some(horizontal_accuracy);
+        self
+    }
+
+    /// Sets `live_period`.
+    pub fn live_period(mut self, live_period: i64) -> Self {
+        self.live_period = Some(live_period);
+        self
+    }
+
+    /// Sets `heading`.
+    pub fn heading(mut self, heading: i64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Sets `proximity_alert_radius`.
+    pub fn proximity_alert_radius(mut self, proximity_alert_radius: i64) -> Self {
+        self.proximity_alert_radius = Some(proximity_alert_radius);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendVenue {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, latitude: f64, longitude: f64, title: impl Into<String>, address: impl Into<String>) -> Self {
+        SendVenue {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `foursquare_id`.
+    pub fn foursquare_id(mut self, foursquare_id: impl Into<String>) -> Self {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    /// Sets `foursquare_type`.
+    pub fn foursquare_type(mut self, foursquare_type: impl Into<String>) -> Self {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+
+    /// Sets `google_place_id`.
+    pub fn google_place_id(mut self, google_place_id: impl Into<String>) -> Self {
+        self.google_place_id = Some(google_place_id.into());
+        self
+    }
+
+    /// Sets `google_place_type`.
+    pub fn google_place_type(mut self, google_place_type: impl Into<String>) -> Self {
+        self.google_place_type = Some(google_place_type.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendContact {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, phone_number: impl Into<String>, first_name: impl Into<String>) -> Self {
+        SendContact {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `last_name`.
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets `vcard`.
+    pub fn vcard(mut self, vcard: impl Into<String>) -> Self {
+        self.vcard = Some(vcard.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendPoll {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, question: impl Into<String>, options: Vec<InputPollOption>) -> Self {
+        SendPoll {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            question: question.into(),
+            question_parse_mode: None,
+            question_entities: None,
+            options,
+            is_anonymous: None,
+            ty: None,
+            allows_multiple_answers: None,
+            correct_option_id: None,
+            explanation: None,
+            explanation_parse_mode: None,
+            explanation_entities: None,
+            open_period: None,
+            close_date: None,
+            is_closed: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `question_parse_mode`.
+    pub fn question_parse_mode(mut self, question_parse_mode: impl Into<String>) -> Self {
+        self.question_parse_mode = Some(question_parse_mode.into());
+        self
+    }
+
+    /// Sets `question_entities`.
+    pub fn question_entities(mut self, question_entities: Vec<MessageEntity>) -> Self {
+        self.question_entities = Some(question_entities);
+        self
+    }
+
+    /// Sets `is_anonymous`.
+    pub fn is_anonymous(mut self, is_anonymous: bool) -> Self {
+        self.is_anonymous = Some(is_anonymous);
+        self
+    }
+
+    /// Sets `type`.
+    pub fn ty(mut self, ty: PollType) -> Self {
+        self.ty = Some(ty);
+        self
+    }
+
+    /// Sets `allows_multiple_answers`.
+    pub fn allows_multiple_answers(mut self, allows_multiple_answers: bool) -> Self {
+        self.allows_multiple_answers = Some(allows_multiple_answers);
+        self
+    }
+
+    /// Sets `correct_option_id`.
+    pub fn correct_option_id(mut self, correct_option_id: i64) -> Self {
+        self.correct_option_id = Some(correct_option_id);
+        self
+    }
+
+    /// Sets `explanation`.
+    pub fn explanation(mut self, explanation: impl Into<String>) -> Self {
+        self.explanation = Some(explanation.into());
+        self
+    }
+
+    /// Sets `explanation_parse_mode`.
+    pub fn explanation_parse_mode(mut self, explanation_parse_mode: impl Into<String>) -> Self {
+        self.explanation_parse_mode = Some(explanation_parse_mode.into());
+        self
+    }
+
+    /// Sets `explanation_entities`.
+    pub fn explanation_entities(mut self, explanation_entities: Vec<MessageEntity>) -> Self {
+        self.explanation_entities = Some(explanation_entities);
+        self
+    }
+
+    /// Sets `open_period`.
+    pub fn open_period(mut self, open_period: i64) -> Self {
+        self.open_period = Some(open_period);
+        self
+    }
+
+    /// Sets `close_date`.
+    pub fn close_date(mut self, close_date: i64) -> Self {
+        self.close_date = Some(close_date);
+        self
+    }
+
+    /// Sets `is_closed`.
+    pub fn is_closed(mut self, is_closed: bool) -> Self {
+        self.is_closed = Some(is_closed);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendDice {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        SendDice {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            emoji: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `emoji`.
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl SendChatAction {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, action: impl Into<String>) -> Self {
+        SendChatAction {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            action: action.into(),
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+}
+
+impl SetMessageReaction {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_id: i64) -> Self {
+        SetMessageReaction {
+            chat_id: chat_id.into(),
+            message_id,
+            reaction: None,
+            is_big: None,
+        }
+    }
+
+    /// Sets `reaction`.
+    pub fn reaction(mut self, reaction: Vec<ReactionType>) -> Self {
+        self.reaction = Some(reaction);
+        self
+    }
+
+    /// Sets `is_big`.
+    pub fn is_big(mut self, is_big: bool) -> Self {
+        self.is_big = Some(is_big);
+        self
+    }
+}
+
+impl GetUserProfilePhotos {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64) -> Self {
+        GetUserProfilePhotos {
+            user_id,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Sets `offset`.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl GetFile {
+    /// Creates the request from its required parameters.
+    pub fn new(file_id: impl Into<String>) -> Self {
+        GetFile {
+            file_id: file_id.into(),
+        }
+    }
+}
+
+impl BanChatMember {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        BanChatMember {
+            chat_id: chat_id.into(),
+            user_id,
+            until_date: None,
+            revoke_messages: None,
+        }
+    }
+
+    /// Sets `until_date`.
+    pub fn until_date(mut self, until_date: i64) -> Self {
+        self.until_date = Some(until_date);
+        self
+    }
+
+    /// Sets `revoke_messages`.
+    pub fn revoke_messages(mut self, revoke_messages: bool) -> Self {
+        self.revoke_messages = Some(revoke_messages);
+        self
+    }
+}
+
+impl UnbanChatMember {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        UnbanChatMember {
+            chat_id: chat_id.into(),
+            user_id,
+            only_if_banned: None,
+        }
+    }
+
+    /// Sets `only_if_banned`.
+    pub fn only_if_banned(mut self, only_if_banned: bool) -> Self {
+        self.only_if_banned = Some(only_if_banned);
+        self
+    }
+}
+
+impl RestrictChatMember {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64, permissions: ChatPermissions) -> Self {
+        RestrictChatMember {
+            chat_id: chat_id.into(),
+            user_id,
+            permissions,
+            use_independent_chat_permissions: None,
+            until_date: None,
+        }
+    }
+
+    /// Sets `use_independent_chat_permissions`.
+    pub fn use_independent_chat_permissions(mut self, use_independent_chat_permissions: bool) -> Self {
+        self.use_independent_chat_permissions = Some(use_independent_chat_permissions);
+        self
+    }
+
+    /// Sets `until_date`.
+    pub fn until_date(mut self, until_date: i64) -> Self {
+        self.until_date = Some(until_date);
+        self
+    }
+}
+
+impl PromoteChatMember {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        PromoteChatMember {
+            chat_id: chat_id.into(),
+            user_id,
+            is_anonymous: None,
+            can_manage_chat: None,
+            can_delete_messages: None,
+            can_manage_video_chats: None,
+            can_restrict_members: None,
+            can_promote_members: None,
+            can_change_info: None,
+            can_invite_users: None,
+            can_post_stories: None,
+            can_edit_stories: None,
+            can_delete_stories: None,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_pin_messages: None,
+            can_manage_topics: None,
+        }
+    }
+
+    /// Sets `is_anonymous`.
+    pub fn is_anonymous(mut self, is_anonymous: bool) -> Self {
+        self.is_anonymous = Some(is_anonymous);
+        self
+    }
+
+    /// Sets `can_manage_chat`.
+    pub fn can_manage_chat(mut self, can_manage_chat: bool) -> Self {
+        self.can_manage_chat = Some(can_manage_chat);
+        self
+    }
+
+    /// Sets `can_delete_messages`.
+    pub fn can_delete_messages(mut self, can_delete_messages: bool) -> Self {
+        self.can_delete_messages = Some(can_delete_messages);
+        self
+    }
+
+    /// Sets `can_manage_video_chats`.
+    pub fn can_manage_video_chats(mut self, can_manage_video_chats: bool) -> Self {
+        self.can_manage_video_chats = Some(can_manage_video_chats);
+        self
+    }
+
+    /// Sets `can_restrict_members`.
+    pub fn can_restrict_members(mut self, can_restrict_members: bool) -> Self {
+        self.can_restrict_members = Some(can_restrict_members);
+        self
+    }
+
+    /// Sets `can_promote_members`.
+    pub fn can_promote_members(mut self, can_promote_members: bool) -> Self {
+        self.can_promote_members = Some(can_promote_members);
+        self
+    }
+
+    /// Sets `can_change_info`.
+    pub fn can_change_info(mut self, can_change_info: bool) -> Self {
+        self.can_change_info = Some(can_change_info);
+        self
+    }
+
+    /// Sets `can_invite_users`.
+    pub fn can_invite_users(mut self, can_invite_users: bool) -> Self {
+        self.can_invite_users = Some(can_invite_users);
+        self
+    }
+
+    /// Sets `can_post_stories`.
+    pub fn can_post_stories(mut self, can_post_stories: bool) -> Self {
+        self.can_post_stories = Some(can_post_stories);
+        self
+    }
+
+    /// Sets `can_edit_stories`.
+    pub fn can_edit_stories(mut self, can_edit_stories: bool) -> Self {
+        self.can_edit_stories = Some(can_edit_stories);
+        self
+    }
+
+    /// Sets `can_delete_stories`.
+    pub fn can_delete_stories(mut self, can_delete_stories: bool) -> Self {
+        self.can_delete_stories = Some(can_delete_stories);
+        self
+    }
+
+    /// Sets `can_post_messages`.
+    pub fn can_post_messages(mut self, can_post_messages: bool) -> Self {
+        self.can_post_messages = Some(can_post_messages);
+        self
+    }
+
+    /// Sets `can_edit_messages`.
+    pub fn can_edit_messages(mut self, can_edit_messages: bool) -> Self {
+        self.can_edit_messages = Some(can_edit_messages);
+        self
+    }
+
+    /// Sets `can_pin_messages`.
+    pub fn can_pin_messages(mut self, can_pin_messages: bool) -> Self {
+        self.can_pin_messages = Some(can_pin_messages);
+        self
+    }
+
+    /// Sets `can_manage_topics`.
+    pub fn can_manage_topics(mut self, can_manage_topics: bool) -> Self {
+        self.can_manage_topics = Some(can_manage_topics);
+        self
+    }
+}
+
+impl SetChatAdministratorCustomTitle {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64, custom_title: impl Into<String>) -> Self {
+        SetChatAdministratorCustomTitle {
+            chat_id: chat_id.into(),
+            user_id,
+            custom_title: custom_title.into(),
+        }
+    }
+}
+
+impl BanChatSenderChat {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, sender_chat_id: i64) -> Self {
+        BanChatSenderChat {
+            chat_id: chat_id.into(),
+            sender_chat_id,
+        }
+    }
+}
+
+impl UnbanChatSenderChat {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, sender_chat_id: i64) -> Self {
+        UnbanChatSenderChat {
+            chat_id: chat_id.into(),
+            sender_chat_id,
+        }
+    }
+}
+
+impl SetChatPermissions {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, permissions: ChatPermissions) -> Self {
+        SetChatPermissions {
+            chat_id: chat_id.into(),
+            permissions,
+            use_independent_chat_permissions: None,
+        }
+    }
+
+    /// Sets `use_independent_chat_permissions`.
+    pub fn use_independent_chat_permissions(mut self, use_independent_chat_permissions: bool) -> Self {
+        self.use_independent_chat_permissions = Some(use_independent_chat_permissions);
+        self
+    }
+}
+
+impl ExportChatInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        ExportChatInviteLink {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl CreateChatInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        CreateChatInviteLink {
+            chat_id: chat_id.into(),
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: None,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets `expire_date`.
+    pub fn expire_date(mut self, expire_date: i64) -> Self {
+        self.expire_date = Some(expire_date);
+        self
+    }
+
+    /// Sets `member_limit`.
+    pub fn member_limit(mut self, member_limit: i64) -> Self {
+        self.member_limit = Some(member_limit);
+        self
+    }
+
+    /// Sets `creates_join_request`.
+    pub fn creates_join_request(mut self, creates_join_request: bool) -> Self {
+        self.creates_join_request = Some(creates_join_request);
+        self
+    }
+}
+
+impl EditChatInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, invite_link: impl Into<String>) -> Self {
+        EditChatInviteLink {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: None,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets `expire_date`.
+    pub fn expire_date(mut self, expire_date: i64) -> Self {
+        self.expire_date = Some(expire_date);
+        self
+    }
+
+    /// Sets `member_limit`.
+    pub fn member_limit(mut self, member_limit: i64) -> Self {
+        self.member_limit = Some(member_limit);
+        self
+    }
+
+    /// Sets `creates_join_request`.
+    pub fn creates_join_request(mut self, creates_join_request: bool) -> Self {
+        self.creates_join_request = Some(creates_join_request);
+        self
+    }
+}
+
+impl CreateChatSubscriptionInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, subscription_period: i64, subscription_price: i64) -> Self {
+        CreateChatSubscriptionInviteLink {
+            chat_id: chat_id.into(),
+            name: None,
+            subscription_period,
+            subscription_price,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl EditChatSubscriptionInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, invite_link: impl Into<String>) -> Self {
+        EditChatSubscriptionInviteLink {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+            name: None,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl RevokeChatInviteLink {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, invite_link: impl Into<String>) -> Self {
+        RevokeChatInviteLink {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+        }
+    }
+}
+
+impl ApproveChatJoinRequest {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        ApproveChatJoinRequest {
+            chat_id: chat_id.into(),
+            user_id,
+        }
+    }
+}
+
+impl DeclineChatJoinRequest {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        DeclineChatJoinRequest {
+            chat_id: chat_id.into(),
+            user_id,
+        }
+    }
+}
+
+impl SetChatPhoto {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, photo: impl Into<String>) -> Self {
+        SetChatPhoto {
+            chat_id: chat_id.into(),
+            photo: photo.into(),
+        }
+    }
+}
+
+impl DeleteChatPhoto {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        DeleteChatPhoto {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl SetChatTitle {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, title: impl Into<String>) -> Self {
+        SetChatTitle {
+            chat_id: chat_id.into(),
+            title: title.into(),
+        }
+    }
+}
+
+impl SetChatDescription {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        SetChatDescription {
+            chat_id: chat_id.into(),
+            description: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl PinChatMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_id: i64) -> Self {
+        PinChatMessage {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_id,
+            disable_notification: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+}
+
+impl UnpinChatMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        UnpinChatMessage {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_id: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_id`.
+    pub fn message_id(mut self, message_id: i64) -> Self {
+        self.message_id = Some(message_id);
+        self
+    }
+}
+
+impl UnpinAllChatMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        UnpinAllChatMessages {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl LeaveChat {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        LeaveChat {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl GetChat {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        GetChat {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl GetChatAdministrators {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        GetChatAdministrators {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl GetChatMemberCount {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        GetChatMemberCount {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl GetChatMember {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        GetChatMember {
+            chat_id: chat_id.into(),
+            user_id,
+        }
+    }
+}
+
+impl SetChatStickerSet {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, sticker_set_name: impl Into<String>) -> Self {
+        SetChatStickerSet {
+            chat_id: chat_id.into(),
+            sticker_set_name: sticker_set_name.into(),
+        }
+    }
+}
+
+impl DeleteChatStickerSet {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        DeleteChatStickerSet {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl GetForumTopicIconStickers {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetForumTopicIconStickers {
+        }
+    }
+}
+
+impl Default for GetForumTopicIconStickers {
+    fn default() -> Self {
+        GetForumTopicIconStickers::new()
+    }
+}
+
+impl CreateForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, name: impl Into<String>) -> Self {
+        CreateForumTopic {
+            chat_id: chat_id.into(),
+            name: name.into(),
+            icon_color: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+
+    /// Sets `icon_color`.
+    pub fn icon_color(mut self, icon_color: i64) -> Self {
+        self.icon_color = Some(icon_color);
+        self
+    }
+
+    /// Sets `icon_custom_emoji_id`.
+    pub fn icon_custom_emoji_id(mut self, icon_custom_emoji_id: impl Into<String>) -> Self {
+        self.icon_custom_emoji_id = Some(icon_custom_emoji_id.into());
+        self
+    }
+}
+
+impl EditForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_thread_id: i64) -> Self {
+        EditForumTopic {
+            chat_id: chat_id.into(),
+            message_thread_id,
+            name: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets `icon_custom_emoji_id`.
+    pub fn icon_custom_emoji_id(mut self, icon_custom_emoji_id: impl Into<String>) -> Self {
+        self.icon_custom_emoji_id = Some(icon_custom_emoji_id.into());
+        self
+    }
+}
+
+impl CloseForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_thread_id: i64) -> Self {
+        CloseForumTopic {
+            chat_id: chat_id.into(),
+            message_thread_id,
+        }
+    }
+}
+
+impl ReopenForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_thread_id: i64) -> Self {
+        ReopenForumTopic {
+            chat_id: chat_id.into(),
+            message_thread_id,
+        }
+    }
+}
+
+impl DeleteForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_thread_id: i64) -> Self {
+        DeleteForumTopic {
+            chat_id: chat_id.into(),
+            message_thread_id,
+        }
+    }
+}
+
+impl UnpinAllForumTopicMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_thread_id: i64) -> Self {
+        UnpinAllForumTopicMessages {
+            chat_id: chat_id.into(),
+            message_thread_id,
+        }
+    }
+}
+
+impl EditGeneralForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, name: impl Into<String>) -> Self {
+        EditGeneralForumTopic {
+            chat_id: chat_id.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl CloseGeneralForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        CloseGeneralForumTopic {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl ReopenGeneralForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        ReopenGeneralForumTopic {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl HideGeneralForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        HideGeneralForumTopic {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl UnhideGeneralForumTopic {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        UnhideGeneralForumTopic {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl UnpinAllGeneralForumTopicMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>) -> Self {
+        UnpinAllGeneralForumTopicMessages {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl AnswerCallbackQuery {
+    /// Creates the request from its required parameters.
+    pub fn new(callback_query_id: impl Into<String>) -> Self {
+        AnswerCallbackQuery {
+            callback_query_id: callback_query_id.into(),
+            text: None,
+            show_alert: None,
+            url: None,
+            cache_time: None,
+        }
+    }
+
+    /// Sets `text`.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets `show_alert`.
+    pub fn show_alert(mut self, show_alert: bool) -> Self {
+        self.show_alert = Some(show_alert);
+        self
+    }
+
+    /// Sets `url`.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets `cache_time`.
+    pub fn cache_time(mut self, cache_time: i64) -> Self {
+        self.cache_time = Some(cache_time);
+        self
+    }
+}
+
+impl GetUserChatBoosts {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, user_id: i64) -> Self {
+        GetUserChatBoosts {
+            chat_id: chat_id.into(),
+            user_id,
+        }
+    }
+}
+
+impl GetBusinessConnection {
+    /// Creates the request from its required parameters.
+    pub fn new(business_connection_id: impl Into<String>) -> Self {
+        GetBusinessConnection {
+            business_connection_id: business_connection_id.into(),
+        }
+    }
+}
+
+impl SetMyCommands {
+    /// Creates the request from its required parameters.
+    pub fn new(commands: Vec<BotCommand>) -> Self {
+        SetMyCommands {
+            commands,
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `scope`.
+    pub fn scope(mut self, scope: impl Into<BotCommandScope>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl DeleteMyCommands {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        DeleteMyCommands {
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `scope`.
+    pub fn scope(mut self, scope: impl Into<BotCommandScope>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for DeleteMyCommands {
+    fn default() -> Self {
+        DeleteMyCommands::new()
+    }
+}
+
+impl GetMyCommands {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMyCommands {
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `scope`.
+    pub fn scope(mut self, scope: impl Into<BotCommandScope>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for GetMyCommands {
+    fn default() -> Self {
+        GetMyCommands::new()
+    }
+}
+
+impl SetMyName {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        SetMyName {
+            name: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for SetMyName {
+    fn default() -> Self {
+        SetMyName::new()
+    }
+}
+
+impl GetMyName {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMyName {
+            language_code: None,
+        }
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for GetMyName {
+    fn default() -> Self {
+        GetMyName::new()
+    }
+}
+
+impl SetMyDescription {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        SetMyDescription {
+            description: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for SetMyDescription {
+    fn default() -> Self {
+        SetMyDescription::new()
+    }
+}
+
+impl GetMyDescription {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMyDescription {
+            language_code: None,
+        }
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for GetMyDescription {
+    fn default() -> Self {
+        GetMyDescription::new()
+    }
+}
+
+impl SetMyShortDescription {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        SetMyShortDescription {
+            short_description: None,
+            language_code: None,
+        }
+    }
+
+    /// Sets `short_description`.
+    pub fn short_description(mut self, short_description: impl Into<String>) -> Self {
+        self.short_description = Some(short_description.into());
+        self
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for SetMyShortDescription {
+    fn default() -> Self {
+        SetMyShortDescription::new()
+    }
+}
+
+impl GetMyShortDescription {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMyShortDescription {
+            language_code: None,
+        }
+    }
+
+    /// Sets `language_code`.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
+
+impl Default for GetMyShortDescription {
+    fn default() -> Self {
+        GetMyShortDescription::new()
+    }
+}
+
+impl SetChatMenuButton {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        SetChatMenuButton {
+            chat_id: None,
+            menu_button: None,
+        }
+    }
+
+    /// Sets `chat_id`.
+    pub fn chat_id(mut self, chat_id: i64) -> Self {
+        self.chat_id = Some(chat_id);
+        self
+    }
+
+    /// Sets `menu_button`.
+    pub fn menu_button(mut self, menu_button: impl Into<MenuButton>) -> Self {
+        self.menu_button = Some(menu_button.into());
+        self
+    }
+}
+
+impl Default for SetChatMenuButton {
+    fn default() -> Self {
+        SetChatMenuButton::new()
+    }
+}
+
+impl GetChatMenuButton {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetChatMenuButton {
+            chat_id: None,
+        }
+    }
+
+    /// Sets `chat_id`.
+    pub fn chat_id(mut self, chat_id: i64) -> Self {
+        self.chat_id = Some(chat_id);
+        self
+    }
+}
+
+impl Default for GetChatMenuButton {
+    fn default() -> Self {
+        GetChatMenuButton::new()
+    }
+}
+
+impl SetMyDefaultAdministratorRights {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        SetMyDefaultAdministratorRights {
+            rights: None,
+            for_channels: None,
+        }
+    }
+
+    /// Sets `rights`.
+    pub fn rights(mut self, rights: ChatAdministratorRights) -> Self {
+        self.rights = Some(rights);
+        self
+    }
+
+    /// Sets `for_channels`.
+    pub fn for_channels(mut self, for_channels: bool) -> Self {
+        self.for_channels = Some(for_channels);
+        self
+    }
+}
+
+impl Default for SetMyDefaultAdministratorRights {
+    fn default() -> Self {
+        SetMyDefaultAdministratorRights::new()
+    }
+}
+
+impl GetMyDefaultAdministratorRights {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetMyDefaultAdministratorRights {
+            for_channels: None,
+        }
+    }
+
+    /// Sets `for_channels`.
+    pub fn for_channels(mut self, for_channels: bool) -> Self {
+        self.for_channels = Some(for_channels);
+        self
+    }
+}
+
+impl Default for GetMyDefaultAdministratorRights {
+    fn default() -> Self {
+        GetMyDefaultAdministratorRights::new()
+    }
+}
+
+impl EditMessageText {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>, text: impl Into<String>) -> Self {
+        EditMessageText {
+            business_connection_id: None,
+            target: target.into(),
+            text: text.into(),
+            parse_mode: None,
+            entities: None,
+            link_preview_options: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `entities`.
+    pub fn entities(mut self, entities: Vec<MessageEntity>) -> Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Sets `link_preview_options`.
+    pub fn link_preview_options(mut self, link_preview_options: LinkPreviewOptions) -> Self {
+        self.link_preview_options = Some(link_preview_options);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl EditMessageCaption {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>) -> Self {
+        EditMessageCaption {
+            business_connection_id: None,
+            target: target.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `caption`.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets `parse_mode`.
+    pub fn parse_mode(mut self, parse_mode: impl Into<String>) -> Self {
+        self.parse_mode = Some(parse_mode.into());
+        self
+    }
+
+    /// Sets `caption_entities`.
+    pub fn caption_entities(mut self, caption_entities: Vec<MessageEntity>) -> Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Sets `show_caption_above_media`.
+    pub fn show_caption_above_media(mut self, show_caption_above_media: bool) -> Self {
+        self.show_caption_above_media = Some(show_caption_above_media);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl EditMessageMedia {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>, media: impl Into<InputMedia>) -> Self {
+        EditMessageMedia {
+            business_connection_id: None,
+            target: target.into(),
+            media: media.into(),
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl EditMessageLiveLocation {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>, latitude: f64, longitude: f64) -> Self {
+        EditMessageLiveLocation {
+            business_connection_id: None,
+            target: target.into(),
+            latitude,
+            longitude,
+            live_period: None,
+            horizontal_accuracy: None,
+            heading: None,
+            proximity_alert_radius: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `live_period`.
+    pub fn live_period(mut self, live_period: i64) -> Self {
+        self.live_period = Some(live_period);
+        self
+    }
+
+    /// Sets `horizontal_accuracy`.
+    pub fn horizontal_accuracy(mut self, horizontal_accuracy: f64) -> Self {
+        self.horizontal_accuracy = Some(horizontal_accuracy);
+        self
+    }
+
+    /// Sets `heading`.
+    pub fn heading(mut self, heading: i64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Sets `proximity_alert_radius`.
+    pub fn proximity_alert_radius(mut self, proximity_alert_radius: i64) -> Self {
+        self.proximity_alert_radius = Some(proximity_alert_radius);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl StopMessageLiveLocation {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>) -> Self {
+        StopMessageLiveLocation {
+            business_connection_id: None,
+            target: target.into(),
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl EditMessageReplyMarkup {
+    /// Creates the request from its required parameters.
+    pub fn new(target: impl Into<MessageTarget>) -> Self {
+        EditMessageReplyMarkup {
+            business_connection_id: None,
+            target: target.into(),
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl StopPoll {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_id: i64) -> Self {
+        StopPoll {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_id,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl DeleteMessage {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_id: i64) -> Self {
+        DeleteMessage {
+            chat_id: chat_id.into(),
+            message_id,
+        }
+    }
+}
+
+impl DeleteMessages {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, message_ids: Vec<i64>) -> Self {
+        DeleteMessages {
+            chat_id: chat_id.into(),
+            message_ids,
+        }
+    }
+}
+
+impl SendSticker {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, sticker: impl Into<String>) -> Self {
+        SendSticker {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            sticker: sticker.into(),
+            emoji: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `emoji`.
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: impl Into<PolymorphReplyMarkup>) -> Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl GetStickerSet {
+    /// Creates the request from its required parameters.
+    pub fn new(name: impl Into<String>) -> Self {
+        GetStickerSet {
+            name: name.into(),
+        }
+    }
+}
+
+impl GetCustomEmojiStickers {
+    /// Creates the request from its required parameters.
+    pub fn new(custom_emoji_ids: Vec<String>) -> Self {
+        GetCustomEmojiStickers {
+            custom_emoji_ids,
+        }
+    }
+}
+
+impl UploadStickerFile {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, sticker: impl Into<String>, sticker_format: InputStickerFormat) -> Self {
+        UploadStickerFile {
+            user_id,
+            sticker: sticker.into(),
+            sticker_format,
+        }
+    }
+}
+
+impl CreateNewStickerSet {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, name: impl Into<String>, title: impl Into<String>, stickers: Vec<InputSticker>) -> Self {
+        CreateNewStickerSet {
+            user_id,
+            name: name.into(),
+            title: title.into(),
+            stickers,
+            sticker_type: None,
+            needs_repainting: None,
+        }
+    }
+
+    /// Sets `sticker_type`.
+    pub fn sticker_type(mut self, sticker_type: StickerType) -> Self {
+        self.sticker_type = Some(sticker_type);
+        self
+    }
+
+    /// Sets `needs_repainting`.
+    pub fn needs_repainting(mut self, needs_repainting: bool) -> Self {
+        self.needs_repainting = Some(needs_repainting);
+        self
+    }
+}
+
+impl AddStickerToSet {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, name: impl Into<String>, sticker: InputSticker) -> Self {
+        AddStickerToSet {
+            user_id,
+            name: name.into(),
+            sticker,
+        }
+    }
+}
+
+impl SetStickerPositionInSet {
+    /// Creates the request from its required parameters.
+    pub fn new(sticker: impl Into<String>, position: i64) -> Self {
+        SetStickerPositionInSet {
+            sticker: sticker.into(),
+            position,
+        }
+    }
+}
+
+impl DeleteStickerFromSet {
+    /// Creates the request from its required parameters.
+    pub fn new(sticker: impl Into<String>) -> Self {
+        DeleteStickerFromSet {
+            sticker: sticker.into(),
+        }
+    }
+}
+
+impl ReplaceStickerInSet {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, name: impl Into<String>, old_sticker: impl Into<String>, sticker: InputSticker) -> Self {
+        ReplaceStickerInSet {
+            user_id,
+            name: name.into(),
+            old_sticker: old_sticker.into(),
+            sticker,
+        }
+    }
+}
+
+impl SetStickerEmojiList {
+    /// Creates the request from its required parameters.
+    pub fn new(sticker: impl Into<String>, emoji_list: Vec<String>) -> Self {
+        SetStickerEmojiList {
+            sticker: sticker.into(),
+            emoji_list,
+        }
+    }
+}
+
+impl SetStickerKeywords {
+    /// Creates the request from its required parameters.
+    pub fn new(sticker: impl Into<String>) -> Self {
+        SetStickerKeywords {
+            sticker: sticker.into(),
+            keywords: None,
+        }
+    }
+
+    /// Sets `keywords`.
+    pub fn keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
+}
+
+impl SetStickerMaskPosition {
+    /// Creates the request from its required parameters.
+    pub fn new(sticker: impl Into<String>) -> Self {
+        SetStickerMaskPosition {
+            sticker: sticker.into(),
+            mask_position: None,
+        }
+    }
+
+    /// Sets `mask_position`.
+    pub fn mask_position(mut self, mask_position: MaskPosition) -> Self {
+        self.mask_position = Some(mask_position);
+        self
+    }
+}
+
+impl SetStickerSetTitle {
+    /// Creates the request from its required parameters.
+    pub fn new(name: impl Into<String>, title: impl Into<String>) -> Self {
+        SetStickerSetTitle {
+            name: name.into(),
+            title: title.into(),
+        }
+    }
+}
+
+impl SetStickerSetThumbnail {
+    /// Creates the request from its required parameters.
+    pub fn new(name: impl Into<String>, user_id: i64, format: InputStickerFormat) -> Self {
+        SetStickerSetThumbnail {
+            name: name.into(),
+            user_id,
+            thumbnail: None,
+            format,
+        }
+    }
+
+    /// Sets `thumbnail`.
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+}
+
+impl SetCustomEmojiStickerSetThumbnail {
+    /// Creates the request from its required parameters.
+    pub fn new(name: impl Into<String>) -> Self {
+        SetCustomEmojiStickerSetThumbnail {
+            name: name.into(),
+            custom_emoji_id: None,
+        }
+    }
+
+    /// Sets `custom_emoji_id`.
+    pub fn custom_emoji_id(mut self, custom_emoji_id: impl Into<String>) -> Self {
+        self.custom_emoji_id = Some(custom_emoji_id.into());
+        self
+    }
+}
+
+impl DeleteStickerSet {
+    /// Creates the request from its required parameters.
+    pub fn new(name: impl Into<String>) -> Self {
+        DeleteStickerSet {
+            name: name.into(),
+        }
+    }
+}
+
+impl AnswerInlineQuery {
+    /// Creates the request from its required parameters.
+    pub fn new(inline_query_id: impl Into<String>, results: Vec<InlineQueryResult>) -> Self {
+        AnswerInlineQuery {
+            inline_query_id: inline_query_id.into(),
+            results,
+            cache_time: None,
+            is_personal: None,
+            next_offset: None,
+            button: None,
+        }
+    }
+
+    /// Sets `cache_time`.
+    pub fn cache_time(mut self, cache_time: i64) -> Self {
+        self.cache_time = Some(cache_time);
+        self
+    }
+
+    /// Sets `is_personal`.
+    pub fn is_personal(mut self, is_personal: bool) -> Self {
+        self.is_personal = Some(is_personal);
+        self
+    }
+
+    /// Sets `next_offset`.
+    pub fn next_offset(mut self, next_offset: impl Into<String>) -> Self {
+        self.next_offset = Some(next_offset.into());
+        self
+    }
+
+    /// Sets `button`.
+    pub fn button(mut self, button: InlineQueryResultsButton) -> Self {
+        self.button = Some(button);
+        self
+    }
+}
+
+impl AnswerWebAppQuery {
+    /// Creates the request from its required parameters.
+    pub fn new(web_app_query_id: impl Into<String>, result: impl Into<InlineQueryResult>) -> Self {
+        AnswerWebAppQuery {
+            web_app_query_id: web_app_query_id.into(),
+            result: result.into(),
+        }
+    }
+}
+
+impl SendInvoice {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: impl Into<PolymorphChatId>, title: impl Into<String>, description: impl Into<String>, payload: impl Into<String>, currency: impl Into<String>, prices: Vec<LabeledPrice>) -> Self {
+        SendInvoice {
+            chat_id: chat_id.into(),
+            message_thread_id: None,
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            start_parameter: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: None,
+            need_phone_number: None,
+            need_email: None,
+            need_shipping_address: None,
+            send_phone_number_to_provider: None,
+            send_email_to_provider: None,
+            is_flexible: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `provider_token`.
+    pub fn provider_token(mut self, provider_token: impl Into<String>) -> Self {
+        self.provider_token = Some(provider_token.into());
+        self
+    }
+
+    /// Sets `max_tip_amount`.
+    pub fn max_tip_amount(mut self, max_tip_amount: i64) -> Self {
+        self.max_tip_amount = Some(max_tip_amount);
+        self
+    }
+
+    /// Sets `suggested_tip_amounts`.
+    pub fn suggested_tip_amounts(mut self, suggested_tip_amounts: Vec<i64>) -> Self {
+        self.suggested_tip_amounts = Some(suggested_tip_amounts);
+        self
+    }
+
+    /// Sets `start_parameter`.
+    pub fn start_parameter(mut self, start_parameter: impl Into<String>) -> Self {
+        self.start_parameter = Some(start_parameter.into());
+        self
+    }
+
+    /// Sets `provider_data`.
+    pub fn provider_data(mut self, provider_data: impl Into<String>) -> Self {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// Sets `photo_url`.
+    pub fn photo_url(mut self, photo_url: impl Into<String>) -> Self {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    /// Sets `photo_size`.
+    pub fn photo_size(mut self, photo_size: i64) -> Self {
+        self.photo_size = Some(photo_size);
+        self
+    }
+
+    /// Sets `photo_width`.
+    pub fn photo_width(mut self, photo_width: i64) -> Self {
+        self.photo_width = Some(photo_width);
+        self
+    }
+
+    /// Sets `photo_height`.
+    pub fn photo_height(mut self, photo_height: i64) -> Self {
+        self.photo_height = Some(photo_height);
+        self
+    }
+
+    /// Sets `need_name`.
+    pub fn need_name(mut self, need_name: bool) -> Self {
+        self.need_name = Some(need_name);
+        self
+    }
+
+    /// Sets `need_phone_number`.
+    pub fn need_phone_number(mut self, need_phone_number: bool) -> Self {
+        self.need_phone_number = Some(need_phone_number);
+        self
+    }
+
+    /// Sets `need_email`.
+    pub fn need_email(mut self, need_email: bool) -> Self {
+        self.need_email = Some(need_email);
+        self
+    }
+
+    /// Sets `need_shipping_address`.
+    pub fn need_shipping_address(mut self, need_shipping_address: bool) -> Self {
+        self.need_shipping_address = Some(need_shipping_address);
+        self
+    }
+
+    /// Sets `send_phone_number_to_provider`.
+    pub fn send_phone_number_to_provider(mut self, send_phone_number_to_provider: bool) -> Self {
+        self.send_phone_number_to_provider = Some(send_phone_number_to_provider);
+        self
+    }
+
+    /// Sets `send_email_to_provider`.
+    pub fn send_email_to_provider(mut self, send_email_to_provider: bool) -> Self {
+        self.send_email_to_provider = Some(send_email_to_provider);
+        self
+    }
+
+    /// Sets `is_flexible`.
+    pub fn is_flexible(mut self, is_flexible: bool) -> Self {
+        self.is_flexible = Some(is_flexible);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl CreateInvoiceLink {
+    /// Creates the request from its required parameters.
+    pub fn new(title: impl Into<String>, description: impl Into<String>, payload: impl Into<String>, currency: impl Into<String>, prices: Vec<LabeledPrice>) -> Self {
+        CreateInvoiceLink {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: None,
+            need_phone_number: None,
+            need_email: None,
+            need_shipping_address: None,
+            send_phone_number_to_provider: None,
+            send_email_to_provider: None,
+            is_flexible: None,
+        }
+    }
+
+    /// Sets `provider_token`.
+    pub fn provider_token(mut self, provider_token: impl Into<String>) -> Self {
+        self.provider_token = Some(provider_token.into());
+        self
+    }
+
+    /// Sets `max_tip_amount`.
+    pub fn max_tip_amount(mut self, max_tip_amount: i64) -> Self {
+        self.max_tip_amount = Some(max_tip_amount);
+        self
+    }
+
+    /// Sets `suggested_tip_amounts`.
+    pub fn suggested_tip_amounts(mut self, suggested_tip_amounts: Vec<i64>) -> Self {
+        self.suggested_tip_amounts = Some(suggested_tip_amounts);
+        self
+    }
+
+    /// Sets `provider_data`.
+    pub fn provider_data(mut self, provider_data: impl Into<String>) -> Self {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// Sets `photo_url`.
+    pub fn photo_url(mut self, photo_url: impl Into<String>) -> Self {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    /// Sets `photo_size`.
+    pub fn photo_size(mut self, photo_size: i64) -> Self {
+        self.photo_size = Some(photo_size);
+        self
+    }
+
+    /// Sets `photo_width`.
+    pub fn photo_width(mut self, photo_width: i64) -> Self {
+        self.photo_width = Some(photo_width);
+        self
+    }
+
+    /// Sets `photo_height`.
+    pub fn photo_height(mut self, photo_height: i64) -> Self {
+        self.photo_height = Some(photo_height);
+        self
+    }
+
+    /// Sets `need_name`.
+    pub fn need_name(mut self, need_name: bool) -> Self {
+        self.need_name = Some(need_name);
+        self
+    }
+
+    /// Sets `need_phone_number`.
+    pub fn need_phone_number(mut self, need_phone_number: bool) -> Self {
+        self.need_phone_number = Some(need_phone_number);
+        self
+    }
+
+    /// Sets `need_email`.
+    pub fn need_email(mut self, need_email: bool) -> Self {
+        self.need_email = Some(need_email);
+        self
+    }
+
+    /// Sets `need_shipping_address`.
+    pub fn need_shipping_address(mut self, need_shipping_address: bool) -> Self {
+        self.need_shipping_address = Some(need_shipping_address);
+        self
+    }
+
+    /// Sets `send_phone_number_to_provider`.
+    pub fn send_phone_number_to_provider(mut self, send_phone_number_to_provider: bool) -> Self {
+        self.send_phone_number_to_provider = Some(send_phone_number_to_provider);
+        self
+    }
+
+    /// Sets `send_email_to_provider`.
+    pub fn send_email_to_provider(mut self, send_email_to_provider: bool) -> Self {
+        self.send_email_to_provider = Some(send_email_to_provider);
+        self
+    }
+
+    /// Sets `is_flexible`.
+    pub fn is_flexible(mut self, is_flexible: bool) -> Self {
+        self.is_flexible = Some(is_flexible);
+        self
+    }
+}
+
+impl AnswerShippingQuery {
+    /// Creates the request from its required parameters.
+    pub fn new(shipping_query_id: impl Into<String>, ok: bool) -> Self {
+        AnswerShippingQuery {
+            shipping_query_id: shipping_query_id.into(),
+            ok,
+            shipping_options: None,
+            error_message: None,
+        }
+    }
+
+    /// Sets `shipping_options`.
+    pub fn shipping_options(mut self, shipping_options: Vec<ShippingOption>) -> Self {
+        self.shipping_options = Some(shipping_options);
+        self
+    }
+
+    /// Sets `error_message`.
+    pub fn error_message(mut self, error_message: impl Into<String>) -> Self {
+        self.error_message = Some(error_message.into());
+        self
+    }
+}
+
+impl AnswerPreCheckoutQuery {
+    /// Creates the request from its required parameters.
+    pub fn new(pre_checkout_query_id: impl Into<String>, ok: bool) -> Self {
+        AnswerPreCheckoutQuery {
+            pre_checkout_query_id: pre_checkout_query_id.into(),
+            ok,
+            error_message: None,
+        }
+    }
+
+    /// Sets `error_message`.
+    pub fn error_message(mut self, error_message: impl Into<String>) -> Self {
+        self.error_message = Some(error_message.into());
+        self
+    }
+}
+
+impl GetStarTransactions {
+    /// Creates the request from its required parameters.
+    pub fn new() -> Self {
+        GetStarTransactions {
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Sets `offset`.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl Default for GetStarTransactions {
+    fn default() -> Self {
+        GetStarTransactions::new()
+    }
+}
+
+impl RefundStarPayment {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, telegram_payment_charge_id: impl Into<String>) -> Self {
+        RefundStarPayment {
+            user_id,
+            telegram_payment_charge_id: telegram_payment_charge_id.into(),
+        }
+    }
+}
+
+impl SendGame {
+    /// Creates the request from its required parameters.
+    pub fn new(chat_id: i64, game_short_name: impl Into<String>) -> Self {
+        SendGame {
+            business_connection_id: None,
+            chat_id,
+            message_thread_id: None,
+            game_short_name: game_short_name.into(),
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets `business_connection_id`.
+    pub fn business_connection_id(mut self, business_connection_id: impl Into<String>) -> Self {
+        self.business_connection_id = Some(business_connection_id.into());
+        self
+    }
+
+    /// Sets `message_thread_id`.
+    pub fn message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.message_thread_id = Some(message_thread_id);
+        self
+    }
+
+    /// Sets `disable_notification`.
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = Some(disable_notification);
+        self
+    }
+
+    /// Sets `protect_content`.
+    pub fn protect_content(mut self, protect_content: bool) -> Self {
+        self.protect_content = Some(protect_content);
+        self
+    }
+
+    /// Sets `message_effect_id`.
+    pub fn message_effect_id(mut self, message_effect_id: impl Into<String>) -> Self {
+        self.message_effect_id = Some(message_effect_id.into());
+        self
+    }
+
+    /// Sets `reply_parameters`.
+    pub fn reply_parameters(mut self, reply_parameters: ReplyParameters) -> Self {
+        self.reply_parameters = Some(reply_parameters);
+        self
+    }
+
+    /// Sets `reply_markup`.
+    pub fn reply_markup(mut self, reply_markup: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(reply_markup);
+        self
+    }
+}
+
+impl SetGameScore {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, score: i64, target: impl Into<MessageTarget<i64>>) -> Self {
+        SetGameScore {
+            user_id,
+            score,
+            force: None,
+            disable_edit_message: None,
+            target: target.into(),
+        }
+    }
+
+    /// Sets `force`.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+
+    /// Sets `disable_edit_message`.
+    pub fn disable_edit_message(mut self, disable_edit_message: bool) -> Self {
+        self.disable_edit_message = Some(disable_edit_message);
+        self
+    }
+}
+
+impl GetGameHighScores {
+    /// Creates the request from its required parameters.
+    pub fn new(user_id: i64, target: impl Into<MessageTarget<i64>>) -> Self {
+        GetGameHighScores {
+            user_id,
+            target: target.into(),
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! Multi-step dialogs with users.
+//!
+//! A conversation is a `State` per user in a chat, moved on by the messages
+//! and callback queries of the user: each `State::next` gives a `Transition`
+//! to the next state, or to the end of the conversation, with the requests to
+//! send meanwhile, typically the question of the next step sent with
+//! `Message::prompt`. `Conversations` keeps the states in a `Storage`, drops
+//! those left alone for longer than its timeout and ends conversations on
+//! cancellation commands.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::*;
+use crate::ratelimit::{Clock, UnixClock};
+
+
+/// A user in a chat, whose conversation is tracked separately from the
+/// conversations of the other users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Key {
+    pub chat_id: i64,
+    pub user_id: i64,
+}
+
+impl Key {
+    pub fn new(chat_id: i64, user_id: i64) -> Self {
+        Key { chat_id, user_id }
+    }
+
+    /// The key of the user and chat of `update`, if it has both.
+    pub fn of(update: &Update) -> Option<Self> {
+        Some(Key::new(update.chat()?.id, update.user()?.id))
+    }
+}
+
+/// What moves a conversation on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input<'a> {
+    Message(&'a Message),
+    CallbackQuery(&'a CallbackQuery),
+}
+
+impl<'a> Input<'a> {
+    /// The new message or callback query of `update`, if any.
+    pub fn of(update: &'a Update) -> Option<Self> {
+        match (&update.message, &update.callback_query) {
+            (Some(message), _) => Some(Input::Message(message)),
+            (_, Some(query)) => Some(Input::CallbackQuery(query)),
+            _ => None,
+        }
+    }
+
+    /// Text of the message.
+    pub fn text(&self) -> Option<&'a str> {
+        match self {
+            Input::Message(message) => message.text.as_deref(),
+            Input::CallbackQuery(_) => None,
+        }
+    }
+
+    /// Data of the callback query.
+    pub fn data(&self) -> Option<&'a str> {
+        match self {
+            Input::Message(_) => None,
+            Input::CallbackQuery(query) => query.data.as_deref(),
+        }
+    }
+}
+
+/// A step of a conversation.
+pub trait State: Sized + Clone + Serialize + DeserializeOwned {
+    /// Moves on with `input`, from the user the conversation is with.
+    fn next(self, input: Input<'_>) -> Transition<Self>;
+}
+
+/// The state a conversation moves to, and the requests to send.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition<S> {
+    state: Option<S>,
+    requests: Vec<Request>,
+}
+
+impl<S> Transition<S> {
+    /// Moves to `state`.
+    pub fn to(state: S) -> Self {
+        Transition { state: Some(state), requests: Vec::new() }
+    }
+
+    /// Ends the conversation.
+    pub fn end() -> Self {
+        Transition { state: None, requests: Vec::new() }
+    }
+
+    /// Sends `request` too.
+    pub fn send<M: Method>(mut self, request: &M) -> Self {
+        self.requests.push(Request::new(request));
+        self
+    }
+}
+
+/// What became of an update.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Handled {
+    /// The update isn't part of a conversation.
+    Ignored,
+    /// The conversation moved to another state, with the requests to send.
+    Continued(Vec<Request>),
+    /// The conversation ended, with the requests to send.
+    Ended(Vec<Request>),
+    /// The update is a cancellation command, which ended the conversation.
+    Cancelled,
+    /// The conversation timed out before the update came, which isn't part of
+    /// it anymore.
+    TimedOut,
+}
+
+
+/// A saved state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry<S> {
+    pub state: S,
+    /// Time of the last transition, as given by the clock of the conversations
+    pub updated_at: Duration,
+}
+
+/// Where the states of the conversations are kept.
+pub trait Storage<S> {
+    type Error;
+
+    fn load(&self, key: Key) -> Result<Option<Entry<S>>, Self::Error>;
+
+    fn save(&mut self, key: Key, entry: Entry<S>) -> Result<(), Self::Error>;
+
+    fn remove(&mut self, key: Key) -> Result<(), Self::Error>;
+}
+
+/// A storage keeping the states in memory only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryStorage<S> {
+    entries: HashMap<Key, Entry<S>>,
+}
+
+impl<S> MemoryStorage<S> {
+    pub fn new() -> Self {
+        MemoryStorage { entries: HashMap::new() }
+    }
+
+    /// Number of saved states, including those that timed out.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<S> Default for MemoryStorage<S> {
+    fn default() -> Self {
+        MemoryStorage::new()
+    }
+}
+
+impl<S: Clone> Storage<S> for MemoryStorage<S> {
+    type Error = Infallible;
+
+    fn load(&self, key: Key) -> Result<Option<Entry<S>>, Infallible> {
+        Ok(self.entries.get(&key).cloned())
+    }
+
+    fn save(&mut self, key: Key, entry: Entry<S>) -> Result<(), Infallible> {
+        self.entries.insert(key, entry);
+        Ok(())
+    }
+
+    fn remove(&mut self, key: Key) -> Result<(), Infallible> {
+        self.entries.remove(&key);
+        Ok(())
+    }
+}
+
+/// A storage keeping the states in a JSON file, rewritten on every change.
+#[derive(Debug, Clone)]
+pub struct JsonFileStorage<S> {
+    path: PathBuf,
+    memory: MemoryStorage<S>,
+}
+
+/// A saved state, as written to the file.
+#[derive(Serialize, Deserialize)]
+struct Record<S> {
+    #[serde(flatten)]
+    key: Key,
+    #[serde(flatten)]
+    entry: Entry<S>,
+}
+
+impl<S: Serialize + DeserializeOwned> JsonFileStorage<S> {
+    /// The storage in the file at `path`, created on the first change if
+    /// missing.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let records: Vec<Record<S>> = match fs::read(&path) {
+            Ok(json) => serde_json::from_slice(&json).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let entries = records.into_iter().map(|record| (record.key, record.entry)).collect();
+        Ok(JsonFileStorage {
+            path,
+            memory: MemoryStorage { entries },
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the states to a temporary file replacing the file, so that the
+    /// file is never left half written.
+    fn write(&self) -> io::Result<()> {
+        let records: Vec<Record<&S>> = self
+            .memory
+            .entries
+            .iter()
+            .map(|(&key, entry)| Record {
+                key,
+                entry: Entry { state: &entry.state, updated_at: entry.updated_at },
+            })
+            .collect();
+        let json = serde_json::to_vec(&records).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let temporary = format!("{}.tmp", self.path.display());
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, &self.path)
+    }
+}
+
+impl<S: Clone + Serialize + DeserializeOwned> Storage<S> for JsonFileStorage<S> {
+    type Error = io::Error;
+
+    fn load(&self, key: Key) -> io::Result<Option<Entry<S>>> {
+        Ok(self.memory.entries.get(&key).cloned())
+    }
+
+    fn save(&mut self, key: Key, entry: Entry<S>) -> io::Result<()> {
+        self.memory.entries.insert(key, entry);
+        self.write()
+    }
+
+    fn remove(&mut self, key: Key) -> io::Result<()> {
+        if self.memory.entries.remove(&key).is_some() {
+            self.write()?;
+        }
+        Ok(())
+    }
+}
+
+
+/// The conversations of a bot.
+#[derive(Debug)]
+pub struct Conversations<S, St = MemoryStorage<S>, C = UnixClock> {
+    storage: St,
+    clock: C,
+    timeout: Option<Duration>,
+    cancel_commands: Vec<String>,
+    state: PhantomData<S>,
+}
+
+impl<S: State, St: Storage<S>> Conversations<S, St, UnixClock> {
+    /// Conversations kept in `storage`, which never time out and are
+    /// cancelled by the `/cancel` command.
+    pub fn new(storage: St) -> Self {
+        Conversations::with_clock(storage, UnixClock)
+    }
+}
+
+impl<S: State, St: Storage<S>, C: Clock> Conversations<S, St, C> {
+    /// Conversations timed by `clock`, which has to count from the same origin
+    /// as the clock that saved the states in `storage`.
+    pub fn with_clock(storage: St, clock: C) -> Self {
+        Conversations {
+            storage,
+            clock,
+            timeout: None,
+            cancel_commands: vec!["cancel".to_string()],
+            state: PhantomData,
+        }
+    }
+
+    /// Sets the time after which a conversation the user doesn't move on
+    /// ends.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the commands that cancel conversations.
+    pub fn cancel_commands<I: IntoIterator<Item = T>, T: Into<String>>(mut self, commands: I) -> Self {
+        self.cancel_commands = commands.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn storage(&self) -> &St {
+        &self.storage
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Starts a conversation with the user of `key` at `state`, replacing the
+    /// current one if any.
+    pub fn start(&mut self, key: Key, state: S) -> Result<(), St::Error> {
+        let updated_at = self.clock.now();
+        self.storage.save(key, Entry { state, updated_at })
+    }
+
+    /// The current state of the conversation with the user of `key`.
+    pub fn state(&self, key: Key) -> Result<Option<S>, St::Error> {
+        let entry = self.storage.load(key)?;
+        Ok(entry.filter(|entry| !self.timed_out(entry)).map(|entry| entry.state))
+    }
+
+    /// Ends the conversation with the user of `key`. Returns whether there was
+    /// one.
+    pub fn cancel(&mut self, key: Key) -> Result<bool, St::Error> {
+        let ongoing = self.state(key)?.is_some();
+        self.storage.remove(key)?;
+        Ok(ongoing)
+    }
+
+    /// Moves the conversation `update` is part of on.
+    pub fn handle(&mut self, update: &Update) -> Result<Handled, St::Error> {
+        let (key, input) = match (Key::of(update), Input::of(update)) {
+            (Some(key), Some(input)) => (key, input),
+            _ => return Ok(Handled::Ignored),
+        };
+        let entry = match self.storage.load(key)? {
+            Some(entry) => entry,
+            None => return Ok(Handled::Ignored),
+        };
+        if self.timed_out(&entry) {
+            self.storage.remove(key)?;
+            return Ok(Handled::TimedOut);
+        }
+        if let Input::Message(message) = input {
+            let command = message.command();
+            if command.is_some_and(|command| self.cancel_commands.iter().any(|cancel| *cancel == command.name)) {
+                self.storage.remove(key)?;
+                return Ok(Handled::Cancelled);
+            }
+        }
+        let transition = entry.state.next(input);
+        match transition.state {
+            Some(state) => {
+                self.start(key, state)?;
+                Ok(Handled::Continued(transition.requests))
+            }
+            None => {
+                self.storage.remove(key)?;
+                Ok(Handled::Ended(transition.requests))
+            }
+        }
+    }
+
+    fn timed_out(&self, entry: &Entry<S>) -> bool {
+        self.timeout.is_some_and(|timeout| entry.updated_at + timeout <= self.clock.now())
+    }
+}
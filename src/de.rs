@@ -0,0 +1,90 @@
+//! Deserialization of the unions, whose subtypes are told apart by a tag field
+//! or, for the unions without one, by the fields they require.
+
+use serde::de::{DeserializeOwned, Error};
+use serde_json::Value;
+
+/// A subtype of a union: the value of the tag field it's sent with, if it has
+/// one, and the fields it can't do without.
+pub(crate) struct Variant {
+    pub tag: Option<&'static str>,
+    pub required: &'static [&'static str],
+}
+
+/// Index of the subtype `value` is an instance of. Among the subtypes whose
+/// tag matches (or the untagged ones, when none does), the one requiring the
+/// most fields wins, as long as `value` has all of them: the cached and the
+/// uncached inline query results share their tags, for instance.
+pub(crate) fn select(value: &Value, tag: Option<&str>, variants: &[Variant]) -> Option<usize> {
+    let found = tag.and_then(|tag| value.get(tag));
+    let matches = |variant: &Variant| match (variant.tag, found) {
+        (Some(expected), Some(Value::String(found))) => found == expected,
+        (Some(expected), Some(Value::Number(found))) => found.to_string() == expected,
+        _ => false,
+    };
+    let tagged = variants.iter().any(matches);
+
+    let mut best: Option<usize> = None;
+    for (i, variant) in variants.iter().enumerate() {
+        let candidate = if tagged { matches(variant) } else { variant.tag.is_none() };
+        if candidate
+            && variant.required.iter().all(|field| value.get(field).is_some())
+            && best.is_none_or(|best| variant.required.len() > variants[best].required.len())
+        {
+            best = Some(i);
+        }
+    }
+    best
+}
+
+#[cfg(not(feature = "strict"))]
+pub(crate) fn from_value<T: DeserializeOwned, E: Error>(value: Value) -> Result<T, E> {
+    serde_json::from_value(value).map_err(E::custom)
+}
+
+#[cfg(feature = "strict")]
+thread_local! {
+    /// Path of the last error inside a union, from the union, and its message.
+    static INNER: std::cell::RefCell<Option<(String, String)>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Keeps the path of the error inside the subtype, which the path of the
+/// outer deserializer ends before, for `inner_path`.
+#[cfg(feature = "strict")]
+pub(crate) fn from_value<T: DeserializeOwned, E: Error>(value: Value) -> Result<T, E> {
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        let path = err.path().to_string();
+        let message = err.into_inner().to_string();
+        let path = match inner_path(&message) {
+            Some(inner) => join_paths(&path, &inner),
+            None => path,
+        };
+        INNER.with(|last| *last.borrow_mut() = Some((path, message.clone())));
+        E::custom(message)
+    })
+}
+
+/// Path inside the union the error with `message` comes from, if it does.
+/// Errors of other subtypes that were given up on are left out by their
+/// message.
+#[cfg(feature = "strict")]
+pub(crate) fn inner_path(message: &str) -> Option<String> {
+    let last = INNER.with(|last| last.borrow_mut().take());
+    last.filter(|(_, inner)| message.starts_with(&inner[..])).map(|(path, _)| path)
+}
+
+/// `outer` followed by `inner`, where `.` is the root.
+#[cfg(feature = "strict")]
+pub(crate) fn join_paths(outer: &str, inner: &str) -> String {
+    match (outer, inner) {
+        (_, ".") => outer.to_string(),
+        (".", _) => inner.to_string(),
+        _ if inner.starts_with('[') => format!("{}{}", outer, inner),
+        _ => format!("{}.{}", outer, inner),
+    }
+}
+
+#[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+pub(crate) fn unknown<E: Error>(union: &str) -> E {
+    E::custom(format_args!("data did not match any variant of {}", union))
+}
@@ -0,0 +1,151 @@
+//! Filters deciding which updates a route handles.
+
+use std::collections::HashSet;
+
+use super::*;
+
+
+/// A condition on updates.
+///
+/// Closures taking an `&Update` are filters too, and filters combine with
+/// `and`, `or` and `not`:
+///
+/// ```
+/// use tg_bot_models::dispatch::filter::{self, Filter};
+/// use tg_bot_models::ChatType;
+///
+/// let filter = filter::command("start").and(filter::chat_type(ChatType::Private));
+/// ```
+pub trait Filter: Send + Sync {
+    fn matches(&self, update: &Update) -> bool;
+
+    /// Matches the updates both filters match.
+    fn and<F: Filter>(self, other: F) -> And<Self, F>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Matches the updates either filter matches.
+    fn or<F: Filter>(self, other: F) -> Or<Self, F>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Matches the updates this filter doesn't match.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+impl<F: Fn(&Update) -> bool + Send + Sync> Filter for F {
+    fn matches(&self, update: &Update) -> bool {
+        self(update)
+    }
+}
+
+impl Filter for Box<dyn Filter> {
+    fn matches(&self, update: &Update) -> bool {
+        (**self).matches(update)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct And<A, B>(A, B);
+
+impl<A: Filter, B: Filter> Filter for And<A, B> {
+    fn matches(&self, update: &Update) -> bool {
+        self.0.matches(update) && self.1.matches(update)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Or<A, B>(A, B);
+
+impl<A: Filter, B: Filter> Filter for Or<A, B> {
+    fn matches(&self, update: &Update) -> bool {
+        self.0.matches(update) || self.1.matches(update)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Not<F>(F);
+
+impl<F: Filter> Filter for Not<F> {
+    fn matches(&self, update: &Update) -> bool {
+        !self.0.matches(update)
+    }
+}
+
+
+/// Matches every update.
+pub fn any() -> impl Filter {
+    |_: &Update| true
+}
+
+/// Matches the updates of the given kind.
+pub fn kind(kind: UpdateKind) -> impl Filter {
+    move |update: &Update| update.kind() == Some(kind)
+}
+
+/// Matches the updates from chats of the given type.
+pub fn chat_type(ty: ChatType) -> impl Filter {
+    move |update: &Update| update.chat().is_some_and(|chat| chat.ty == ty)
+}
+
+/// Matches the messages starting with the command `name`, addressed to any
+/// bot. See `command_of` in groups, where commands can be addressed to other
+/// bots.
+pub fn command<S: Into<String>>(name: S) -> impl Filter {
+    let name = name.into();
+    move |update: &Update| update.message().and_then(Message::command).is_some_and(|command| command.name == name)
+}
+
+/// Matches the messages starting with the command `name`, either addressed to
+/// the bot with the username `bot` or to no bot in particular.
+pub fn command_of<S: Into<String>, B: Into<String>>(name: S, bot: B) -> impl Filter {
+    let name = name.into();
+    let bot = bot.into();
+    move |update: &Update| {
+        let command = update.message().and_then(Message::command);
+        command.is_some_and(|command| command.name == name && command.bot.is_none_or(|to| to.eq_ignore_ascii_case(&bot)))
+    }
+}
+
+/// Matches the messages whose text or caption matches `regex`.
+#[cfg(feature = "regex")]
+pub fn text_matches(regex: regex::Regex) -> impl Filter {
+    move |update: &Update| update.message().and_then(Message::text_or_caption).is_some_and(|text| regex.is_match(text))
+}
+
+/// Matches the callback queries whose data starts with `prefix`.
+pub fn callback_data<S: Into<String>>(prefix: S) -> impl Filter {
+    let prefix = prefix.into();
+    move |update: &Update| {
+        let data = update.callback_query.as_ref().and_then(|query| query.data.as_deref());
+        data.is_some_and(|data| data.starts_with(&prefix[..]))
+    }
+}
+
+/// Matches the updates caused by the users with the given ids.
+pub fn users<I: IntoIterator<Item = i64>>(ids: I) -> impl Filter {
+    let ids: HashSet<i64> = ids.into_iter().collect();
+    move |update: &Update| update.user().is_some_and(|user| ids.contains(&user.id))
+}
+
+/// Matches the updates from the chats with the given ids.
+pub fn chats<I: IntoIterator<Item = i64>>(ids: I) -> impl Filter {
+    let ids: HashSet<i64> = ids.into_iter().collect();
+    move |update: &Update| update.chat().is_some_and(|chat| ids.contains(&chat.id))
+}
+
+/// Matches the messages carrying media.
+pub fn has_media() -> impl Filter {
+    |update: &Update| update.message().is_some_and(Message::has_media)
+}
@@ -0,0 +1,255 @@
+//! Routing of updates to handlers.
+//!
+//! A `Dispatcher` holds routes, each a `Filter` and a handler, and passes an
+//! update to the handlers of the routes whose filter matches it, by priority
+//! and then in the order the routes were added. A handler tells whether the
+//! update goes on to the next matching route, with `Flow`. Handlers returning
+//! an error or panicking are reported in the `Dispatched` of the update, and
+//! affect neither the other routes nor the other updates.
+//!
+//! Handlers are either plain functions or return futures. The dispatcher runs
+//! on any runtime: `Dispatcher::dispatch` is a future, which completes when
+//! first polled if every matching handler is a plain function.
+
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use super::*;
+
+pub mod filter;
+
+use self::filter::Filter;
+
+
+/// Whether an update goes on to the next matching route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flow {
+    /// The update was handled.
+    Stop,
+    Continue,
+}
+
+/// Error returned by a handler.
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Result of a handler.
+pub type HandlerResult = Result<Flow, BoxError>;
+
+type BoxFuture = Pin<Box<dyn Future<Output = HandlerResult> + Send>>;
+
+enum Handler {
+    Sync(Box<dyn Fn(&Update) -> HandlerResult + Send + Sync>),
+    Async(Box<dyn Fn(Arc<Update>) -> BoxFuture + Send + Sync>),
+}
+
+/// A handler and the updates it handles.
+pub struct Route {
+    filter: Box<dyn Filter>,
+    handler: Handler,
+    priority: i32,
+}
+
+impl Route {
+    /// A route passing the updates matching `filter` to `handler`.
+    pub fn new<F, H>(filter: F, handler: H) -> Self
+    where
+        F: Filter + 'static,
+        H: Fn(&Update) -> HandlerResult + Send + Sync + 'static,
+    {
+        Route {
+            filter: Box::new(filter),
+            handler: Handler::Sync(Box::new(handler)),
+            priority: 0,
+        }
+    }
+
+    /// A route passing the updates matching `filter` to `handler`, which
+    /// returns a future.
+    pub fn new_async<F, H, R>(filter: F, handler: H) -> Self
+    where
+        F: Filter + 'static,
+        H: Fn(Arc<Update>) -> R + Send + Sync + 'static,
+        R: Future<Output = HandlerResult> + Send + 'static,
+    {
+        Route {
+            filter: Box::new(filter),
+            handler: Handler::Async(Box::new(move |update| Box::pin(handler(update)))),
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the route, 0 by default. Routes with a higher
+    /// priority come first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl fmt::Debug for Route {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let handler = match self.handler {
+            Handler::Sync(_) => "sync",
+            Handler::Async(_) => "async",
+        };
+        f.debug_struct("Route").field("handler", &handler).field("priority", &self.priority).finish()
+    }
+}
+
+
+/// Why a handler failed.
+#[derive(Debug)]
+pub enum HandlerError {
+    Failed(BoxError),
+    /// The handler panicked, with the given message.
+    Panicked(String),
+}
+
+impl fmt::Display for HandlerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandlerError::Failed(err) => err.fmt(f),
+            HandlerError::Panicked(message) => write!(f, "handler panicked: {}", message),
+        }
+    }
+}
+
+impl Error for HandlerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HandlerError::Failed(err) => Some(&**err),
+            HandlerError::Panicked(_) => None,
+        }
+    }
+}
+
+/// What became of an update.
+#[derive(Debug, Default)]
+pub struct Dispatched {
+    /// Routes whose handler ran, by the order they were added in
+    pub handled_by: Vec<usize>,
+    /// The handlers that failed, by the order their routes were added in
+    pub failures: Vec<(usize, HandlerError)>,
+}
+
+impl Dispatched {
+    /// Whether no route matched the update.
+    pub fn is_unhandled(&self) -> bool {
+        self.handled_by.is_empty()
+    }
+}
+
+/// The handlers that failed on an update, by the order their routes were
+/// added in.
+#[derive(Debug)]
+pub struct Failures(pub Vec<(usize, HandlerError)>);
+
+impl fmt::Display for Failures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (_, err)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            err.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for Failures {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.first().map(|(_, err)| err as _)
+    }
+}
+
+
+/// Routes of the updates.
+#[derive(Debug, Default)]
+pub struct Dispatcher {
+    /// Routes by priority, with the order they were added in.
+    routes: Vec<(usize, Route)>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Dispatcher::default()
+    }
+
+    /// Adds `route` after the routes of the same priority.
+    pub fn route(&mut self, route: Route) -> &mut Self {
+        let index = self.routes.len();
+        let position = self.routes.iter().position(|(_, other)| other.priority < route.priority);
+        self.routes.insert(position.unwrap_or(index), (index, route));
+        self
+    }
+
+    /// Passes `update` to the handlers of the matching routes. A failing handler
+    /// doesn't stop the update, it goes on to the next matching route.
+    pub async fn dispatch(&self, update: Update) -> Dispatched {
+        let update = Arc::new(update);
+        let mut dispatched = Dispatched::default();
+        for (index, route) in &self.routes {
+            if !route.filter.matches(&update) {
+                continue;
+            }
+            dispatched.handled_by.push(*index);
+            let result = match &route.handler {
+                Handler::Sync(handler) => {
+                    panic::catch_unwind(AssertUnwindSafe(|| handler(&update))).unwrap_or_else(|payload| Err(panicked(payload)))
+                }
+                Handler::Async(handler) => CatchUnwind(handler(update.clone())).await,
+            };
+            match result {
+                Ok(Flow::Continue) => {}
+                Ok(Flow::Stop) => break,
+                Err(err) => {
+                    let err = match err.downcast::<Panicked>() {
+                        Ok(panicked) => HandlerError::Panicked(panicked.0),
+                        Err(err) => HandlerError::Failed(err),
+                    };
+                    dispatched.failures.push((*index, err));
+                }
+            }
+        }
+        dispatched
+    }
+}
+
+
+/// Message of a panic, while it's passed as an error.
+#[derive(Debug)]
+struct Panicked(String);
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for Panicked {}
+
+fn panicked(payload: Box<dyn Any + Send>) -> BoxError {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("Box<dyn Any>", |message| message).to_string(),
+    };
+    Box::new(Panicked(message))
+}
+
+/// A future resolving to an error if polling it panics.
+struct CatchUnwind(BoxFuture);
+
+impl Future for CatchUnwind {
+    type Output = HandlerResult;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<HandlerResult> {
+        let future = &mut self.0;
+        panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))).unwrap_or_else(|payload| Poll::Ready(Err(panicked(payload))))
+    }
+}
@@ -0,0 +1,232 @@
+//! Downloading of files.
+//!
+//! `GetFile` gives the `file_path` of a file, which is either relative to the
+//! file endpoint of the Bot API server, or, with a local Bot API server, an
+//! absolute path on its disk. A `Downloader` resolves it to a `Source`, reads
+//! it, through a `Fetch` for URLs, and copies it to a writer, checking its
+//! size on the way. The crate has no HTTP client: `Fetch` is implemented by
+//! the functions giving a reader of the body of a URL.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::*;
+use crate::dispatch::BoxError;
+use crate::middleware::Service;
+
+
+/// Where the files are downloaded from.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FileEndpoint {
+    base: String,
+    token: String,
+}
+
+impl FileEndpoint {
+    /// The endpoint of `https://api.telegram.org`.
+    pub fn telegram<S: Into<String>>(token: S) -> Self {
+        FileEndpoint::new("https://api.telegram.org", token)
+    }
+
+    /// The endpoint of the Bot API server at `base`, such as
+    /// `http://localhost:8081` for a local one.
+    pub fn new<B: Into<String>, S: Into<String>>(base: B, token: S) -> Self {
+        let mut base = base.into();
+        while base.ends_with('/') {
+            base.pop();
+        }
+        FileEndpoint { base, token: token.into() }
+    }
+
+    /// URL of the file at `file_path`, relative to the endpoint.
+    pub fn url(&self, file_path: &str) -> String {
+        format!("{}/file/bot{}/{}", self.base, self.token, file_path.trim_start_matches('/'))
+    }
+
+    /// Where to read `file` from, `None` if it has no `file_path`.
+    pub fn source(&self, file: &File) -> Option<Source> {
+        let path = file.file_path.as_deref()?;
+        // Local Bot API servers give the absolute path of the files.
+        Some(if path.starts_with('/') {
+            Source::Local(PathBuf::from(path))
+        } else {
+            Source::Url(self.url(path))
+        })
+    }
+}
+
+/// Leaves the token out.
+impl fmt::Debug for FileEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileEndpoint").field("base", &self.base).finish_non_exhaustive()
+    }
+}
+
+/// Where a file is read from.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Source {
+    Url(String),
+    /// A path on the disk of a local Bot API server.
+    Local(PathBuf),
+}
+
+/// Leaves the token out of URLs.
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Url(url) => {
+                // The token goes from `/file/bot` to the next `/`.
+                let redacted = match url.split_once("/file/bot") {
+                    Some((base, rest)) => {
+                        let path = rest.split_once('/').map_or("", |(_, path)| path);
+                        format!("{}/file/bot<token>/{}", base, path)
+                    }
+                    None => url.clone(),
+                };
+                f.debug_tuple("Url").field(&redacted).finish()
+            }
+            Source::Local(path) => f.debug_tuple("Local").field(path).finish(),
+        }
+    }
+}
+
+
+/// Reads the body of URLs.
+pub trait Fetch {
+    type Reader: Read;
+
+    fn fetch(&self, url: &str) -> io::Result<Self::Reader>;
+}
+
+impl<F: Fn(&str) -> io::Result<R>, R: Read> Fetch for F {
+    type Reader = R;
+
+    fn fetch(&self, url: &str) -> io::Result<R> {
+        self(url)
+    }
+}
+
+/// Why a download failed.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The `GetFile` request failed.
+    GetFile(BoxError),
+    /// The file has no `file_path`, it can't be downloaded.
+    NoPath,
+    /// The file is bigger than the downloader accepts.
+    TooLarge { max: u64 },
+    /// The file doesn't have the size given by Telegram. Files bigger than
+    /// expected aren't read further, `actual` is then `expected + 1`.
+    SizeMismatch { expected: u64, actual: u64 },
+    Io(io::Error),
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DownloadError::GetFile(err) => write!(f, "getFile failed: {}", err),
+            DownloadError::NoPath => f.write_str("the file has no path to download it from"),
+            DownloadError::TooLarge { max } => write!(f, "the file is over {} bytes", max),
+            DownloadError::SizeMismatch { expected, actual } => {
+                write!(f, "the file has {} bytes instead of {}", actual, expected)
+            }
+            DownloadError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DownloadError::GetFile(err) => Some(&**err),
+            DownloadError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DownloadError {
+    fn from(err: io::Error) -> Self {
+        DownloadError::Io(err)
+    }
+}
+
+/// Downloads files from an endpoint.
+#[derive(Debug, Clone)]
+pub struct Downloader<F> {
+    endpoint: FileEndpoint,
+    fetch: F,
+    max_size: Option<u64>,
+}
+
+impl<F: Fetch> Downloader<F> {
+    pub fn new(endpoint: FileEndpoint, fetch: F) -> Self {
+        Downloader { endpoint, fetch, max_size: None }
+    }
+
+    /// Sets the size of the largest file to download.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    pub fn endpoint(&self) -> &FileEndpoint {
+        &self.endpoint
+    }
+
+    /// Copies `file` to `writer`. Returns the number of bytes copied.
+    pub fn download<W: Write>(&self, file: &File, writer: &mut W) -> Result<u64, DownloadError> {
+        let expected = file.file_size.map(|size| size as u64);
+        if let (Some(size), Some(max)) = (expected, self.max_size) {
+            if size > max {
+                return Err(DownloadError::TooLarge { max });
+            }
+        }
+        match self.endpoint.source(file).ok_or(DownloadError::NoPath)? {
+            Source::Url(url) => self.copy(self.fetch.fetch(&url)?, expected, writer),
+            Source::Local(path) => self.copy(fs::File::open(path)?, expected, writer),
+        }
+    }
+
+    /// Requests the path of `object` with `GetFile` through `requests`, then
+    /// copies the file to `writer`. Returns the number of bytes copied.
+    ///
+    /// Only the request is asynchronous: the file is read from the reader of
+    /// `Fetch`, blocking.
+    pub async fn download_object<S, D, W>(&self, requests: &S, object: &D, writer: &mut W) -> Result<u64, DownloadError>
+    where
+        S: Service<Request, Response = Value>,
+        S::Error: Into<BoxError>,
+        D: FileLike + ?Sized,
+        W: Write,
+    {
+        let response = requests.call(Request::new(&GetFile::new(object.file_id()))).await;
+        let mut file: File = serde_json::from_value(response.map_err(|err| DownloadError::GetFile(err.into()))?)
+            .map_err(|err| DownloadError::GetFile(err.into()))?;
+        if file.file_size.is_none() {
+            file.file_size = object.file_size().map(|size| size as i64);
+        }
+        self.download(&file, writer)
+    }
+
+    fn copy<R: Read, W: Write>(&self, reader: R, expected: Option<u64>, writer: &mut W) -> Result<u64, DownloadError> {
+        // Reading one byte over the limit tells files over it.
+        let limit = match (expected, self.max_size) {
+            (Some(expected), Some(max)) => expected.min(max),
+            (expected, max) => expected.or(max).unwrap_or(u64::MAX - 1),
+        };
+        let copied = io::copy(&mut reader.take(limit + 1), writer)?;
+        if let Some(max) = self.max_size.filter(|&max| copied > max) {
+            return Err(DownloadError::TooLarge { max });
+        }
+        match expected {
+            Some(expected) if copied != expected => Err(DownloadError::SizeMismatch { expected, actual: copied }),
+            _ => Ok(copied),
+        }
+    }
+}
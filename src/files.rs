@@ -0,0 +1,117 @@
+//! What the objects holding a file have in common.
+
+use super::*;
+
+
+/// A received object holding a file, which can be downloaded or sent again by
+/// its file id.
+pub trait FileLike {
+    fn file_id(&self) -> &str;
+
+    /// Size of the file in bytes, if known.
+    fn file_size(&self) -> Option<u64>;
+
+    /// MIME type of the file, as defined by the sender.
+    fn mime_type(&self) -> Option<&str> {
+        None
+    }
+
+    /// Thumbnail of the file, as defined by the sender.
+    fn thumb(&self) -> Option<&PhotoSize> {
+        None
+    }
+}
+
+macro_rules! file_like {
+    ($ty:ident $(, mime_type: $mime_type:ident)? $(, thumb: $thumb:ident)?) => {
+        impl FileLike for $ty {
+            fn file_id(&self) -> &str {
+                &self.file_id
+            }
+
+            fn file_size(&self) -> Option<u64> {
+                self.file_size.map(|size| size as u64)
+            }
+
+            $(fn mime_type(&self) -> Option<&str> {
+                self.$mime_type.as_deref()
+            })?
+
+            $(fn thumb(&self) -> Option<&PhotoSize> {
+                self.$thumb.as_ref()
+            })?
+        }
+    };
+}
+
+file_like!(Animation, mime_type: mime_type, thumb: thumbnail);
+file_like!(Audio, mime_type: mime_type, thumb: thumbnail);
+file_like!(Document, mime_type: mime_type, thumb: thumbnail);
+file_like!(File);
+file_like!(PhotoSize);
+file_like!(Sticker, thumb: thumbnail);
+file_like!(Video, mime_type: mime_type, thumb: thumbnail);
+file_like!(VideoNote, thumb: thumbnail);
+file_like!(Voice, mime_type: mime_type);
+
+impl FileLike for PassportFile {
+    fn file_id(&self) -> &str {
+        &self.file_id
+    }
+
+    fn file_size(&self) -> Option<u64> {
+        Some(self.file_size as u64)
+    }
+}
+
+
+impl Message {
+    /// Every file of the message, with the thumbnails after the files they're
+    /// of: the sizes of photos, media, the animation of premium stickers, the
+    /// media of games, paid media bought and the files of Telegram Passport
+    /// data.
+    pub fn files(&self) -> Vec<&dyn FileLike> {
+        let mut files = Vec::new();
+        let sizes = self.photo.iter().chain(&self.new_chat_photo).chain(self.game.as_ref().map(|game| &game.photo));
+        for size in sizes.flatten() {
+            push(&mut files, size);
+        }
+        let media: [Option<&dyn FileLike>; 9] = [
+            self.animation.as_ref().map(|file| file as _),
+            self.audio.as_ref().map(|file| file as _),
+            // Animation messages also have the animation as their document.
+            self.document.as_ref().filter(|_| self.animation.is_none()).map(|file| file as _),
+            self.video.as_ref().map(|file| file as _),
+            self.video_note.as_ref().map(|file| file as _),
+            self.voice.as_ref().map(|file| file as _),
+            self.sticker.as_ref().map(|file| file as _),
+            self.sticker.as_ref().and_then(|sticker| sticker.premium_animation.as_ref()).map(|file| file as _),
+            self.game.as_ref().and_then(|game| game.animation.as_ref()).map(|file| file as _),
+        ];
+        for &file in media.iter().flatten() {
+            push(&mut files, file);
+        }
+        for paid in self.paid_media.iter().flat_map(|info| &info.paid_media) {
+            match paid {
+                PaidMedia::PaidMediaPhoto(photo) => photo.photo.iter().for_each(|size| push(&mut files, size)),
+                PaidMedia::PaidMediaVideo(video) => push(&mut files, &video.video),
+                _ => {}
+            }
+        }
+        for element in self.passport_data.iter().flat_map(|data| &data.data) {
+            let sides = [&element.front_side, &element.reverse_side, &element.selfie];
+            let passport_files = element.files.iter().chain(&element.translation).flatten();
+            for file in sides.iter().copied().flatten().chain(passport_files) {
+                push(&mut files, file);
+            }
+        }
+        files
+    }
+}
+
+fn push<'a>(files: &mut Vec<&'a dyn FileLike>, file: &'a dyn FileLike) {
+    files.push(file);
+    if let Some(thumb) = file.thumb() {
+        files.push(thumb);
+    }
+}
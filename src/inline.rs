@@ -0,0 +1,186 @@
+//! Answering inline queries.
+//!
+//! Each kind of result is created with the `new` of its type, which sets its
+//! `type`, and converts into an `InlineQueryResult`. `InlineAnswerBuilder`
+//! gathers them into an `AnswerInlineQuery`, checking what the Bot API would
+//! refuse, and pages through longer lists of results with the offset of the
+//! query: the offset of the next page is the number of results already
+//! shown.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use super::*;
+use crate::validate::{self as checks, ValidationError};
+
+
+/// Most results of an answer.
+pub const MAX_RESULTS: usize = 50;
+
+/// Why a result can't be added to an answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InlineAnswerError {
+    /// The id of the result isn't 1-64 bytes.
+    Id(ValidationError),
+    /// Another result has the same id.
+    DuplicateId(String),
+    /// The answer already has `MAX_RESULTS` results.
+    TooManyResults,
+}
+
+impl fmt::Display for InlineAnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InlineAnswerError::Id(err) => err.fmt(f),
+            InlineAnswerError::DuplicateId(id) => write!(f, "two results have the id {:?}", id),
+            InlineAnswerError::TooManyResults => write!(f, "an answer has at most {} results", MAX_RESULTS),
+        }
+    }
+}
+
+impl Error for InlineAnswerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InlineAnswerError::Id(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+
+/// Builds the answer to an inline query.
+#[derive(Debug, Clone)]
+pub struct InlineAnswerBuilder {
+    answer: AnswerInlineQuery,
+    ids: HashSet<String>,
+    offset: usize,
+    page_size: usize,
+}
+
+impl InlineAnswerBuilder {
+    /// The answer to `query`, whose offset is where `page` starts. Offsets
+    /// that this builder didn't give are read as the first page.
+    pub fn new(query: &InlineQuery) -> Self {
+        InlineAnswerBuilder {
+            answer: AnswerInlineQuery::new(query.id.clone(), Vec::new()),
+            ids: HashSet::new(),
+            offset: query.offset.parse().unwrap_or(0),
+            page_size: MAX_RESULTS,
+        }
+    }
+
+    /// Sets the number of results of a page, at most `MAX_RESULTS`.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.clamp(1, MAX_RESULTS);
+        self
+    }
+
+    /// Sets `cache_time`.
+    pub fn cache_time(mut self, cache_time: i64) -> Self {
+        self.answer.cache_time = Some(cache_time);
+        self
+    }
+
+    /// Sets `is_personal`.
+    pub fn is_personal(mut self, is_personal: bool) -> Self {
+        self.answer.is_personal = Some(is_personal);
+        self
+    }
+
+    /// Sets `button`.
+    pub fn button(mut self, button: InlineQueryResultsButton) -> Self {
+        self.answer.button = Some(button);
+        self
+    }
+
+    /// Where the page of the query starts in the whole list of results.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Adds a result.
+    pub fn result<R: Into<InlineQueryResult>>(mut self, result: R) -> Result<Self, InlineAnswerError> {
+        self.push(result.into())?;
+        Ok(self)
+    }
+
+    /// Adds the page of the query from the whole list of results, and sets the
+    /// offset of the next page if there are more. Results already added take
+    /// places of the page but not of the offset, which counts the paged results
+    /// only, so they may differ from page to page. A page has at least one paged
+    /// result, for the pagination to go on.
+    pub fn page<I>(mut self, results: I) -> Result<Self, InlineAnswerError>
+    where
+        I: IntoIterator,
+        I::Item: Into<InlineQueryResult>,
+    {
+        let added = self.answer.results.len();
+        let room = self.page_size.saturating_sub(added).max(1);
+        let mut results = results.into_iter().skip(self.offset).peekable();
+        let mut paged = 0;
+        for result in results.by_ref().take(room) {
+            self.push(result.into())?;
+            paged += 1;
+        }
+        self.answer.next_offset = results.peek().map(|_| (self.offset + paged).to_string());
+        Ok(self)
+    }
+
+    pub fn build(self) -> AnswerInlineQuery {
+        self.answer
+    }
+
+    fn push(&mut self, result: InlineQueryResult) -> Result<(), InlineAnswerError> {
+        let index = self.answer.results.len();
+        if index == MAX_RESULTS {
+            return Err(InlineAnswerError::TooManyResults);
+        }
+        if let Some(id) = result.id() {
+            checks::bytes(&format!("results[{}].id", index), id, 1, 64).map_err(InlineAnswerError::Id)?;
+            if !self.ids.insert(id.to_string()) {
+                return Err(InlineAnswerError::DuplicateId(id.to_string()));
+            }
+        }
+        self.answer.results.push(result);
+        Ok(())
+    }
+}
+
+
+impl InlineQueryResult {
+    /// Identifier of the result, `None` for results of unknown kinds without
+    /// one.
+    pub fn id(&self) -> Option<&str> {
+        macro_rules! id {
+            ($($variant:ident),*) => {
+                match self {
+                    $(InlineQueryResult::$variant(result) => Some(&result.id),)*
+                    InlineQueryResult::Unknown(raw) => raw.0.get("id").and_then(|id| id.as_str()),
+                }
+            };
+        }
+        id!(
+            InlineQueryResultCachedAudio,
+            InlineQueryResultCachedDocument,
+            InlineQueryResultCachedGif,
+            InlineQueryResultCachedMpeg4Gif,
+            InlineQueryResultCachedPhoto,
+            InlineQueryResultCachedSticker,
+            InlineQueryResultCachedVideo,
+            InlineQueryResultCachedVoice,
+            InlineQueryResultArticle,
+            InlineQueryResultAudio,
+            InlineQueryResultContact,
+            InlineQueryResultGame,
+            InlineQueryResultDocument,
+            InlineQueryResultGif,
+            InlineQueryResultLocation,
+            InlineQueryResultMpeg4Gif,
+            InlineQueryResultPhoto,
+            InlineQueryResultVenue,
+            InlineQueryResultVideo,
+            InlineQueryResultVoice
+        )
+    }
+}
@@ -0,0 +1,1180 @@
+// Generated by `cargo run -p codegen` from `api/botapi.json` (Bot API 7.10,
+// September 6, 2024), do not edit by hand.
+
+//! `Validate` impls checking the limits documented for the parameters of
+//! the methods and for the fields of the objects sent with them.
+
+use super::*;
+
+impl Validate for PolymorphReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            PolymorphReplyMarkup::InlineKeyboardMarkup(value) => value.validate(),
+            PolymorphReplyMarkup::ReplyKeyboardMarkup(value) => value.validate(),
+            PolymorphReplyMarkup::ReplyKeyboardRemove(_) => Ok(()),
+            PolymorphReplyMarkup::ForceReply(value) => value.validate(),
+        }
+    }
+}
+
+impl Validate for InputMedia {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputMedia::InputMediaAnimation(value) => value.validate(),
+            InputMedia::InputMediaDocument(value) => value.validate(),
+            InputMedia::InputMediaAudio(value) => value.validate(),
+            InputMedia::InputMediaPhoto(value) => value.validate(),
+            InputMedia::InputMediaVideo(value) => value.validate(),
+            InputMedia::Unknown(_) => Ok(()),
+        }
+    }
+}
+
+impl Validate for InlineQueryResult {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InlineQueryResult::InlineQueryResultCachedAudio(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedDocument(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedGif(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedMpeg4Gif(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedPhoto(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedSticker(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedVideo(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultCachedVoice(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultArticle(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultAudio(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultContact(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultGame(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultDocument(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultGif(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultLocation(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultMpeg4Gif(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultPhoto(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultVenue(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultVideo(value) => value.validate(),
+            InlineQueryResult::InlineQueryResultVoice(value) => value.validate(),
+            InlineQueryResult::Unknown(_) => Ok(()),
+        }
+    }
+}
+
+impl Validate for InputMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputMessageContent::InputTextMessageContent(value) => value.validate(),
+            InputMessageContent::InputLocationMessageContent(value) => value.validate(),
+            InputMessageContent::InputVenueMessageContent(_) => Ok(()),
+            InputMessageContent::InputContactMessageContent(value) => value.validate(),
+            InputMessageContent::InputInvoiceMessageContent(value) => value.validate(),
+            InputMessageContent::Unknown(_) => Ok(()),
+        }
+    }
+}
+
+impl Validate for ReplyParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(quote), None) = (&self.quote, &self.quote_parse_mode) {
+            validate::characters("quote", quote, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputPollOption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("text", &self.text, 1, 100)?;
+        Ok(())
+    }
+}
+
+impl Validate for ReplyKeyboardMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("keyboard", &self.keyboard)?;
+        if let Some(input_field_placeholder) = &self.input_field_placeholder {
+            validate::characters("input_field_placeholder", input_field_placeholder, 1, 64)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for KeyboardButton {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("request_users", &self.request_users)?;
+        Ok(())
+    }
+}
+
+impl Validate for KeyboardButtonRequestUsers {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(max_quantity) = self.max_quantity {
+            validate::range("max_quantity", max_quantity, 1, 10)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InlineKeyboardMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("inline_keyboard", &self.inline_keyboard)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineKeyboardButton {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(callback_data) = &self.callback_data {
+            validate::bytes("callback_data", callback_data, 1, 64)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for ForceReply {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(input_field_placeholder) = &self.input_field_placeholder {
+            validate::characters("input_field_placeholder", input_field_placeholder, 1, 64)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for BotCommand {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("command", &self.command, 1, 32)?;
+        validate::characters("description", &self.description, 1, 256)?;
+        Ok(())
+    }
+}
+
+impl Validate for InputMediaPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputMediaVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputMediaAnimation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputMediaAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputMediaDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("emoji_list", &self.emoji_list, 1, 20)?;
+        if let Some(keywords) = &self.keywords {
+            validate::items("keywords", keywords, 0, 20)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultsButton {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(start_parameter) = &self.start_parameter {
+            validate::characters("start_parameter", start_parameter, 1, 64)?;
+            validate::safe_characters("start_parameter", start_parameter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultArticle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultGif {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultMpeg4Gif {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultVoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let Some(horizontal_accuracy) = self.horizontal_accuracy {
+            validate::range("horizontal_accuracy", horizontal_accuracy, 0, 1500)?;
+        }
+        if let Some(heading) = self.heading {
+            validate::range("heading", heading, 1, 360)?;
+        }
+        if let Some(proximity_alert_radius) = self.proximity_alert_radius {
+            validate::range("proximity_alert_radius", proximity_alert_radius, 1, 100000)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultVenue {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultContact {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let Some(vcard) = &self.vcard {
+            validate::bytes("vcard", vcard, 0, 2048)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultGame {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedGif {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedMpeg4Gif {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedVoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InlineQueryResultCachedAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::bytes("id", &self.id, 1, 64)?;
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        validate::nested("input_message_content", &self.input_message_content)?;
+        Ok(())
+    }
+}
+
+impl Validate for InputTextMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("message_text", &self.message_text, 1, 4096)?;
+        Ok(())
+    }
+}
+
+impl Validate for InputLocationMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(horizontal_accuracy) = self.horizontal_accuracy {
+            validate::range("horizontal_accuracy", horizontal_accuracy, 0, 1500)?;
+        }
+        if let Some(heading) = self.heading {
+            validate::range("heading", heading, 1, 360)?;
+        }
+        if let Some(proximity_alert_radius) = self.proximity_alert_radius {
+            validate::range("proximity_alert_radius", proximity_alert_radius, 1, 100000)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputContactMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(vcard) = &self.vcard {
+            validate::bytes("vcard", vcard, 0, 2048)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for InputInvoiceMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("title", &self.title, 1, 32)?;
+        validate::characters("description", &self.description, 1, 255)?;
+        validate::bytes("payload", &self.payload, 1, 128)?;
+        if let Some(suggested_tip_amounts) = &self.suggested_tip_amounts {
+            validate::items("suggested_tip_amounts", suggested_tip_amounts, 0, 4)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetUpdates {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(limit) = self.limit {
+            validate::range("limit", limit, 1, 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for SetWebhook {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(max_connections) = self.max_connections {
+            validate::range("max_connections", max_connections, 1, 100)?;
+        }
+        if let Some(secret_token) = &self.secret_token {
+            validate::characters("secret_token", secret_token, 1, 256)?;
+            validate::safe_characters("secret_token", secret_token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for DeleteWebhook {}
+
+impl Validate for GetWebhookInfo {}
+
+impl Validate for GetMe {}
+
+impl Validate for LogOut {}
+
+impl Validate for Close {}
+
+impl Validate for SendMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.parse_mode.is_none() {
+            validate::characters("text", &self.text, 1, 4096)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for ForwardMessage {}
+
+impl Validate for ForwardMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("message_ids", &self.message_ids, 1, 100)?;
+        Ok(())
+    }
+}
+
+impl Validate for CopyMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for CopyMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("message_ids", &self.message_ids, 1, 100)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendAnimation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendVoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendVideoNote {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendPaidMedia {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::range("star_count", self.star_count, 1, 2500)?;
+        validate::items("media", &self.media, 0, 10)?;
+        if let Some(payload) = &self.payload {
+            validate::bytes("payload", payload, 0, 128)?;
+        }
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendMediaGroup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("media", &self.media, 2, 10)?;
+        validate::nested("media", &self.media)?;
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(horizontal_accuracy) = self.horizontal_accuracy {
+            validate::range("horizontal_accuracy", horizontal_accuracy, 0, 1500)?;
+        }
+        if let Some(heading) = self.heading {
+            validate::range("heading", heading, 1, 360)?;
+        }
+        if let Some(proximity_alert_radius) = self.proximity_alert_radius {
+            validate::range("proximity_alert_radius", proximity_alert_radius, 1, 100000)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendVenue {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendContact {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(vcard) = &self.vcard {
+            validate::bytes("vcard", vcard, 0, 2048)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendPoll {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("question", &self.question, 1, 300)?;
+        validate::items("options", &self.options, 2, 10)?;
+        validate::nested("options", &self.options)?;
+        if let (Some(explanation), None) = (&self.explanation, &self.explanation_parse_mode) {
+            validate::characters("explanation", explanation, 0, 200)?;
+        }
+        if let Some(open_period) = self.open_period {
+            validate::range("open_period", open_period, 5, 600)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendDice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendChatAction {}
+
+impl Validate for SetMessageReaction {}
+
+impl Validate for GetUserProfilePhotos {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(limit) = self.limit {
+            validate::range("limit", limit, 1, 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetFile {}
+
+impl Validate for BanChatMember {}
+
+impl Validate for UnbanChatMember {}
+
+impl Validate for RestrictChatMember {}
+
+impl Validate for PromoteChatMember {}
+
+impl Validate for SetChatAdministratorCustomTitle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("custom_title", &self.custom_title, 0, 16)?;
+        Ok(())
+    }
+}
+
+impl Validate for BanChatSenderChat {}
+
+impl Validate for UnbanChatSenderChat {}
+
+impl Validate for SetChatPermissions {}
+
+impl Validate for ExportChatInviteLink {}
+
+impl Validate for CreateChatInviteLink {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 32)?;
+        }
+        if let Some(member_limit) = self.member_limit {
+            validate::range("member_limit", member_limit, 1, 99999)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for EditChatInviteLink {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 32)?;
+        }
+        if let Some(member_limit) = self.member_limit {
+            validate::range("member_limit", member_limit, 1, 99999)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for CreateChatSubscriptionInviteLink {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 32)?;
+        }
+        validate::range("subscription_price", self.subscription_price, 1, 2500)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditChatSubscriptionInviteLink {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 32)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for RevokeChatInviteLink {}
+
+impl Validate for ApproveChatJoinRequest {}
+
+impl Validate for DeclineChatJoinRequest {}
+
+impl Validate for SetChatPhoto {}
+
+impl Validate for DeleteChatPhoto {}
+
+impl Validate for SetChatTitle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("title", &self.title, 1, 128)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetChatDescription {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(description) = &self.description {
+            validate::characters("description", description, 0, 255)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PinChatMessage {}
+
+impl Validate for UnpinChatMessage {}
+
+impl Validate for UnpinAllChatMessages {}
+
+impl Validate for LeaveChat {}
+
+impl Validate for GetChat {}
+
+impl Validate for GetChatAdministrators {}
+
+impl Validate for GetChatMemberCount {}
+
+impl Validate for GetChatMember {}
+
+impl Validate for SetChatStickerSet {}
+
+impl Validate for DeleteChatStickerSet {}
+
+impl Validate for GetForumTopicIconStickers {}
+
+impl Validate for CreateForumTopic {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("name", &self.name, 1, 128)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditForumTopic {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 128)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for CloseForumTopic {}
+
+impl Validate for ReopenForumTopic {}
+
+impl Validate for DeleteForumTopic {}
+
+impl Validate for UnpinAllForumTopicMessages {}
+
+impl Validate for EditGeneralForumTopic {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("name", &self.name, 1, 128)?;
+        Ok(())
+    }
+}
+
+impl Validate for CloseGeneralForumTopic {}
+
+impl Validate for ReopenGeneralForumTopic {}
+
+impl Validate for HideGeneralForumTopic {}
+
+impl Validate for UnhideGeneralForumTopic {}
+
+impl Validate for UnpinAllGeneralForumTopicMessages {}
+
+impl Validate for AnswerCallbackQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(text) = &self.text {
+            validate::characters("text", text, 0, 200)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetUserChatBoosts {}
+
+impl Validate for GetBusinessConnection {}
+
+impl Validate for SetMyCommands {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("commands", &self.commands, 0, 100)?;
+        validate::nested("commands", &self.commands)?;
+        Ok(())
+    }
+}
+
+impl Validate for DeleteMyCommands {}
+
+impl Validate for GetMyCommands {}
+
+impl Validate for SetMyName {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            validate::characters("name", name, 0, 64)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetMyName {}
+
+impl Validate for SetMyDescription {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(description) = &self.description {
+            validate::characters("description", description, 0, 512)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetMyDescription {}
+
+impl Validate for SetMyShortDescription {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(short_description) = &self.short_description {
+            validate::characters("short_description", short_description, 0, 120)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetMyShortDescription {}
+
+impl Validate for SetChatMenuButton {}
+
+impl Validate for GetChatMenuButton {}
+
+impl Validate for SetMyDefaultAdministratorRights {}
+
+impl Validate for GetMyDefaultAdministratorRights {}
+
+impl Validate for EditMessageText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.parse_mode.is_none() {
+            validate::characters("text", &self.text, 1, 4096)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditMessageCaption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(caption), None) = (&self.caption, &self.parse_mode) {
+            validate::characters("caption", caption, 0, 1024)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditMessageMedia {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("media", &self.media)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditMessageLiveLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(horizontal_accuracy) = self.horizontal_accuracy {
+            validate::range("horizontal_accuracy", horizontal_accuracy, 0, 1500)?;
+        }
+        if let Some(heading) = self.heading {
+            validate::range("heading", heading, 1, 360)?;
+        }
+        if let Some(proximity_alert_radius) = self.proximity_alert_radius {
+            validate::range("proximity_alert_radius", proximity_alert_radius, 1, 100000)?;
+        }
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for StopMessageLiveLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for EditMessageReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for StopPoll {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for DeleteMessage {}
+
+impl Validate for DeleteMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("message_ids", &self.message_ids, 1, 100)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for GetStickerSet {}
+
+impl Validate for GetCustomEmojiStickers {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("custom_emoji_ids", &self.custom_emoji_ids, 0, 200)?;
+        Ok(())
+    }
+}
+
+impl Validate for UploadStickerFile {}
+
+impl Validate for CreateNewStickerSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("name", &self.name, 1, 64)?;
+        validate::characters("title", &self.title, 1, 64)?;
+        validate::items("stickers", &self.stickers, 1, 50)?;
+        validate::nested("stickers", &self.stickers)?;
+        Ok(())
+    }
+}
+
+impl Validate for AddStickerToSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("sticker", &self.sticker)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetStickerPositionInSet {}
+
+impl Validate for DeleteStickerFromSet {}
+
+impl Validate for ReplaceStickerInSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("sticker", &self.sticker)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetStickerEmojiList {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("emoji_list", &self.emoji_list, 1, 20)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetStickerKeywords {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(keywords) = &self.keywords {
+            validate::items("keywords", keywords, 0, 20)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for SetStickerMaskPosition {}
+
+impl Validate for SetStickerSetTitle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("title", &self.title, 1, 64)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetStickerSetThumbnail {}
+
+impl Validate for SetCustomEmojiStickerSetThumbnail {}
+
+impl Validate for DeleteStickerSet {}
+
+impl Validate for AnswerInlineQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::items("results", &self.results, 0, 50)?;
+        validate::nested("results", &self.results)?;
+        if let Some(next_offset) = &self.next_offset {
+            validate::bytes("next_offset", next_offset, 0, 64)?;
+        }
+        validate::nested("button", &self.button)?;
+        Ok(())
+    }
+}
+
+impl Validate for AnswerWebAppQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("result", &self.result)?;
+        Ok(())
+    }
+}
+
+impl Validate for SendInvoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("title", &self.title, 1, 32)?;
+        validate::characters("description", &self.description, 1, 255)?;
+        validate::bytes("payload", &self.payload, 1, 128)?;
+        if let Some(suggested_tip_amounts) = &self.suggested_tip_amounts {
+            validate::items("suggested_tip_amounts", suggested_tip_amounts, 0, 4)?;
+        }
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        payments::check_invoice(self)
+    }
+}
+
+impl Validate for CreateInvoiceLink {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::characters("title", &self.title, 1, 32)?;
+        validate::characters("description", &self.description, 1, 255)?;
+        validate::bytes("payload", &self.payload, 1, 128)?;
+        if let Some(suggested_tip_amounts) = &self.suggested_tip_amounts {
+            validate::items("suggested_tip_amounts", suggested_tip_amounts, 0, 4)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for AnswerShippingQuery {}
+
+impl Validate for AnswerPreCheckoutQuery {}
+
+impl Validate for GetStarTransactions {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(limit) = self.limit {
+            validate::range("limit", limit, 1, 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for RefundStarPayment {}
+
+impl Validate for SetPassportDataErrors {}
+
+impl Validate for SendGame {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::nested("reply_parameters", &self.reply_parameters)?;
+        validate::nested("reply_markup", &self.reply_markup)?;
+        Ok(())
+    }
+}
+
+impl Validate for SetGameScore {}
+
+impl Validate for GetGameHighScores {}
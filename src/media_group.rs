@@ -0,0 +1,218 @@
+//! Reassembly of albums.
+//!
+//! The messages of an album come in separate updates, sharing a
+//! `media_group_id`, and nothing tells which one is the last. `MediaGroups`
+//! buffers them and hands out the album once no message of it came for a
+//! quiet period:
+//!
+//! ```text
+//! on a message => if let Some(message) = groups.push(message) { handle it alone },
+//! loop {
+//!     match groups.poll() {
+//!         Next::Ready(album) => handle the album,
+//!         Next::Wait(delay) => sleep for `delay`, or until more messages come,
+//!         Next::Idle => wait for more messages,
+//!     }
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use super::*;
+use crate::ratelimit::{Clock, SystemClock};
+
+
+/// The messages of an album, by id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Album {
+    pub media_group_id: String,
+    pub messages: Vec<Message>,
+}
+
+impl Album {
+    /// The chat the album was sent to.
+    pub fn chat(&self) -> &Chat {
+        &self.messages[0].chat
+    }
+
+    /// The media of the album, as sent again by file id. Thumbnails are left
+    /// out, as they can't be sent by file id.
+    pub fn input_media(&self) -> Result<Vec<InputMedia>, UnsupportedMedia> {
+        self.messages.iter().map(input_media).collect()
+    }
+
+    /// Sends the album again to `chat_id`, with the same captions.
+    pub fn to_send_request<C: Into<PolymorphChatId>>(&self, chat_id: C) -> Result<SendMediaGroup, UnsupportedMedia> {
+        Ok(SendMediaGroup::new(chat_id, self.input_media()?))
+    }
+}
+
+/// A message of an album without media that can be sent in albums.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedMedia {
+    pub message_id: i64,
+}
+
+impl fmt::Display for UnsupportedMedia {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "message {} has no photo, video, document or audio", self.message_id)
+    }
+}
+
+impl Error for UnsupportedMedia {}
+
+fn input_media(message: &Message) -> Result<InputMedia, UnsupportedMedia> {
+    let caption = message.caption.clone();
+    let entities = message.caption_entities.clone();
+    let above = message.show_caption_above_media;
+    let spoiler = message.has_media_spoiler;
+    let photo = message.photo.as_ref().and_then(|sizes| sizes.largest());
+    if let Some(photo) = photo {
+        let mut media = InputMediaPhoto::new(photo.file_id.clone());
+        media.caption = caption;
+        media.caption_entities = entities;
+        media.show_caption_above_media = above;
+        media.has_spoiler = spoiler;
+        return Ok(media.into());
+    }
+    if let Some(video) = &message.video {
+        let mut media = InputMediaVideo::new(video.file_id.clone())
+            .width(video.width)
+            .height(video.height)
+            .duration(video.duration);
+        media.caption = caption;
+        media.caption_entities = entities;
+        media.show_caption_above_media = above;
+        media.has_spoiler = spoiler;
+        return Ok(media.into());
+    }
+    if let Some(document) = &message.document {
+        let mut media = InputMediaDocument::new(document.file_id.clone());
+        media.caption = caption;
+        media.caption_entities = entities;
+        return Ok(media.into());
+    }
+    if let Some(audio) = &message.audio {
+        let mut media = InputMediaAudio::new(audio.file_id.clone()).duration(audio.duration);
+        media.performer = audio.performer.clone();
+        media.title = audio.title.clone();
+        media.caption = caption;
+        media.caption_entities = entities;
+        return Ok(media.into());
+    }
+    Err(UnsupportedMedia { message_id: message.message_id })
+}
+
+
+/// What the aggregator has to hand out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Next {
+    /// An album no message came for during the quiet period.
+    Ready(Album),
+    /// No album is complete before `Duration` from now.
+    Wait(Duration),
+    /// No message is buffered.
+    Idle,
+}
+
+/// An album being received.
+#[derive(Debug)]
+struct Pending {
+    chat_id: i64,
+    album: Album,
+    last_at: Duration,
+}
+
+/// Buffer of the messages of albums.
+#[derive(Debug)]
+pub struct MediaGroups<C: Clock = SystemClock> {
+    clock: C,
+    quiet_period: Duration,
+    /// Albums in the order their first message came in.
+    pending: Vec<Pending>,
+}
+
+impl MediaGroups<SystemClock> {
+    /// An aggregator handing out albums once no message of them came for
+    /// `quiet_period`.
+    pub fn new(quiet_period: Duration) -> Self {
+        MediaGroups::with_clock(SystemClock::new(), quiet_period)
+    }
+}
+
+impl<C: Clock> MediaGroups<C> {
+    pub fn with_clock(clock: C, quiet_period: Duration) -> Self {
+        MediaGroups { clock, quiet_period, pending: Vec::new() }
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Number of albums being received.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Buffers `message` if it's part of an album, or gives it back. A message
+    /// received twice, edited for instance, replaces the buffered one.
+    pub fn push(&mut self, message: Message) -> Option<Message> {
+        let media_group_id = match &message.media_group_id {
+            Some(id) => id.clone(),
+            None => return Some(message),
+        };
+        let now = self.clock.now();
+        let chat_id = message.chat.id;
+        let pending = self
+            .pending
+            .iter_mut()
+            .find(|pending| pending.chat_id == chat_id && pending.album.media_group_id == media_group_id);
+        match pending {
+            Some(pending) => {
+                let messages = &mut pending.album.messages;
+                match messages.iter_mut().find(|buffered| buffered.message_id == message.message_id) {
+                    Some(buffered) => *buffered = message,
+                    None => messages.push(message),
+                }
+                pending.last_at = now;
+            }
+            None => self.pending.push(Pending {
+                chat_id,
+                album: Album { media_group_id, messages: vec![message] },
+                last_at: now,
+            }),
+        }
+        None
+    }
+
+    /// The next complete album, if any.
+    pub fn poll(&mut self) -> Next {
+        let now = self.clock.now();
+        let ready = self.pending.iter().position(|pending| pending.last_at + self.quiet_period <= now);
+        if let Some(i) = ready {
+            return Next::Ready(sorted(self.pending.remove(i).album));
+        }
+        match self.pending.iter().map(|pending| pending.last_at + self.quiet_period).min() {
+            Some(ready_at) => Next::Wait(ready_at - now),
+            None => Next::Idle,
+        }
+    }
+
+    /// Every buffered album, complete or not, for instance before shutting
+    /// down.
+    pub fn flush(&mut self) -> Vec<Album> {
+        self.pending.drain(..).map(|pending| sorted(pending.album)).collect()
+    }
+}
+
+/// Updates can come out of order.
+fn sorted(mut album: Album) -> Album {
+    album.messages.sort_by_key(|message| message.message_id);
+    album
+}
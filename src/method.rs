@@ -0,0 +1,656 @@
+// Generated by `cargo run -p codegen` from `api/botapi.json` (Bot API 7.10,
+// September 6, 2024), do not edit by hand.
+
+//! The `Method` trait, which ties the parameters of each method to its name
+//! and to the type of its result.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::*;
+
+/// A method of the Bot API, implemented by the struct holding its parameters.
+pub trait Method: Serialize {
+    /// Name of the method, as used in the request URL.
+    const NAME: &'static str;
+    /// Type of the `result` field of a successful response.
+    type Response: DeserializeOwned;
+}
+
+/// Result of the methods returning Message or True.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MessageOrTrue {
+    Message(Box<Message>),
+    True(bool),
+}
+
+impl Method for GetUpdates {
+    const NAME: &'static str = "getUpdates";
+    type Response = Vec<Update>;
+}
+
+impl Method for SetWebhook {
+    const NAME: &'static str = "setWebhook";
+    type Response = bool;
+}
+
+impl Method for DeleteWebhook {
+    const NAME: &'static str = "deleteWebhook";
+    type Response = bool;
+}
+
+impl Method for GetWebhookInfo {
+    const NAME: &'static str = "getWebhookInfo";
+    type Response = WebhookInfo;
+}
+
+impl Method for GetMe {
+    const NAME: &'static str = "getMe";
+    type Response = User;
+}
+
+impl Method for LogOut {
+    const NAME: &'static str = "logOut";
+    type Response = bool;
+}
+
+impl Method for Close {
+    const NAME: &'static str = "close";
+    type Response = bool;
+}
+
+impl Method for SendMessage {
+    const NAME: &'static str = "sendMessage";
+    type Response = Message;
+}
+
+impl Method for ForwardMessage {
+    const NAME: &'static str = "forwardMessage";
+    type Response = Message;
+}
+
+impl Method for ForwardMessages {
+    const NAME: &'static str = "forwardMessages";
+    type Response = Vec<MessageId>;
+}
+
+impl Method for CopyMessage {
+    const NAME: &'static str = "copyMessage";
+    type Response = MessageId;
+}
+
+impl Method for CopyMessages {
+    const NAME: &'static str = "copyMessages";
+    type Response = Vec<MessageId>;
+}
+
+impl Method for SendPhoto {
+    const NAME: &'static str = "sendPhoto";
+    type Response = Message;
+}
+
+impl Method for SendAudio {
+    const NAME: &'static str = "sendAudio";
+    type Response = Message;
+}
+
+impl Method for SendDocument {
+    const NAME: &'static str = "sendDocument";
+    type Response = Message;
+}
+
+impl Method for SendVideo {
+    const NAME: &'static str = "sendVideo";
+    type Response = Message;
+}
+
+impl Method for SendAnimation {
+    const NAME: &'static str = "sendAnimation";
+    type Response = Message;
+}
+
+impl Method for SendVoice {
+    const NAME: &'static str = "sendVoice";
+    type Response = Message;
+}
+
+impl Method for SendVideoNote {
+    const NAME: &'static str = "sendVideoNote";
+    type Response = Message;
+}
+
+impl Method for SendPaidMedia {
+    const NAME: &'static str = "sendPaidMedia";
+    type Response = Message;
+}
+
+impl Method for SendMediaGroup {
+    const NAME: &'static str = "sendMediaGroup";
+    type Response = Vec<Message>;
+}
+
+impl Method for SendLocation {
+    const NAME: &'static str = "sendLocation";
+    type Response = Message;
+}
+
+impl Method for SendVenue {
+    const NAME: &'static str = "sendVenue";
+    type Response = Message;
+}
+
+impl Method for SendContact {
+    const NAME: &'static str = "sendContact";
+    type Response = Message;
+}
+
+impl Method for SendPoll {
+    const NAME: &'static str = "sendPoll";
+    type Response = Message;
+}
+
+impl Method for SendDice {
+    const NAME: &'static str = "sendDice";
+    type Response = Message;
+}
+
+impl Method for SendChatAction {
+    const NAME: &'static str = "sendChatAction";
+    type Response = bool;
+}
+
+impl Method for SetMessageReaction {
+    const NAME: &'static str = "setMessageReaction";
+    type Response = bool;
+}
+
+impl Method for GetUserProfilePhotos {
+    const NAME: &'static str = "getUserProfilePhotos";
+    type Response = UserProfilePhotos;
+}
+
+impl Method for GetFile {
+    const NAME: &'static str = "getFile";
+    type Response = File;
+}
+
+impl Method for BanChatMember {
+    const NAME: &'static str = "banChatMember";
+    type Response = bool;
+}
+
+impl Method for UnbanChatMember {
+    const NAME: &'static str = "unbanChatMember";
+    type Response = bool;
+}
+
+impl Method for RestrictChatMember {
+    const NAME: &'static str = "restrictChatMember";
+    type Response = bool;
+}
+
+impl Method for PromoteChatMember {
+    const NAME: &'static str = "promoteChatMember";
+    type Response = bool;
+}
+
+impl Method for SetChatAdministratorCustomTitle {
+    const NAME: &'static str = "setChatAdministratorCustomTitle";
+    type Response = bool;
+}
+
+impl Method for BanChatSenderChat {
+    const NAME: &'static str = "banChatSenderChat";
+    type Response = bool;
+}
+
+impl Method for UnbanChatSenderChat {
+    const NAME: &'static str = "unbanChatSenderChat";
+    type Response = bool;
+}
+
+impl Method for SetChatPermissions {
+    const NAME: &'static str = "setChatPermissions";
+    type Response = bool;
+}
+
+impl Method for ExportChatInviteLink {
+    const NAME: &'static str = "exportChatInviteLink";
+    type Response = String;
+}
+
+impl Method for CreateChatInviteLink {
+    const NAME: &'static str = "createChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+impl Method for EditChatInviteLink {
+    const NAME: &'static str = "editChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+impl Method for CreateChatSubscriptionInviteLink {
+    const NAME: &'static str = "createChatSubscriptionInviteLink";
+    type Response = ChatInviteLink;
+}
+
+impl Method for EditChatSubscriptionInviteLink {
+    const NAME: &'static str = "editChatSubscriptionInviteLink";
+    type Response = ChatInviteLink;
+}
+
+impl Method for RevokeChatInviteLink {
+    const NAME: &'static str = "revokeChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+impl Method for ApproveChatJoinRequest {
+    const NAME: &'static str = "approveChatJoinRequest";
+    type Response = bool;
+}
+
+impl Method for DeclineChatJoinRequest {
+    const NAME: &'static str = "declineChatJoinRequest";
+    type Response = bool;
+}
+
+impl Method for SetChatPhoto {
+    const NAME: &'static str = "setChatPhoto";
+    type Response = bool;
+}
+
+impl Method for DeleteChatPhoto {
+    const NAME: &'static str = "deleteChatPhoto";
+    type Response = bool;
+}
+
+impl Method for SetChatTitle {
+    const NAME: &'static str = "setChatTitle";
+    type Response = bool;
+}
+
+impl Method for SetChatDescription {
+    const NAME: &'static str = "setChatDescription";
+    type Response = bool;
+}
+
+impl Method for PinChatMessage {
+    const NAME: &'static str = "pinChatMessage";
+    type Response = bool;
+}
+
+impl Method for UnpinChatMessage {
+    const NAME: &'static str = "unpinChatMessage";
+    type Response = bool;
+}
+
+impl Method for UnpinAllChatMessages {
+    const NAME: &'static str = "unpinAllChatMessages";
+    type Response = bool;
+}
+
+impl Method for LeaveChat {
+    const NAME: &'static str = "leaveChat";
+    type Response = bool;
+}
+
+impl Method for GetChat {
+    const NAME: &'static str = "getChat";
+    type Response = ChatFullInfo;
+}
+
+impl Method for GetChatAdministrators {
+    const NAME: &'static str = "getChatAdministrators";
+    type Response = Vec<ChatMember>;
+}
+
+impl Method for GetChatMemberCount {
+    const NAME: &'static str = "getChatMemberCount";
+    type Response = i64;
+}
+
+impl Method for GetChatMember {
+    const NAME: &'static str = "getChatMember";
+    type Response = ChatMember;
+}
+
+impl Method for SetChatStickerSet {
+    const NAME: &'static str = "setChatStickerSet";
+    type Response = bool;
+}
+
+impl Method for DeleteChatStickerSet {
+    const NAME: &'static str = "deleteChatStickerSet";
+    type Response = bool;
+}
+
+impl Method for GetForumTopicIconStickers {
+    const NAME: &'static str = "getForumTopicIconStickers";
+    type Response = Vec<Sticker>;
+}
+
+impl Method for CreateForumTopic {
+    const NAME: &'static str = "createForumTopic";
+    type Response = ForumTopic;
+}
+
+impl Method for EditForumTopic {
+    const NAME: &'static str = "editForumTopic";
+    type Response = bool;
+}
+
+impl Method for CloseForumTopic {
+    const NAME: &'static str = "closeForumTopic";
+    type Response = bool;
+}
+
+impl Method for ReopenForumTopic {
+    const NAME: &'static str = "reopenForumTopic";
+    type Response = bool;
+}
+
+impl Method for DeleteForumTopic {
+    const NAME: &'static str = "deleteForumTopic";
+    type Response = bool;
+}
+
+impl Method for UnpinAllForumTopicMessages {
+    const NAME: &'static str = "unpinAllForumTopicMessages";
+    type Response = bool;
+}
+
+impl Method for EditGeneralForumTopic {
+    const NAME: &'static str = "editGeneralForumTopic";
+    type Response = bool;
+}
+
+impl Method for CloseGeneralForumTopic {
+    const NAME: &'static str = "closeGeneralForumTopic";
+    type Response = bool;
+}
+
+impl Method for ReopenGeneralForumTopic {
+    const NAME: &'static str = "reopenGeneralForumTopic";
+    type Response = bool;
+}
+
+impl Method for HideGeneralForumTopic {
+    const NAME: &'static str = "hideGeneralForumTopic";
+    type Response = bool;
+}
+
+impl Method for UnhideGeneralForumTopic {
+    const NAME: &'static str = "unhideGeneralForumTopic";
+    type Response = bool;
+}
+
+impl Method for UnpinAllGeneralForumTopicMessages {
+    const NAME: &'static str = "unpinAllGeneralForumTopicMessages";
+    type Response = bool;
+}
+
+impl Method for AnswerCallbackQuery {
+    const NAME: &'static str = "answerCallbackQuery";
+    type Response = bool;
+}
+
+impl Method for GetUserChatBoosts {
+    const NAME: &'static str = "getUserChatBoosts";
+    type Response = UserChatBoosts;
+}
+
+impl Method for GetBusinessConnection {
+    const NAME: &'static str = "getBusinessConnection";
+    type Response = BusinessConnection;
+}
+
+impl Method for SetMyCommands {
+    const NAME: &'static str = "setMyCommands";
+    type Response = bool;
+}
+
+impl Method for DeleteMyCommands {
+    const NAME: &'static str = "deleteMyCommands";
+    type Response = bool;
+}
+
+impl Method for GetMyCommands {
+    const NAME: &'static str = "getMyCommands";
+    type Response = Vec<BotCommand>;
+}
+
+impl Method for SetMyName {
+    const NAME: &'static str = "setMyName";
+    type Response = bool;
+}
+
+impl Method for GetMyName {
+    const NAME: &'static str = "getMyName";
+    type Response = BotName;
+}
+
+impl Method for SetMyDescription {
+    const NAME: &'static str = "setMyDescription";
+    type Response = bool;
+}
+
+impl Method for GetMyDescription {
+    const NAME: &'static str = "getMyDescription";
+    type Response = BotDescription;
+}
+
+impl Method for SetMyShortDescription {
+    const NAME: &'static str = "setMyShortDescription";
+    type Response = bool;
+}
+
+impl Method for GetMyShortDescription {
+    const NAME: &'static str = "getMyShortDescription";
+    type Response = BotShortDescription;
+}
+
+impl Method for SetChatMenuButton {
+    const NAME: &'static str = "setChatMenuButton";
+    type Response = bool;
+}
+
+impl Method for GetChatMenuButton {
+    const NAME: &'static str = "getChatMenuButton";
+    type Response = MenuButton;
+}
+
+impl Method for SetMyDefaultAdministratorRights {
+    const NAME: &'static str = "setMyDefaultAdministratorRights";
+    type Response = bool;
+}
+
+impl Method for GetMyDefaultAdministratorRights {
+    const NAME: &'static str = "getMyDefaultAdministratorRights";
+    type Response = ChatAdministratorRights;
+}
+
+impl Method for EditMessageText {
+    const NAME: &'static str = "editMessageText";
+    type Response = MessageOrTrue;
+}
+
+impl Method for EditMessageCaption {
+    const NAME: &'static str = "editMessageCaption";
+    type Response = MessageOrTrue;
+}
+
+impl Method for EditMessageMedia {
+    const NAME: &'static str = "editMessageMedia";
+    type Response = MessageOrTrue;
+}
+
+impl Method for EditMessageLiveLocation {
+    const NAME: &'static str = "editMessageLiveLocation";
+    type Response = MessageOrTrue;
+}
+
+impl Method for StopMessageLiveLocation {
+    const NAME: &'static str = "stopMessageLiveLocation";
+    type Response = MessageOrTrue;
+}
+
+impl Method for EditMessageReplyMarkup {
+    const NAME: &'static str = "editMessageReplyMarkup";
+    type Response = MessageOrTrue;
+}
+
+impl Method for StopPoll {
+    const NAME: &'static str = "stopPoll";
+    type Response = Poll;
+}
+
+impl Method for DeleteMessage {
+    const NAME: &'static str = "deleteMessage";
+    type Response = bool;
+}
+
+impl Method for DeleteMessages {
+    const NAME: &'static str = "deleteMessages";
+    type Response = bool;
+}
+
+impl Method for SendSticker {
+    const NAME: &'static str = "sendSticker";
+    type Response = Message;
+}
+
+impl Method for GetStickerSet {
+    const NAME: &'static str = "getStickerSet";
+    type Response = StickerSet;
+}
+
+impl Method for GetCustomEmojiStickers {
+    const NAME: &'static str = "getCustomEmojiStickers";
+    type Response = Vec<Sticker>;
+}
+
+impl Method for UploadStickerFile {
+    const NAME: &'static str = "uploadStickerFile";
+    type Response = File;
+}
+
+impl Method for CreateNewStickerSet {
+    const NAME: &'static str = "createNewStickerSet";
+    type Response = bool;
+}
+
+impl Method for AddStickerToSet {
+    const NAME: &'static str = "addStickerToSet";
+    type Response = bool;
+}
+
+impl Method for SetStickerPositionInSet {
+    const NAME: &'static str = "setStickerPositionInSet";
+    type Response = bool;
+}
+
+impl Method for DeleteStickerFromSet {
+    const NAME: &'static str = "deleteStickerFromSet";
+    type Response = bool;
+}
+
+impl Method for ReplaceStickerInSet {
+    const NAME: &'static str = "replaceStickerInSet";
+    type Response = bool;
+}
+
+impl Method for SetStickerEmojiList {
+    const NAME: &'static str = "setStickerEmojiList";
+    type Response = bool;
+}
+
+impl Method for SetStickerKeywords {
+    const NAME: &'static str = "setStickerKeywords";
+    type Response = bool;
+}
+
+impl Method for SetStickerMaskPosition {
+    const NAME: &'static str = "setStickerMaskPosition";
+    type Response = bool;
+}
+
+impl Method for SetStickerSetTitle {
+    const NAME: &'static str = "setStickerSetTitle";
+    type Response = bool;
+}
+
+impl Method for SetStickerSetThumbnail {
+    const NAME: &'static str = "setStickerSetThumbnail";
+    type Response = bool;
+}
+
+impl Method for SetCustomEmojiStickerSetThumbnail {
+    const NAME: &'static str = "setCustomEmojiStickerSetThumbnail";
+    type Response = bool;
+}
+
+impl Method for DeleteStickerSet {
+    const NAME: &'static str = "deleteStickerSet";
+    type Response = bool;
+}
+
+impl Method for AnswerInlineQuery {
+    const NAME: &'static str = "answerInlineQuery";
+    type Response = bool;
+}
+
+impl Method for AnswerWebAppQuery {
+    const NAME: &'static str = "answerWebAppQuery";
+    type Response = SentWebAppMessage;
+}
+
+impl Method for SendInvoice {
+    const NAME: &'static str = "sendInvoice";
+    type Response = Message;
+}
+
+impl Method for CreateInvoiceLink {
+    const NAME: &'static str = "createInvoiceLink";
+    type Response = String;
+}
+
+impl Method for AnswerShippingQuery {
+    const NAME: &'static str = "answerShippingQuery";
+    type Response = bool;
+}
+
+impl Method for AnswerPreCheckoutQuery {
+    const NAME: &'static str = "answerPreCheckoutQuery";
+    type Response = bool;
+}
+
+impl Method for GetStarTransactions {
+    const NAME: &'static str = "getStarTransactions";
+    type Response = StarTransactions;
+}
+
+impl Method for RefundStarPayment {
+    const NAME: &'static str = "refundStarPayment";
+    type Response = bool;
+}
+
+impl Method for SetPassportDataErrors {
+    const NAME: &'static str = "setPassportDataErrors";
+    type Response = bool;
+}
+
+impl Method for SendGame {
+    const NAME: &'static str = "sendGame";
+    type Response = Message;
+}
+
+impl Method for SetGameScore {
+    const NAME: &'static str = "setGameScore";
+    type Response = MessageOrTrue;
+}
+
+impl Method for GetGameHighScores {
+    const NAME: &'static str = "getGameHighScores";
+    type Response = Vec<GameHighScore>;
+}
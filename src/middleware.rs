@@ -0,0 +1,470 @@
+//! Layers around the processing of updates and the execution of requests.
+//!
+//! Both are `Service`s: the processing of updates takes `Incoming` updates,
+//! typically ending in a `Dispatcher`, and the execution of requests takes
+//! `Request`s, ending in the HTTP client of the application. A `Layer` wraps
+//! a service into another one, adding to what it does:
+//!
+//! ```text
+//! let updates = dispatcher.with(I18nLayer::new(...)).with(AccessLayer::allow(...)).with(LogLayer::new(...));
+//! let requests = client.with(MetricsLayer::new(metrics.clone())).with(LogLayer::new(...));
+//! ```
+//!
+//! The last layer added is the outermost, it sees the updates first.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use super::*;
+use crate::dispatch::{BoxError, Dispatcher, Failures};
+
+
+/// A future that can be sent between threads.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An asynchronous function from requests to responses.
+pub trait Service<R>: Send + Sync {
+    type Response;
+    type Error;
+
+    fn call(&self, request: R) -> BoxFuture<'_, Result<Self::Response, Self::Error>>;
+}
+
+/// A wrapper of services.
+pub trait Layer<S> {
+    type Service;
+
+    fn layer(&self, inner: S) -> Self::Service;
+}
+
+pub trait ServiceExt: Sized {
+    /// Wraps the service in `layer`.
+    fn with<L: Layer<Self>>(self, layer: L) -> L::Service {
+        layer.layer(self)
+    }
+}
+
+impl<S> ServiceExt for S {}
+
+/// A service calling a function.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceFn<F>(F);
+
+/// The service calling `f`.
+pub fn service_fn<F>(f: F) -> ServiceFn<F> {
+    ServiceFn(f)
+}
+
+impl<R, F, U, T, E> Service<R> for ServiceFn<F>
+where
+    F: Fn(R) -> U + Send + Sync,
+    U: Future<Output = Result<T, E>> + Send + 'static,
+{
+    type Response = T;
+    type Error = E;
+
+    fn call(&self, request: R) -> BoxFuture<'_, Result<T, E>> {
+        Box::pin((self.0)(request))
+    }
+}
+
+/// An update, with what the layers it went through found out about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incoming {
+    pub update: Update,
+    /// Locale to answer in, set by `I18nLayer`
+    pub locale: Option<String>,
+}
+
+impl From<Update> for Incoming {
+    fn from(update: Update) -> Self {
+        Incoming { update, locale: None }
+    }
+}
+
+/// Dispatches the updates, failing with the `Failures` of the handlers that
+/// failed.
+impl Service<Incoming> for Dispatcher {
+    type Response = ();
+    type Error = BoxError;
+
+    fn call(&self, incoming: Incoming) -> BoxFuture<'_, Result<(), BoxError>> {
+        Box::pin(async move {
+            let failures = self.dispatch(incoming.update).await.failures;
+            if failures.is_empty() {
+                Ok(())
+            } else {
+                Err(Failures(failures).into())
+            }
+        })
+    }
+}
+
+
+/// Logs the updates and the requests, with their outcome, to a function
+/// taking the lines.
+#[derive(Debug, Clone)]
+pub struct LogLayer<F> {
+    sink: Arc<F>,
+}
+
+impl<F: Fn(&str) + Send + Sync> LogLayer<F> {
+    pub fn new(sink: F) -> Self {
+        LogLayer { sink: Arc::new(sink) }
+    }
+}
+
+impl<S, F> Layer<S> for LogLayer<F> {
+    type Service = Log<S, F>;
+
+    fn layer(&self, inner: S) -> Log<S, F> {
+        Log { inner, sink: self.sink.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Log<S, F> {
+    inner: S,
+    sink: Arc<F>,
+}
+
+impl<S, F> Service<Incoming> for Log<S, F>
+where
+    S: Service<Incoming>,
+    S::Error: fmt::Display + Send,
+    S::Response: Send,
+    F: Fn(&str) + Send + Sync,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+
+    fn call(&self, incoming: Incoming) -> BoxFuture<'_, Result<S::Response, S::Error>> {
+        let mut line = format!("update {}", incoming.update.update_id);
+        if let Some(kind) = incoming.update.kind() {
+            line += &format!(" ({:?})", kind);
+        }
+        if let Some(user) = incoming.update.user() {
+            line += &format!(" from user {}", user.id);
+        }
+        if let Some(chat) = incoming.update.chat() {
+            line += &format!(" in chat {}", chat.id);
+        }
+        Box::pin(async move {
+            let result = self.inner.call(incoming).await;
+            match &result {
+                Ok(_) => (self.sink)(&line),
+                Err(err) => (self.sink)(&format!("{}: {}", line, err)),
+            }
+            result
+        })
+    }
+}
+
+impl<S, F> Service<Request> for Log<S, F>
+where
+    S: Service<Request>,
+    S::Error: fmt::Display + Send,
+    S::Response: Send,
+    F: Fn(&str) + Send + Sync,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+
+    fn call(&self, request: Request) -> BoxFuture<'_, Result<S::Response, S::Error>> {
+        let method = request.method();
+        Box::pin(async move {
+            let result = self.inner.call(request).await;
+            match &result {
+                Ok(_) => (self.sink)(&format!("{}: ok", method)),
+                Err(err) => (self.sink)(&format!("{}: {}", method, err)),
+            }
+            result
+        })
+    }
+}
+
+
+/// Statistics of the requests of a method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MethodStats {
+    pub calls: u64,
+    pub errors: u64,
+    /// Time spent waiting for the responses
+    pub time: Duration,
+}
+
+/// Statistics of the requests by method, shared by the services of a
+/// `MetricsLayer` and the application reading them.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    methods: Mutex<HashMap<&'static str, MethodStats>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics::default()
+    }
+
+    /// Statistics of the requests of the method named `name`.
+    pub fn get(&self, name: &str) -> MethodStats {
+        self.methods.lock().unwrap().get(name).copied().unwrap_or_default()
+    }
+
+    /// Statistics of every method requested so far, by name.
+    pub fn snapshot(&self) -> HashMap<&'static str, MethodStats> {
+        self.methods.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: &'static str, ok: bool, time: Duration) {
+        let mut methods = self.methods.lock().unwrap();
+        let stats = methods.entry(method).or_default();
+        stats.calls += 1;
+        stats.errors += u64::from(!ok);
+        stats.time += time;
+    }
+}
+
+/// Counts the requests and their errors, and times them, by method.
+#[derive(Debug, Clone)]
+pub struct MetricsLayer {
+    metrics: Arc<Metrics>,
+}
+
+impl MetricsLayer {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        MetricsLayer { metrics }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = Measured<S>;
+
+    fn layer(&self, inner: S) -> Measured<S> {
+        Measured { inner, metrics: self.metrics.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measured<S> {
+    inner: S,
+    metrics: Arc<Metrics>,
+}
+
+impl<S> Service<Request> for Measured<S>
+where
+    S: Service<Request>,
+    S::Error: Send,
+    S::Response: Send,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+
+    fn call(&self, request: Request) -> BoxFuture<'_, Result<S::Response, S::Error>> {
+        let method = request.method();
+        Box::pin(async move {
+            let start = Instant::now();
+            let result = self.inner.call(request).await;
+            self.metrics.record(method, result.is_ok(), start.elapsed());
+            result
+        })
+    }
+}
+
+
+/// Drops the updates of users not allowed to use the bot.
+#[derive(Debug, Clone)]
+pub struct AccessLayer {
+    users: Arc<HashSet<i64>>,
+    allow: bool,
+}
+
+impl AccessLayer {
+    /// Lets through the updates of the users with the given ids only.
+    pub fn allow<I: IntoIterator<Item = i64>>(ids: I) -> Self {
+        AccessLayer { users: Arc::new(ids.into_iter().collect()), allow: true }
+    }
+
+    /// Drops the updates of the users with the given ids.
+    pub fn deny<I: IntoIterator<Item = i64>>(ids: I) -> Self {
+        AccessLayer { users: Arc::new(ids.into_iter().collect()), allow: false }
+    }
+
+    /// Whether the updates of `user` go through, those of no user only do
+    /// with deny lists.
+    pub fn allows(&self, user: Option<&User>) -> bool {
+        match user {
+            Some(user) => self.users.contains(&user.id) == self.allow,
+            None => !self.allow,
+        }
+    }
+}
+
+impl<S> Layer<S> for AccessLayer {
+    type Service = Access<S>;
+
+    fn layer(&self, inner: S) -> Access<S> {
+        Access { inner, layer: self.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Access<S> {
+    inner: S,
+    layer: AccessLayer,
+}
+
+impl<S> Service<Incoming> for Access<S>
+where
+    S: Service<Incoming, Response = ()>,
+{
+    type Response = ();
+    type Error = S::Error;
+
+    fn call(&self, incoming: Incoming) -> BoxFuture<'_, Result<(), S::Error>> {
+        if self.layer.allows(incoming.update.user()) {
+            self.inner.call(incoming)
+        } else {
+            Box::pin(async { Ok(()) })
+        }
+    }
+}
+
+
+/// Sets the locale of the updates from the language of their user.
+#[derive(Debug, Clone)]
+pub struct I18nLayer {
+    locales: Arc<Vec<String>>,
+    fallback: String,
+}
+
+impl I18nLayer {
+    /// Picks the locale among `locales`, or `fallback` for languages none of
+    /// them matches.
+    pub fn new<I, T, S>(locales: I, fallback: S) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+        S: Into<String>,
+    {
+        I18nLayer {
+            locales: Arc::new(locales.into_iter().map(Into::into).collect()),
+            fallback: fallback.into(),
+        }
+    }
+
+    /// The locale for the IETF language tag `language`: the supported locale
+    /// equal to it, or else to its primary language, ignoring case.
+    pub fn locale(&self, language: Option<&str>) -> &str {
+        let language = match language {
+            Some(language) => language,
+            None => return &self.fallback,
+        };
+        let primary = language.split(['-', '_']).next().unwrap_or(language);
+        let find = |tag: &str| self.locales.iter().find(|locale| locale.eq_ignore_ascii_case(tag));
+        find(language).or_else(|| find(primary)).unwrap_or(&self.fallback)
+    }
+}
+
+impl<S> Layer<S> for I18nLayer {
+    type Service = I18n<S>;
+
+    fn layer(&self, inner: S) -> I18n<S> {
+        I18n { inner, layer: self.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct I18n<S> {
+    inner: S,
+    layer: I18nLayer,
+}
+
+impl<S: Service<Incoming>> Service<Incoming> for I18n<S> {
+    type Response = S::Response;
+    type Error = S::Error;
+
+    fn call(&self, mut incoming: Incoming) -> BoxFuture<'_, Result<S::Response, S::Error>> {
+        let language = incoming.update.user().and_then(|user| user.language_code.as_deref());
+        incoming.locale = Some(self.layer.locale(language).to_string());
+        self.inner.call(incoming)
+    }
+}
+
+
+/// Reports the errors of the processing of updates to an admin chat, with
+/// the service executing requests. Reports longer than a message are cut
+/// short.
+#[derive(Debug)]
+pub struct ReportLayer<O> {
+    requests: Arc<O>,
+    chat_id: PolymorphChatId,
+}
+
+impl<O> ReportLayer<O> {
+    pub fn new<C: Into<PolymorphChatId>>(requests: Arc<O>, chat_id: C) -> Self {
+        ReportLayer { requests, chat_id: chat_id.into() }
+    }
+}
+
+impl<S, O> Layer<S> for ReportLayer<O> {
+    type Service = Report<S, O>;
+
+    fn layer(&self, inner: S) -> Report<S, O> {
+        Report {
+            inner,
+            requests: self.requests.clone(),
+            chat_id: self.chat_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Report<S, O> {
+    inner: S,
+    requests: Arc<O>,
+    chat_id: PolymorphChatId,
+}
+
+impl<S, O> Service<Incoming> for Report<S, O>
+where
+    S: Service<Incoming>,
+    S::Error: fmt::Display + Send,
+    S::Response: Send,
+    O: Service<Request, Response = Value>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+
+    /// Fails with the error of the inner service, whether it could be
+    /// reported or not.
+    fn call(&self, incoming: Incoming) -> BoxFuture<'_, Result<S::Response, S::Error>> {
+        let update_id = incoming.update.update_id;
+        Box::pin(async move {
+            let result = self.inner.call(incoming).await;
+            let report = result.as_ref().err().map(|err| shorten(format!("Update {} failed: {}", update_id, err)));
+            if let Some(text) = report {
+                let _ = self.requests.call(Request::new(&SendMessage::new(self.chat_id.clone(), text))).await;
+            }
+            result
+        })
+    }
+}
+
+/// Characters of a message text
+const MESSAGE_LENGTH: usize = 4096;
+
+/// `text` cut to the length of a message, ending with an ellipsis when cut.
+fn shorten(mut text: String) -> String {
+    if text.chars().count() > MESSAGE_LENGTH {
+        let (end, _) = text.char_indices().nth(MESSAGE_LENGTH - 1).unwrap();
+        text.truncate(end);
+        text.push('…');
+    }
+    text
+}
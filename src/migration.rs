@@ -0,0 +1,171 @@
+//! Tracking of groups upgraded to supergroups.
+//!
+//! A group upgraded to a supergroup gets a new id: the last message of the
+//! group has `migrate_to_chat_id`, the first one of the supergroup has
+//! `migrate_from_chat_id`, and requests still sent to the old id fail with
+//! `migrate_to_chat_id` in their `ResponseParameters`. `Migrations` learns the
+//! new ids from all three and rewrites the chat ids of outgoing requests. Each
+//! migration it learns is returned once, for applications to update the ids
+//! they store, and saved to a `MigrationStore`.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::*;
+
+
+/// A group that became a supergroup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Migration {
+    /// Id of the group
+    pub from: i64,
+    /// Id of the supergroup
+    pub to: i64,
+}
+
+/// Persistence of the migrations.
+pub trait MigrationStore {
+    type Error;
+
+    /// Every migration saved so far.
+    fn load(&mut self) -> Result<Vec<Migration>, Self::Error>;
+
+    fn save(&mut self, migration: Migration) -> Result<(), Self::Error>;
+}
+
+/// A store keeping the migrations in memory only.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryStore {
+    pub migrations: Vec<Migration>,
+}
+
+impl MigrationStore for MemoryStore {
+    type Error = Infallible;
+
+    fn load(&mut self) -> Result<Vec<Migration>, Infallible> {
+        Ok(self.migrations.clone())
+    }
+
+    fn save(&mut self, migration: Migration) -> Result<(), Infallible> {
+        self.migrations.push(migration);
+        Ok(())
+    }
+}
+
+
+/// Parameters of the requests holding the id of a chat, as paths of keys.
+const CHAT_PARAMS: &[&[&str]] = &[&["chat_id"], &["from_chat_id"], &["reply_parameters", "chat_id"]];
+
+/// The migrations known so far, with the store they're saved to.
+#[derive(Debug)]
+pub struct Migrations<S: MigrationStore = MemoryStore> {
+    store: S,
+    to: HashMap<i64, i64>,
+}
+
+impl Migrations<MemoryStore> {
+    pub fn new() -> Self {
+        Migrations {
+            store: MemoryStore::default(),
+            to: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Migrations<MemoryStore> {
+    fn default() -> Self {
+        Migrations::new()
+    }
+}
+
+impl<S: MigrationStore> Migrations<S> {
+    /// The migrations saved to `store`.
+    pub fn load(mut store: S) -> Result<Self, S::Error> {
+        let to = store.load()?.into_iter().map(|migration| (migration.from, migration.to)).collect();
+        Ok(Migrations { store, to })
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Current id of the chat once known as `chat_id`.
+    pub fn resolve(&self, chat_id: i64) -> i64 {
+        let mut current = chat_id;
+        // A supergroup can't be migrated again, but the store could hold
+        // anything.
+        for _ in 0..self.to.len() {
+            match self.to.get(&current) {
+                Some(&to) => current = to,
+                None => break,
+            }
+        }
+        current
+    }
+
+    /// Learns the migration announced by a service message.
+    pub fn learn_message(&mut self, message: &Message) -> Result<Option<Migration>, S::Error> {
+        if let Some(to) = message.migrate_to_chat_id {
+            return self.learn(Migration { from: message.chat.id, to });
+        }
+        if let Some(from) = message.migrate_from_chat_id {
+            return self.learn(Migration { from, to: message.chat.id });
+        }
+        Ok(None)
+    }
+
+    /// Learns the migration reported by the error response to `request`.
+    pub fn learn_error(&mut self, request: &Request, parameters: &ResponseParameters) -> Result<Option<Migration>, S::Error> {
+        match (request.chat_id(), parameters.migrate_to_chat_id) {
+            (Some(PolymorphChatId::Integer(from)), Some(to)) => self.learn(Migration { from, to }),
+            _ => Ok(None),
+        }
+    }
+
+    /// Saves `migration`, returning it if it wasn't known.
+    pub fn learn(&mut self, migration: Migration) -> Result<Option<Migration>, S::Error> {
+        if self.to.get(&migration.from) == Some(&migration.to) || migration.from == migration.to {
+            return Ok(None);
+        }
+        self.store.save(migration)?;
+        self.to.insert(migration.from, migration.to);
+        Ok(Some(migration))
+    }
+
+    /// Replaces the ids of migrated groups in the chat ids of `request`.
+    /// Returns whether any was replaced.
+    pub fn rewrite(&self, request: &mut Request) -> bool {
+        let mut rewritten = false;
+        for &path in CHAT_PARAMS {
+            let mut value = request.params_mut().get_mut(path[0]);
+            for &key in &path[1..] {
+                value = value.and_then(|value| value.get_mut(key));
+            }
+            if let Some(Value::Number(id)) = value {
+                if let Some(old) = id.as_i64() {
+                    let new = self.resolve(old);
+                    if new != old {
+                        *id = new.into();
+                        rewritten = true;
+                    }
+                }
+            }
+        }
+        rewritten
+    }
+
+    /// Replaces the ids of migrated groups in the chat ids of `method`.
+    /// Returns whether any was replaced, or the error of reading the rewritten
+    /// parameters back into `M`.
+    pub fn rewrite_method<M: Method + DeserializeOwned>(&self, method: &mut M) -> Result<bool, serde_json::Error> {
+        let mut request = Request::new(method);
+        if !self.rewrite(&mut request) {
+            return Ok(false);
+        }
+        *method = serde_json::from_value(Value::Object(request.params().clone()))?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,10564 @@
+// Generated by `cargo run -p codegen` from `api/botapi.json` (Bot API 7.10,
+// September 6, 2024), do not edit by hand.
+
+//! The objects of the Bot API, in the order of its documentation, and the
+//! parameters of its methods.
+
+use super::*;
+
+pub use crate::method::{MessageOrTrue, Method};
+
+
+/// This object represents an incoming update.At most one of the optional parameters
+/// can be present in any given update.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Update {
+    /// The update's unique identifier. Update identifiers start from a certain
+    /// positive number and increase sequentially. This identifier becomes
+    /// especially handy if you're using webhooks, since it allows you to ignore
+    /// repeated updates or to restore the correct update sequence, should they get
+    /// out of order. If there are no new updates for at least a week, then
+    /// identifier of the next update will be chosen randomly instead of
+    /// sequentially.
+    pub update_id: i64,
+    /// Optional. New incoming message of any kind — text, photo, sticker, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<Message>>,
+    /// Optional. New version of a message that is known to the bot and was edited.
+    /// This update may at times be triggered by changes to message fields that are
+    /// either unavailable or not actively used by your bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_message: Option<Box<Message>>,
+    /// Optional. New incoming channel post of any kind — text, photo, sticker, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_post: Option<Box<Message>>,
+    /// Optional. New version of a channel post that is known to the bot and was
+    /// edited. This update may at times be triggered by changes to message fields
+    /// that are either unavailable or not actively used by your bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_channel_post: Option<Box<Message>>,
+    /// Optional. The bot was connected to or disconnected from a business account,
+    /// or a user edited an existing connection with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection: Option<BusinessConnection>,
+    /// Optional. New message from a connected business account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_message: Option<Box<Message>>,
+    /// Optional. New version of a message from a connected business account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_business_message: Option<Box<Message>>,
+    /// Optional. Messages were deleted from a connected business account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_business_messages: Option<BusinessMessagesDeleted>,
+    /// Optional. A reaction to a message was changed by a user. The bot must be an
+    /// administrator in the chat and must explicitly specify "message_reaction" in
+    /// the list of allowed_updates to receive these updates. The update isn't
+    /// received for reactions set by bots.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reaction: Option<MessageReactionUpdated>,
+    /// Optional. Reactions to a message with anonymous reactions were changed. The
+    /// bot must be an administrator in the chat and must explicitly specify
+    /// "message_reaction_count" in the list of allowed_updates to receive these
+    /// updates. The updates are grouped and can be sent with delay up to a few
+    /// minutes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reaction_count: Option<MessageReactionCountUpdated>,
+    /// Optional. New incoming inline query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_query: Option<InlineQuery>,
+    /// Optional. The result of an inline query that was chosen by a user and sent
+    /// to their chat partner. Please see our documentation on the feedback
+    /// collecting for details on how to enable these updates for your bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen_inline_result: Option<ChosenInlineResult>,
+    /// Optional. New incoming callback query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_query: Option<CallbackQuery>,
+    /// Optional. New incoming shipping query. Only for invoices with flexible price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_query: Option<ShippingQuery>,
+    /// Optional. New incoming pre-checkout query. Contains full information about
+    /// checkout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_checkout_query: Option<PreCheckoutQuery>,
+    /// Optional. A user purchased paid media with a non-empty payload sent by the
+    /// bot in a non-channel chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchased_paid_media: Option<PaidMediaPurchased>,
+    /// Optional. New poll state. Bots receive only updates about manually stopped
+    /// polls and polls, which are sent by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// Optional. A user changed their answer in a non-anonymous poll. Bots receive
+    /// new votes only in polls that were sent by the bot itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_answer: Option<PollAnswer>,
+    /// Optional. The bot's chat member status was updated in a chat. For private
+    /// chats, this update is received only when the bot is blocked or unblocked by
+    /// the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub my_chat_member: Option<ChatMemberUpdated>,
+    /// Optional. A chat member's status was updated in a chat. The bot must be an
+    /// administrator in the chat and must explicitly specify "chat_member" in the
+    /// list of allowed_updates to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_member: Option<ChatMemberUpdated>,
+    /// Optional. A request to join the chat has been sent. The bot must have the
+    /// can_invite_users administrator right in the chat to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_join_request: Option<ChatJoinRequest>,
+    /// Optional. A chat boost was added or changed. The bot must be an
+    /// administrator in the chat to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_boost: Option<ChatBoostUpdated>,
+    /// Optional. A boost was removed from a chat. The bot must be an administrator
+    /// in the chat to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_chat_boost: Option<ChatBoostRemoved>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Kind of an update, named after the field of `Update` holding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateKind {
+    Message,
+    EditedMessage,
+    ChannelPost,
+    EditedChannelPost,
+    BusinessConnection,
+    BusinessMessage,
+    EditedBusinessMessage,
+    DeletedBusinessMessages,
+    MessageReaction,
+    MessageReactionCount,
+    InlineQuery,
+    ChosenInlineResult,
+    CallbackQuery,
+    ShippingQuery,
+    PreCheckoutQuery,
+    PurchasedPaidMedia,
+    Poll,
+    PollAnswer,
+    MyChatMember,
+    ChatMember,
+    ChatJoinRequest,
+    ChatBoost,
+    RemovedChatBoost,
+}
+
+impl Update {
+    /// Kind of the update, `None` for kinds unknown to this version of the
+    /// models.
+    pub fn kind(&self) -> Option<UpdateKind> {
+        if self.message.is_some() {
+            Some(UpdateKind::Message)
+        } else if self.edited_message.is_some() {
+            Some(UpdateKind::EditedMessage)
+        } else if self.channel_post.is_some() {
+            Some(UpdateKind::ChannelPost)
+        } else if self.edited_channel_post.is_some() {
+            Some(UpdateKind::EditedChannelPost)
+        } else if self.business_connection.is_some() {
+            Some(UpdateKind::BusinessConnection)
+        } else if self.business_message.is_some() {
+            Some(UpdateKind::BusinessMessage)
+        } else if self.edited_business_message.is_some() {
+            Some(UpdateKind::EditedBusinessMessage)
+        } else if self.deleted_business_messages.is_some() {
+            Some(UpdateKind::DeletedBusinessMessages)
+        } else if self.message_reaction.is_some() {
+            Some(UpdateKind::MessageReaction)
+        } else if self.message_reaction_count.is_some() {
+            Some(UpdateKind::MessageReactionCount)
+        } else if self.inline_query.is_some() {
+            Some(UpdateKind::InlineQuery)
+        } else if self.chosen_inline_result.is_some() {
+            Some(UpdateKind::ChosenInlineResult)
+        } else if self.callback_query.is_some() {
+            Some(UpdateKind::CallbackQuery)
+        } else if self.shipping_query.is_some() {
+            Some(UpdateKind::ShippingQuery)
+        } else if self.pre_checkout_query.is_some() {
+            Some(UpdateKind::PreCheckoutQuery)
+        } else if self.purchased_paid_media.is_some() {
+            Some(UpdateKind::PurchasedPaidMedia)
+        } else if self.poll.is_some() {
+            Some(UpdateKind::Poll)
+        } else if self.poll_answer.is_some() {
+            Some(UpdateKind::PollAnswer)
+        } else if self.my_chat_member.is_some() {
+            Some(UpdateKind::MyChatMember)
+        } else if self.chat_member.is_some() {
+            Some(UpdateKind::ChatMember)
+        } else if self.chat_join_request.is_some() {
+            Some(UpdateKind::ChatJoinRequest)
+        } else if self.chat_boost.is_some() {
+            Some(UpdateKind::ChatBoost)
+        } else if self.removed_chat_boost.is_some() {
+            Some(UpdateKind::RemovedChatBoost)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// Describes the current status of a webhook.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WebhookInfo {
+    /// Webhook URL, may be empty if webhook is not set up
+    pub url: String,
+    /// True, if a custom certificate was provided for webhook certificate checks
+    pub has_custom_certificate: bool,
+    /// Number of updates awaiting delivery
+    pub pending_update_count: i64,
+    /// Optional. Currently used webhook IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// Optional. Unix time for the most recent error that happened when trying to
+    /// deliver an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_date: Option<i64>,
+    /// Optional. Error message in human-readable format for the most recent error
+    /// that happened when trying to deliver an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_message: Option<String>,
+    /// Optional. Unix time of the most recent error that happened when trying to
+    /// synchronize available updates with Telegram datacenters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_synchronization_error_date: Option<i64>,
+    /// Optional. The maximum allowed number of simultaneous HTTPS connections to
+    /// the webhook for update delivery
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    /// Optional. A list of update types the bot is subscribed to. Defaults to all
+    /// update types except chat_member
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a Telegram user or bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct User {
+    /// Unique identifier for this user or bot. This number may have more than 32
+    /// significant bits and some programming languages may have difficulty/silent
+    /// defects in interpreting it. But it has at most 52 significant bits, so a
+    /// 64-bit integer or double-precision float type are safe for storing this
+    /// identifier.
+    pub id: i64,
+    /// True, if this user is a bot
+    pub is_bot: bool,
+    /// User's or bot's first name
+    pub first_name: String,
+    /// Optional. User's or bot's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. User's or bot's username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. IETF language tag of the user's language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+    /// Optional. True, if this user is a Telegram Premium user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_premium: Option<bool>,
+    /// Optional. True, if this user added the bot to the attachment menu
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_to_attachment_menu: Option<bool>,
+    /// Optional. True, if the bot can be invited to groups. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_join_groups: Option<bool>,
+    /// Optional. True, if privacy mode is disabled for the bot. Returned only in
+    /// getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_read_all_group_messages: Option<bool>,
+    /// Optional. True, if the bot supports inline queries. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_inline_queries: Option<bool>,
+    /// Optional. True, if the bot can be connected to a Telegram Business account
+    /// to receive its messages. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_connect_to_business: Option<bool>,
+    /// Optional. True, if the bot has a main Web App. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_main_web_app: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Type of the chat, can be either “private”, “group”, “supergroup” or “channel”
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatType {
+    Private,
+    Group,
+    Supergroup,
+    Channel,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object represents a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Chat {
+    /// Unique identifier for this chat. This number may have more than 32
+    /// significant bits and some programming languages may have difficulty/silent
+    /// defects in interpreting it. But it has at most 52 significant bits, so a
+    /// signed 64-bit integer or double-precision float type are safe for storing
+    /// this identifier.
+    pub id: i64,
+    /// Type of the chat, can be either “private”, “group”, “supergroup” or
+    /// “channel”
+    #[serde(rename = "type")]
+    pub ty: ChatType,
+    /// Optional. Title, for supergroups, channels and group chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Username, for private chats, supergroups and channels if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. First name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Optional. Last name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. True, if the supergroup chat is a forum (has topics enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_forum: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains full information about a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatFullInfo {
+    /// Unique identifier for this chat. This number may have more than 32
+    /// significant bits and some programming languages may have difficulty/silent
+    /// defects in interpreting it. But it has at most 52 significant bits, so a
+    /// signed 64-bit integer or double-precision float type are safe for storing
+    /// this identifier.
+    pub id: i64,
+    /// Type of the chat, can be either “private”, “group”, “supergroup” or
+    /// “channel”
+    #[serde(rename = "type")]
+    pub ty: ChatType,
+    /// Optional. Title, for supergroups, channels and group chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Username, for private chats, supergroups and channels if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. First name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Optional. Last name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. True, if the supergroup chat is a forum (has topics enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_forum: Option<bool>,
+    /// Identifier of the accent color for the chat name and backgrounds of the chat
+    /// photo, reply header, and link preview. See accent colors for more details.
+    pub accent_color_id: i64,
+    /// The maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: i64,
+    /// Optional. Chat photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<ChatPhoto>,
+    /// Optional. If non-empty, the list of all active chat usernames; for private
+    /// chats, supergroups and channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_usernames: Option<Vec<String>>,
+    /// Optional. For private chats, the date of birth of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthdate: Option<Birthdate>,
+    /// Optional. For private chats with business accounts, the intro of the
+    /// business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_intro: Option<BusinessIntro>,
+    /// Optional. For private chats with business accounts, the location of the
+    /// business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_location: Option<BusinessLocation>,
+    /// Optional. For private chats with business accounts, the opening hours of the
+    /// business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_opening_hours: Option<BusinessOpeningHours>,
+    /// Optional. For private chats, the personal channel of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_chat: Option<Chat>,
+    /// Optional. List of available reactions allowed in the chat. If omitted, then
+    /// all emoji reactions are allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_reactions: Option<Vec<ReactionType>>,
+    /// Optional. Custom emoji identifier of the emoji chosen by the chat for the
+    /// reply header and link preview background
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_custom_emoji_id: Option<String>,
+    /// Optional. Identifier of the accent color for the chat's profile background.
+    /// See profile accent colors for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_accent_color_id: Option<i64>,
+    /// Optional. Custom emoji identifier of the emoji chosen by the chat for its
+    /// profile background
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_background_custom_emoji_id: Option<String>,
+    /// Optional. Custom emoji identifier of the emoji status of the chat or the
+    /// other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_custom_emoji_id: Option<String>,
+    /// Optional. Expiration date of the emoji status of the chat or the other party
+    /// in a private chat, in Unix time, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_expiration_date: Option<i64>,
+    /// Optional. Bio of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// Optional. True, if privacy settings of the other party in the private chat
+    /// allows to use tg://user?id=<user_id> links only in chats with the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_private_forwards: Option<bool>,
+    /// Optional. True, if the privacy settings of the other party restrict sending
+    /// voice and video note messages in the private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_restricted_voice_and_video_messages: Option<bool>,
+    /// Optional. True, if users need to join the supergroup before they can send
+    /// messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_to_send_messages: Option<bool>,
+    /// Optional. True, if all users directly joining the supergroup without using
+    /// an invite link need to be approved by supergroup administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_by_request: Option<bool>,
+    /// Optional. Description, for groups, supergroups and channel chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Primary invite link, for groups, supergroups and channel chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// Optional. The most recent pinned message (by sending date)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_message: Option<Box<Message>>,
+    /// Optional. Default chat member permissions, for groups and supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ChatPermissions>,
+    /// Optional. True, if paid media messages can be sent or forwarded to the
+    /// channel chat. The field is available only for channel chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_paid_media: Option<bool>,
+    /// Optional. For supergroups, the minimum allowed delay between consecutive
+    /// messages sent by each unprivileged user; in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_mode_delay: Option<i64>,
+    /// Optional. For supergroups, the minimum number of boosts that a non-
+    /// administrator user needs to add in order to ignore slow mode and chat
+    /// permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unrestrict_boost_count: Option<i64>,
+    /// Optional. The time after which all messages sent to the chat will be
+    /// automatically deleted; in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_auto_delete_time: Option<i64>,
+    /// Optional. True, if aggressive anti-spam checks are enabled in the
+    /// supergroup. The field is only available to chat administrators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_aggressive_anti_spam_enabled: Option<bool>,
+    /// Optional. True, if non-administrators can only get the list of bots and
+    /// administrators in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_hidden_members: Option<bool>,
+    /// Optional. True, if messages from the chat can't be forwarded to other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_protected_content: Option<bool>,
+    /// Optional. True, if new chat members will have access to old messages;
+    /// available only to chat administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_visible_history: Option<bool>,
+    /// Optional. For supergroups, name of the group sticker set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_set_name: Option<String>,
+    /// Optional. True, if the bot can change the group sticker set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_set_sticker_set: Option<bool>,
+    /// Optional. For supergroups, the name of the group's custom emoji sticker set.
+    /// Custom emoji from this set can be used by all users and bots in the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_sticker_set_name: Option<String>,
+    /// Optional. Unique identifier for the linked chat, i.e. the discussion group
+    /// identifier for a channel and vice versa; for supergroups and channel chats.
+    /// This identifier may be greater than 32 bits and some programming languages
+    /// may have difficulty/silent defects in interpreting it. But it is smaller
+    /// than 52 bits, so a signed 64 bit integer or double-precision float type are
+    /// safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_chat_id: Option<i64>,
+    /// Optional. For supergroups, the location to which the supergroup is connected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<ChatLocation>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Message {
+    /// Unique message identifier inside this chat. In specific instances (e.g.,
+    /// message containing a video sent to a big chat), the server might
+    /// automatically schedule a message instead of sending it immediately. In such
+    /// cases, this field will be 0 and the relevant message will be unusable until
+    /// it is actually sent
+    pub message_id: i64,
+    /// Optional. Unique identifier of a message thread to which the message
+    /// belongs; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Optional. Sender of the message; may be empty for messages sent to channels.
+    /// For backward compatibility, if the message was sent on behalf of a chat, the
+    /// field contains a fake sender user in non-channel chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<User>,
+    /// Optional. Sender of the message when sent on behalf of a chat. For example,
+    /// the supergroup itself for messages sent by its anonymous administrators or a
+    /// linked channel for messages automatically forwarded to the channel's
+    /// discussion group. For backward compatibility, if the message was sent on
+    /// behalf of a chat, the field from contains a fake sender user in non-channel
+    /// chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_chat: Option<Chat>,
+    /// Optional. If the sender of the message boosted the chat, the number of
+    /// boosts added by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_boost_count: Option<i64>,
+    /// Optional. The bot that actually sent the message on behalf of the business
+    /// account. Available only for outgoing messages sent on behalf of the
+    /// connected business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_business_bot: Option<User>,
+    /// Date the message was sent in Unix time. It is always a positive number,
+    /// representing a valid date.
+    pub date: i64,
+    /// Optional. Unique identifier of the business connection from which the
+    /// message was received. If non-empty, the message belongs to a chat of the
+    /// corresponding business account that is independent from any potential bot
+    /// chat which might share the same identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Chat the message belongs to
+    pub chat: Chat,
+    /// Optional. Information about the original message for forwarded messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_origin: Option<MessageOrigin>,
+    /// Optional. True, if the message is sent to a forum topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_topic_message: Option<bool>,
+    /// Optional. True, if the message is a channel post that was automatically
+    /// forwarded to the connected discussion group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_automatic_forward: Option<bool>,
+    /// Optional. For replies in the same chat and message thread, the original
+    /// message. Note that the Message object in this field will not contain further
+    /// reply_to_message fields even if it itself is a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message: Option<Box<Message>>,
+    /// Optional. Information about the message that is being replied to, which may
+    /// come from another chat or forum topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reply: Option<ExternalReplyInfo>,
+    /// Optional. For replies that quote part of the original message, the quoted
+    /// part of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<TextQuote>,
+    /// Optional. For replies to a story, the original story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_story: Option<Story>,
+    /// Optional. Bot through which the message was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_bot: Option<User>,
+    /// Optional. Date the message was last edited in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_date: Option<i64>,
+    /// Optional. True, if the message can't be forwarded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_protected_content: Option<bool>,
+    /// Optional. True, if the message was sent by an implicit action, for example,
+    /// as an away or a greeting business message, or as a scheduled message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_from_offline: Option<bool>,
+    /// Optional. The unique identifier of a media message group this message
+    /// belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_group_id: Option<String>,
+    /// Optional. Signature of the post author for messages in channels, or the
+    /// custom title of an anonymous group administrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+    /// Optional. For text messages, the actual UTF-8 text of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Optional. For text messages, special entities like usernames, URLs, bot
+    /// commands, etc. that appear in the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Optional. Options used for link preview generation for the message, if it is
+    /// a text message and link preview options were changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Optional. Unique identifier of the message effect added to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_id: Option<String>,
+    /// Optional. Message is an animation, information about the animation. For
+    /// backward compatibility, when this field is set, the document field will also
+    /// be set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+    /// Optional. Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+    /// Optional. Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Document>,
+    /// Optional. Message contains paid media; information about the paid media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media: Option<PaidMediaInfo>,
+    /// Optional. Message is a photo, available sizes of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Optional. Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker: Option<Sticker>,
+    /// Optional. Message is a forwarded story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story: Option<Story>,
+    /// Optional. Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+    /// Optional. Message is a video note, information about the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_note: Option<VideoNote>,
+    /// Optional. Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    /// Optional. Caption for the animation, audio, document, paid media, photo,
+    /// video or voice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. For messages with a caption, special entities like usernames,
+    /// URLs, bot commands, etc. that appear in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. True, if the message media is covered by a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_media_spoiler: Option<bool>,
+    /// Optional. Message is a shared contact, information about the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    /// Optional. Message is a dice with random value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dice: Option<Dice>,
+    /// Optional. Message is a game, information about the game. More about games »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game: Option<Game>,
+    /// Optional. Message is a native poll, information about the poll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// Optional. Message is a venue, information about the venue. For backward
+    /// compatibility, when this field is set, the location field will also be set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<Venue>,
+    /// Optional. Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Optional. New members that were added to the group or supergroup and
+    /// information about them (the bot itself may be one of these members)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_chat_members: Option<Vec<User>>,
+    /// Optional. A member was removed from the group, information about them (this
+    /// member may be the bot itself)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left_chat_member: Option<User>,
+    /// Optional. A chat title was changed to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_chat_title: Option<String>,
+    /// Optional. A chat photo was change to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_chat_photo: Option<Vec<PhotoSize>>,
+    /// Optional. Service message: the chat photo was deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_chat_photo: Option<bool>,
+    /// Optional. Service message: the group has been created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_chat_created: Option<bool>,
+    /// Optional. Service message: the supergroup has been created. This field can't
+    /// be received in a message coming through updates, because bot can't be a
+    /// member of a supergroup when it is created. It can only be found in
+    /// reply_to_message if someone replies to a very first message in a directly
+    /// created supergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supergroup_chat_created: Option<bool>,
+    /// Optional. Service message: the channel has been created. This field can't be
+    /// received in a message coming through updates, because bot can't be a member
+    /// of a channel when it is created. It can only be found in reply_to_message if
+    /// someone replies to a very first message in a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_chat_created: Option<bool>,
+    /// Optional. Service message: auto-delete timer settings changed in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_auto_delete_timer_changed: Option<MessageAutoDeleteTimerChanged>,
+    /// Optional. The group has been migrated to a supergroup with the specified
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<i64>,
+    /// Optional. The supergroup has been migrated from a group with the specified
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_from_chat_id: Option<i64>,
+    /// Optional. Specified message was pinned. Note that the Message object in this
+    /// field will not contain further reply_to_message fields even if it itself is
+    /// a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_message: Option<MaybeInaccessibleMessage>,
+    /// Optional. Message is an invoice for a payment, information about the
+    /// invoice. More about payments »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Invoice>,
+    /// Optional. Message is a service message about a successful payment,
+    /// information about the payment. More about payments »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_payment: Option<SuccessfulPayment>,
+    /// Optional. Message is a service message about a refunded payment, information
+    /// about the payment. More about payments »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_payment: Option<RefundedPayment>,
+    /// Optional. Service message: users were shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users_shared: Option<UsersShared>,
+    /// Optional. Service message: a chat was shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_shared: Option<ChatShared>,
+    /// Optional. The domain name of the website on which the user has logged in.
+    /// More about Telegram Login »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connected_website: Option<String>,
+    /// Optional. Service message: the user allowed the bot to write messages after
+    /// adding it to the attachment or side menu, launching a Web App from a link,
+    /// or accepting an explicit request from a Web App sent by the method
+    /// requestWriteAccess
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_access_allowed: Option<WriteAccessAllowed>,
+    /// Optional. Telegram Passport data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_data: Option<PassportData>,
+    /// Optional. Service message. A user in the chat triggered another user's
+    /// proximity alert while sharing Live Location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_triggered: Option<ProximityAlertTriggered>,
+    /// Optional. Service message: user boosted the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost_added: Option<ChatBoostAdded>,
+    /// Optional. Service message: chat background set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_background_set: Option<ChatBackground>,
+    /// Optional. Service message: forum topic created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_topic_created: Option<ForumTopicCreated>,
+    /// Optional. Service message: forum topic edited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_topic_edited: Option<ForumTopicEdited>,
+    /// Optional. Service message: forum topic closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_topic_closed: Option<ForumTopicClosed>,
+    /// Optional. Service message: forum topic reopened
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_topic_reopened: Option<ForumTopicReopened>,
+    /// Optional. Service message: the 'General' forum topic hidden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub general_forum_topic_hidden: Option<GeneralForumTopicHidden>,
+    /// Optional. Service message: the 'General' forum topic unhidden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub general_forum_topic_unhidden: Option<GeneralForumTopicUnhidden>,
+    /// Optional. Service message: a scheduled giveaway was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_created: Option<GiveawayCreated>,
+    /// Optional. The message is a scheduled giveaway message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway: Option<Giveaway>,
+    /// Optional. A giveaway with public winners was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_winners: Option<GiveawayWinners>,
+    /// Optional. Service message: a giveaway without public winners was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_completed: Option<GiveawayCompleted>,
+    /// Optional. Service message: video chat scheduled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_chat_scheduled: Option<VideoChatScheduled>,
+    /// Optional. Service message: video chat started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_chat_started: Option<VideoChatStarted>,
+    /// Optional. Service message: video chat ended
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_chat_ended: Option<VideoChatEnded>,
+    /// Optional. Service message: new participants invited to a video chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_chat_participants_invited: Option<VideoChatParticipantsInvited>,
+    /// Optional. Service message: data sent by a Web App
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app_data: Option<WebAppData>,
+    /// Optional. Inline keyboard attached to the message. login_url buttons are
+    /// represented as ordinary url buttons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a unique message identifier.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageId {
+    /// Unique message identifier. In specific instances (e.g., message containing a
+    /// video sent to a big chat), the server might automatically schedule a message
+    /// instead of sending it immediately. In such cases, this field will be 0 and
+    /// the relevant message will be unusable until it is actually sent
+    pub message_id: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes a message that was deleted or is otherwise inaccessible to
+/// the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InaccessibleMessage {
+    /// Chat the message belonged to
+    pub chat: Chat,
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Always 0. The field can be used to differentiate regular and inaccessible
+    /// messages.
+    pub date: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes a message that can be inaccessible to the bot. It can be
+/// one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MaybeInaccessibleMessage {
+    Message(Box<Message>),
+    InaccessibleMessage(InaccessibleMessage),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for MaybeInaccessibleMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: None, required: &["message_id", "date", "chat"] },
+            de::Variant { tag: Some("0"), required: &["chat", "message_id", "date"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("date"), VARIANTS) {
+            Some(0) => MaybeInaccessibleMessage::Message(de::from_value(value)?),
+            Some(1) => MaybeInaccessibleMessage::InaccessibleMessage(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => MaybeInaccessibleMessage::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("MaybeInaccessibleMessage")),
+        })
+    }
+}
+
+
+/// Type of the entity. Currently, can be “mention” (@username), “hashtag” (#hashtag
+/// or #hashtag@chatusername), “cashtag” ($USD or $USD@chatusername), “bot_command”
+/// (/start@jobs_bot), “url” (https://telegram.org), “email” (do-not-
+/// reply@telegram.org), “phone_number” (+1-212-555-0123), “bold” (bold text),
+/// “italic” (italic text), “underline” (underlined text), “strikethrough”
+/// (strikethrough text), “spoiler” (spoiler message), “blockquote” (block
+/// quotation), “expandable_blockquote” (collapsed-by-default block quotation),
+/// “code” (monowidth string), “pre” (monowidth block), “text_link” (for clickable
+/// text URLs), “text_mention” (for users without usernames), “custom_emoji” (for
+/// inline custom emoji stickers)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageEntityType {
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    ExpandableBlockquote,
+    Code,
+    Pre,
+    TextLink,
+    TextMention,
+    CustomEmoji,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object represents one special entity in a text message. For example,
+/// hashtags, usernames, URLs, etc.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageEntity {
+    /// Type of the entity. Currently, can be “mention” (@username), “hashtag”
+    /// (#hashtag or #hashtag@chatusername), “cashtag” ($USD or $USD@chatusername),
+    /// “bot_command” (/start@jobs_bot), “url” (https://telegram.org), “email” (do-
+    /// not-reply@telegram.org), “phone_number” (+1-212-555-0123), “bold” (bold
+    /// text), “italic” (italic text), “underline” (underlined text),
+    /// “strikethrough” (strikethrough text), “spoiler” (spoiler message),
+    /// “blockquote” (block quotation), “expandable_blockquote” (collapsed-by-
+    /// default block quotation), “code” (monowidth string), “pre” (monowidth
+    /// block), “text_link” (for clickable text URLs), “text_mention” (for users
+    /// without usernames), “custom_emoji” (for inline custom emoji stickers)
+    #[serde(rename = "type")]
+    pub ty: MessageEntityType,
+    /// Offset in UTF-16 code units to the start of the entity
+    pub offset: i64,
+    /// Length of the entity in UTF-16 code units
+    pub length: i64,
+    /// Optional. For “text_link” only, URL that will be opened after user taps on
+    /// the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. For “text_mention” only, the mentioned user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. For “pre” only, the programming language of the entity text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Optional. For “custom_emoji” only, unique identifier of the custom emoji.
+    /// Use getCustomEmojiStickers to get full information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about the quoted part of a message that is
+/// replied to by the given message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TextQuote {
+    /// Text of the quoted part of a message that is replied to by the given message
+    pub text: String,
+    /// Optional. Special entities that appear in the quote. Currently, only bold,
+    /// italic, underline, strikethrough, spoiler, and custom_emoji entities are
+    /// kept in quotes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Approximate quote position in the original message in UTF-16 code units as
+    /// specified by the sender
+    pub position: i64,
+    /// Optional. True, if the quote was chosen manually by the message sender.
+    /// Otherwise, the quote was added automatically by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_manual: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about a message that is being replied to, which
+/// may come from another chat or forum topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ExternalReplyInfo {
+    /// Origin of the message replied to by the given message
+    pub origin: MessageOrigin,
+    /// Optional. Chat the original message belongs to. Available only if the chat
+    /// is a supergroup or a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat: Option<Chat>,
+    /// Optional. Unique message identifier inside the original chat. Available only
+    /// if the original chat is a supergroup or a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    /// Optional. Options used for link preview generation for the original message,
+    /// if it is a text message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Optional. Message is an animation, information about the animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+    /// Optional. Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+    /// Optional. Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Document>,
+    /// Optional. Message contains paid media; information about the paid media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media: Option<PaidMediaInfo>,
+    /// Optional. Message is a photo, available sizes of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Optional. Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker: Option<Sticker>,
+    /// Optional. Message is a forwarded story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story: Option<Story>,
+    /// Optional. Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+    /// Optional. Message is a video note, information about the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_note: Option<VideoNote>,
+    /// Optional. Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    /// Optional. True, if the message media is covered by a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_media_spoiler: Option<bool>,
+    /// Optional. Message is a shared contact, information about the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    /// Optional. Message is a dice with random value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dice: Option<Dice>,
+    /// Optional. Message is a game, information about the game. More about games »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game: Option<Game>,
+    /// Optional. Message is a scheduled giveaway, information about the giveaway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway: Option<Giveaway>,
+    /// Optional. A giveaway with public winners was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_winners: Option<GiveawayWinners>,
+    /// Optional. Message is an invoice for a payment, information about the
+    /// invoice. More about payments »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Invoice>,
+    /// Optional. Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Optional. Message is a native poll, information about the poll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// Optional. Message is a venue, information about the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<Venue>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes reply parameters for the message that is being sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReplyParameters {
+    /// Identifier of the message that will be replied to in the current chat, or in
+    /// the chat chat_id if it is specified
+    pub message_id: i64,
+    /// Optional. If the message to be replied to is from a different chat, unique
+    /// identifier for the chat or username of the channel (in the format
+    /// @channelusername). Not supported for messages sent on behalf of a business
+    /// account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<PolymorphChatId>,
+    /// Optional. Pass True if the message should be sent even if the specified
+    /// message to be replied to is not found. Always False for replies in another
+    /// chat or forum topic. Always True for messages sent on behalf of a business
+    /// account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// Optional. Quoted part of the message to be replied to; 0-1024 characters
+    /// after entities parsing. The quote must be an exact substring of the message
+    /// to be replied to, including bold, italic, underline, strikethrough, spoiler,
+    /// and custom_emoji entities. The message will fail to send if the quote isn't
+    /// found in the original message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// Optional. Mode for parsing entities in the quote. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_parse_mode: Option<String>,
+    /// Optional. A JSON-serialized list of special entities that appear in the
+    /// quote. It can be specified instead of quote_parse_mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Position of the quote in the original message in UTF-16 code units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_position: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the origin of a message. It can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MessageOrigin {
+    MessageOriginUser(MessageOriginUser),
+    MessageOriginHiddenUser(MessageOriginHiddenUser),
+    MessageOriginChat(MessageOriginChat),
+    MessageOriginChannel(MessageOriginChannel),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for MessageOrigin {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("user"), required: &["type", "date", "sender_user"] },
+            de::Variant { tag: Some("hidden_user"), required: &["type", "date", "sender_user_name"] },
+            de::Variant { tag: Some("chat"), required: &["type", "date", "sender_chat"] },
+            de::Variant { tag: Some("channel"), required: &["type", "date", "chat", "message_id"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => MessageOrigin::MessageOriginUser(de::from_value(value)?),
+            Some(1) => MessageOrigin::MessageOriginHiddenUser(de::from_value(value)?),
+            Some(2) => MessageOrigin::MessageOriginChat(de::from_value(value)?),
+            Some(3) => MessageOrigin::MessageOriginChannel(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => MessageOrigin::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("MessageOrigin")),
+        })
+    }
+}
+
+
+/// The message was originally sent by a known user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginUser {
+    /// Type of the message origin, always “user”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// User that sent the message originally
+    pub sender_user: User,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The message was originally sent by an unknown user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginHiddenUser {
+    /// Type of the message origin, always “hidden_user”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Name of the user that sent the message originally
+    pub sender_user_name: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The message was originally sent on behalf of a chat to a group chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginChat {
+    /// Type of the message origin, always “chat”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Chat that sent the message originally
+    pub sender_chat: Chat,
+    /// Optional. For messages originally sent by an anonymous chat administrator,
+    /// original message author signature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The message was originally sent to a channel chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginChannel {
+    /// Type of the message origin, always “channel”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Channel chat to which the message was originally sent
+    pub chat: Chat,
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Optional. Signature of the original post author
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one size of a photo or a file / sticker thumbnail.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PhotoSize {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Photo width
+    pub width: i64,
+    /// Photo height
+    pub height: i64,
+    /// Optional. File size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without
+/// sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Animation {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Video width as defined by the sender
+    pub width: i64,
+    /// Video height as defined by the sender
+    pub height: i64,
+    /// Duration of the video in seconds as defined by the sender
+    pub duration: i64,
+    /// Optional. Animation thumbnail as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. Original animation filename as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Optional. MIME type of the file as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an audio file to be treated as music by the Telegram
+/// clients.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Audio {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Duration of the audio in seconds as defined by the sender
+    pub duration: i64,
+    /// Optional. Performer of the audio as defined by the sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Optional. Title of the audio as defined by the sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Original filename as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Optional. MIME type of the file as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Optional. Thumbnail of the album cover to which the music file belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a general file (as opposed to photos, voice messages and
+/// audio files).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Document {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Optional. Document thumbnail as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. Original filename as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Optional. MIME type of the file as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a story.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Story {
+    /// Chat that posted the story
+    pub chat: Chat,
+    /// Unique identifier for the story in the chat
+    pub id: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a video file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Video {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Video width as defined by the sender
+    pub width: i64,
+    /// Video height as defined by the sender
+    pub height: i64,
+    /// Duration of the video in seconds as defined by the sender
+    pub duration: i64,
+    /// Optional. Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. Original filename as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Optional. MIME type of the file as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a video message (available in Telegram apps as of v.4.0).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoNote {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Video width and height (diameter of the video message) as defined by the
+    /// sender
+    pub length: i64,
+    /// Duration of the video in seconds as defined by the sender
+    pub duration: i64,
+    /// Optional. Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. File size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a voice note.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Voice {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Duration of the audio in seconds as defined by the sender
+    pub duration: i64,
+    /// Optional. MIME type of the file as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes the paid media added to a message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the media
+    pub star_count: i64,
+    /// Information about the paid media
+    pub paid_media: Vec<PaidMedia>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes paid media. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum PaidMedia {
+    PaidMediaPreview(PaidMediaPreview),
+    PaidMediaPhoto(PaidMediaPhoto),
+    PaidMediaVideo(PaidMediaVideo),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for PaidMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("preview"), required: &["type"] },
+            de::Variant { tag: Some("photo"), required: &["type", "photo"] },
+            de::Variant { tag: Some("video"), required: &["type", "video"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => PaidMedia::PaidMediaPreview(de::from_value(value)?),
+            Some(1) => PaidMedia::PaidMediaPhoto(de::from_value(value)?),
+            Some(2) => PaidMedia::PaidMediaVideo(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => PaidMedia::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("PaidMedia")),
+        })
+    }
+}
+
+
+/// The paid media isn't available before the payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaPreview {
+    /// Type of the paid media, always “preview”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Optional. Media width as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Media height as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Duration of the media in seconds as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The paid media is a photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaPhoto {
+    /// Type of the paid media, always “photo”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The photo
+    pub photo: Vec<PhotoSize>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The paid media is a video.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaVideo {
+    /// Type of the paid media, always “video”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The video
+    pub video: Video,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a phone contact.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Contact {
+    /// Contact's phone number
+    pub phone_number: String,
+    /// Contact's first name
+    pub first_name: String,
+    /// Optional. Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. Contact's user identifier in Telegram. This number may have more
+    /// than 32 significant bits and some programming languages may have
+    /// difficulty/silent defects in interpreting it. But it has at most 52
+    /// significant bits, so a 64-bit integer or double-precision float type are
+    /// safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+    /// Optional. Additional data about the contact in the form of a vCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an animated emoji that displays a random value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Dice {
+    /// Emoji on which the dice throw animation is based
+    pub emoji: String,
+    /// Value of the dice, 1-6 for “🎲”, “🎯” and “🎳” base emoji, 1-5 for “🏀” and “⚽”
+    /// base emoji, 1-64 for “🎰” base emoji
+    pub value: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about one answer option in a poll.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PollOption {
+    /// Option text, 1-100 characters
+    pub text: String,
+    /// Optional. Special entities that appear in the option text. Currently, only
+    /// custom emoji entities are allowed in poll option texts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+    /// Number of users that voted for this option
+    pub voter_count: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about one answer option in a poll to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputPollOption {
+    /// Option text, 1-100 characters
+    pub text: String,
+    /// Optional. Mode for parsing entities in the text. See formatting options for
+    /// more details. Currently, only custom emoji entities are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_parse_mode: Option<String>,
+    /// Optional. A JSON-serialized list of special entities that appear in the poll
+    /// option text. It can be specified instead of text_parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an answer of a user in a non-anonymous poll.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PollAnswer {
+    /// Unique poll identifier
+    pub poll_id: String,
+    /// Optional. The chat that changed the answer to the poll, if the voter is
+    /// anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter_chat: Option<Chat>,
+    /// Optional. The user that changed the answer to the poll, if the voter isn't
+    /// anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// 0-based identifiers of chosen answer options. May be empty if the vote was
+    /// retracted.
+    pub option_ids: Vec<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Poll type, currently can be “regular” or “quiz”
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PollType {
+    Regular,
+    Quiz,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object contains information about a poll.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Poll {
+    /// Unique poll identifier
+    pub id: String,
+    /// Poll question, 1-300 characters
+    pub question: String,
+    /// Optional. Special entities that appear in the question. Currently, only
+    /// custom emoji entities are allowed in poll questions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_entities: Option<Vec<MessageEntity>>,
+    /// List of poll options
+    pub options: Vec<PollOption>,
+    /// Total number of users that voted in the poll
+    pub total_voter_count: i64,
+    /// True, if the poll is closed
+    pub is_closed: bool,
+    /// True, if the poll is anonymous
+    pub is_anonymous: bool,
+    /// Poll type, currently can be “regular” or “quiz”
+    #[serde(rename = "type")]
+    pub ty: PollType,
+    /// True, if the poll allows multiple answers
+    pub allows_multiple_answers: bool,
+    /// Optional. 0-based identifier of the correct answer option. Available only
+    /// for polls in the quiz mode, which are closed, or was sent (not forwarded) by
+    /// the bot or to the private chat with the bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i64>,
+    /// Optional. Text that is shown when a user chooses an incorrect answer or taps
+    /// on the lamp icon in a quiz-style poll, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    /// Optional. Special entities like usernames, URLs, bot commands, etc. that
+    /// appear in the explanation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Amount of time in seconds the poll will be active after creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i64>,
+    /// Optional. Point in time (Unix timestamp) when the poll will be automatically
+    /// closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a point on the map.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Location {
+    /// Latitude as defined by the sender
+    pub latitude: f64,
+    /// Longitude as defined by the sender
+    pub longitude: f64,
+    /// Optional. The radius of uncertainty for the location, measured in meters;
+    /// 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Optional. Time relative to the message sending date, during which the
+    /// location can be updated; in seconds. For active live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// Optional. The direction in which user is moving, in degrees; 1-360. For
+    /// active live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// Optional. The maximum distance for proximity alerts about approaching
+    /// another chat member, in meters. For sent live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a venue.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Venue {
+    /// Venue location. Can't be a live location
+    pub location: Location,
+    /// Name of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Optional. Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Optional. Foursquare type of the venue. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or
+    /// “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    /// Optional. Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    /// Optional. Google Places type of the venue. (See supported types.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes data sent from a Web App to the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WebAppData {
+    /// The data. Be aware that a bad client can send arbitrary data in this field.
+    pub data: String,
+    /// Text of the web_app keyboard button from which the Web App was opened. Be
+    /// aware that a bad client can send arbitrary data in this field.
+    pub button_text: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the content of a service message, sent whenever a user in
+/// the chat triggers a proximity alert set by another user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ProximityAlertTriggered {
+    /// User that triggered the alert
+    pub traveler: User,
+    /// User that set the alert
+    pub watcher: User,
+    /// The distance between the users
+    pub distance: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a change in auto-delete timer
+/// settings.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageAutoDeleteTimerChanged {
+    /// New auto-delete time for messages in the chat; in seconds
+    pub message_auto_delete_time: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a user boosting a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostAdded {
+    /// Number of boosts added by the user
+    pub boost_count: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the way a background is filled based on the selected
+/// colors. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BackgroundFill {
+    BackgroundFillSolid(BackgroundFillSolid),
+    BackgroundFillGradient(BackgroundFillGradient),
+    BackgroundFillFreeformGradient(BackgroundFillFreeformGradient),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for BackgroundFill {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("solid"), required: &["type", "color"] },
+            de::Variant { tag: Some("gradient"), required: &["type", "top_color", "bottom_color", "rotation_angle"] },
+            de::Variant { tag: Some("freeform_gradient"), required: &["type", "colors"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => BackgroundFill::BackgroundFillSolid(de::from_value(value)?),
+            Some(1) => BackgroundFill::BackgroundFillGradient(de::from_value(value)?),
+            Some(2) => BackgroundFill::BackgroundFillFreeformGradient(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => BackgroundFill::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("BackgroundFill")),
+        })
+    }
+}
+
+
+/// The background is filled using the selected color.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundFillSolid {
+    /// Type of the background fill, always “solid”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The color of the background fill in the RGB24 format
+    pub color: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The background is a gradient fill.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundFillGradient {
+    /// Type of the background fill, always “gradient”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Top color of the gradient in the RGB24 format
+    pub top_color: i64,
+    /// Bottom color of the gradient in the RGB24 format
+    pub bottom_color: i64,
+    /// Clockwise rotation angle of the background fill in degrees; 0-359
+    pub rotation_angle: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The background is a freeform gradient that rotates after every message in the
+/// chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundFillFreeformGradient {
+    /// Type of the background fill, always “freeform_gradient”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// A list of the 3 or 4 base colors that are used to generate the freeform
+    /// gradient in the RGB24 format
+    pub colors: Vec<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the type of a background. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BackgroundType {
+    BackgroundTypeFill(BackgroundTypeFill),
+    BackgroundTypeWallpaper(BackgroundTypeWallpaper),
+    BackgroundTypePattern(BackgroundTypePattern),
+    BackgroundTypeChatTheme(BackgroundTypeChatTheme),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for BackgroundType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("fill"), required: &["type", "fill", "dark_theme_dimming"] },
+            de::Variant { tag: Some("wallpaper"), required: &["type", "document", "dark_theme_dimming"] },
+            de::Variant { tag: Some("pattern"), required: &["type", "document", "fill", "intensity"] },
+            de::Variant { tag: Some("chat_theme"), required: &["type", "theme_name"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => BackgroundType::BackgroundTypeFill(de::from_value(value)?),
+            Some(1) => BackgroundType::BackgroundTypeWallpaper(de::from_value(value)?),
+            Some(2) => BackgroundType::BackgroundTypePattern(de::from_value(value)?),
+            Some(3) => BackgroundType::BackgroundTypeChatTheme(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => BackgroundType::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("BackgroundType")),
+        })
+    }
+}
+
+
+/// The background is automatically filled based on the selected colors.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundTypeFill {
+    /// Type of the background, always “fill”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The background fill
+    pub fill: BackgroundFill,
+    /// Dimming of the background in dark themes, as a percentage; 0-100
+    pub dark_theme_dimming: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The background is a wallpaper in the JPEG format.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundTypeWallpaper {
+    /// Type of the background, always “wallpaper”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Document with the wallpaper
+    pub document: Document,
+    /// Dimming of the background in dark themes, as a percentage; 0-100
+    pub dark_theme_dimming: i64,
+    /// Optional. True, if the wallpaper is downscaled to fit in a 450x450 square
+    /// and then box-blurred with radius 12
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_blurred: Option<bool>,
+    /// Optional. True, if the background moves slightly when the device is tilted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_moving: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The background is a PNG or TGV (gzipped subset of SVG with MIME type
+/// “application/x-tgwallpattern”) pattern to be combined with the background fill
+/// chosen by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundTypePattern {
+    /// Type of the background, always “pattern”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Document with the pattern
+    pub document: Document,
+    /// The background fill that is combined with the pattern
+    pub fill: BackgroundFill,
+    /// Intensity of the pattern when it is shown above the filled background; 0-100
+    pub intensity: i64,
+    /// Optional. True, if the background fill must be applied only to the pattern
+    /// itself. All other pixels are black in this case. For dark themes only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_inverted: Option<bool>,
+    /// Optional. True, if the background moves slightly when the device is tilted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_moving: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The background is taken directly from a built-in chat theme.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BackgroundTypeChatTheme {
+    /// Type of the background, always “chat_theme”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Name of the chat theme, which is usually an emoji
+    pub theme_name: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a chat background.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBackground {
+    /// Type of the background
+    #[serde(rename = "type")]
+    pub ty: BackgroundType,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a new forum topic created in the
+/// chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForumTopicCreated {
+    /// Name of the topic
+    pub name: String,
+    /// Color of the topic icon in RGB format
+    pub icon_color: i64,
+    /// Optional. Unique identifier of the custom emoji shown as the topic icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a forum topic closed in the chat.
+/// Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForumTopicClosed {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about an edited forum topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForumTopicEdited {
+    /// Optional. New name of the topic, if it was edited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Optional. New identifier of the custom emoji shown as the topic icon, if it
+    /// was edited; an empty string if the icon was removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a forum topic reopened in the
+/// chat. Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForumTopicReopened {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about General forum topic hidden in the
+/// chat. Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GeneralForumTopicHidden {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about General forum topic unhidden in
+/// the chat. Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GeneralForumTopicUnhidden {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about a user that was shared with the bot using
+/// a KeyboardButtonRequestUsers button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SharedUser {
+    /// Identifier of the shared user. This number may have more than 32 significant
+    /// bits and some programming languages may have difficulty/silent defects in
+    /// interpreting it. But it has at most 52 significant bits, so 64-bit integers
+    /// or double-precision float types are safe for storing these identifiers. The
+    /// bot may not have access to the user and could be unable to use this
+    /// identifier, unless the user is already known to the bot by some other means.
+    pub user_id: i64,
+    /// Optional. First name of the user, if the name was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Optional. Last name of the user, if the name was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. Username of the user, if the username was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. Available sizes of the chat photo, if the photo was requested by
+    /// the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about the users whose identifiers were shared
+/// with the bot using a KeyboardButtonRequestUsers button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UsersShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Information about users shared with the bot.
+    pub users: Vec<SharedUser>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about a chat that was shared with the bot using
+/// a KeyboardButtonRequestChat button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Identifier of the shared chat. This number may have more than 32 significant
+    /// bits and some programming languages may have difficulty/silent defects in
+    /// interpreting it. But it has at most 52 significant bits, so a 64-bit integer
+    /// or double-precision float type are safe for storing this identifier. The bot
+    /// may not have access to the chat and could be unable to use this identifier,
+    /// unless the chat is already known to the bot by some other means.
+    pub chat_id: i64,
+    /// Optional. Title of the chat, if the title was requested by the bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Username of the chat, if the username was requested by the bot and
+    /// available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. Available sizes of the chat photo, if the photo was requested by
+    /// the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a user allowing a bot to write
+/// messages after adding it to the attachment menu, launching a Web App from a
+/// link, or accepting an explicit request from a Web App sent by the method
+/// requestWriteAccess.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WriteAccessAllowed {
+    /// Optional. True, if the access was granted after the user accepted an
+    /// explicit request from a Web App sent by the method requestWriteAccess
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_request: Option<bool>,
+    /// Optional. Name of the Web App, if the access was granted when the Web App
+    /// was launched from a link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app_name: Option<String>,
+    /// Optional. True, if the access was granted when the bot was added to the
+    /// attachment or side menu
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_attachment_menu: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a video chat scheduled in the
+/// chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoChatScheduled {
+    /// Point in time (Unix timestamp) when the video chat is supposed to be started
+    /// by a chat administrator
+    pub start_date: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a video chat started in the chat.
+/// Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoChatStarted {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about a video chat ended in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoChatEnded {
+    /// Video chat duration in seconds
+    pub duration: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about new members invited to a video
+/// chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoChatParticipantsInvited {
+    /// New members that were invited to the video chat
+    pub users: Vec<User>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about the creation of a scheduled
+/// giveaway.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayCreated {
+    /// Optional. The number of Telegram Stars to be split between giveaway winners;
+    /// for Telegram Star giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_star_count: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a message about a scheduled giveaway.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Giveaway {
+    /// The list of chats which the user must join to participate in the giveaway
+    pub chats: Vec<Chat>,
+    /// Point in time (Unix timestamp) when winners of the giveaway will be selected
+    pub winners_selection_date: i64,
+    /// The number of users which are supposed to be selected as winners of the
+    /// giveaway
+    pub winner_count: i64,
+    /// Optional. True, if only users who join the chats after the giveaway started
+    /// should be eligible to win
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_new_members: Option<bool>,
+    /// Optional. True, if the list of giveaway winners will be visible to everyone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_public_winners: Option<bool>,
+    /// Optional. Description of additional giveaway prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_description: Option<String>,
+    /// Optional. A list of two-letter ISO 3166-1 alpha-2 country codes indicating
+    /// the countries from which eligible users for the giveaway must come. If
+    /// empty, then all users can participate in the giveaway. Users with a phone
+    /// number that was bought on Fragment can always participate in giveaways.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_codes: Option<Vec<String>>,
+    /// Optional. The number of Telegram Stars to be split between giveaway winners;
+    /// for Telegram Star giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_star_count: Option<i64>,
+    /// Optional. The number of months the Telegram Premium subscription won from
+    /// the giveaway will be active for; for Telegram Premium giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_month_count: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a message about the completion of a giveaway with public
+/// winners.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayWinners {
+    /// The chat that created the giveaway
+    pub chat: Chat,
+    /// Identifier of the message with the giveaway in the chat
+    pub giveaway_message_id: i64,
+    /// Point in time (Unix timestamp) when winners of the giveaway were selected
+    pub winners_selection_date: i64,
+    /// Total number of winners in the giveaway
+    pub winner_count: i64,
+    /// List of up to 100 winners of the giveaway
+    pub winners: Vec<User>,
+    /// Optional. The number of other chats the user had to join in order to be
+    /// eligible for the giveaway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_chat_count: Option<i64>,
+    /// Optional. The number of Telegram Stars that were split between giveaway
+    /// winners; for Telegram Star giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_star_count: Option<i64>,
+    /// Optional. The number of months the Telegram Premium subscription won from
+    /// the giveaway will be active for; for Telegram Premium giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_month_count: Option<i64>,
+    /// Optional. Number of undistributed prizes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unclaimed_prize_count: Option<i64>,
+    /// Optional. True, if only users who had joined the chats after the giveaway
+    /// started were eligible to win
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_new_members: Option<bool>,
+    /// Optional. True, if the giveaway was canceled because the payment for it was
+    /// refunded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub was_refunded: Option<bool>,
+    /// Optional. Description of additional giveaway prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_description: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a service message about the completion of a giveaway
+/// without public winners.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayCompleted {
+    /// Number of winners in the giveaway
+    pub winner_count: i64,
+    /// Optional. Number of undistributed prizes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unclaimed_prize_count: Option<i64>,
+    /// Optional. Message with the giveaway that was completed, if it wasn't deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_message: Option<Box<Message>>,
+    /// Optional. True, if the giveaway is a Telegram Star giveaway. Otherwise,
+    /// currently, the giveaway is a Telegram Premium giveaway.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_star_giveaway: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes the options used for link preview generation.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LinkPreviewOptions {
+    /// Optional. True, if the link preview is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+    /// Optional. URL to use for the link preview. If empty, then the first URL
+    /// found in the message text will be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. True, if the media in the link preview is supposed to be shrunk;
+    /// ignored if the URL isn't explicitly specified or media size change isn't
+    /// supported for the preview
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_small_media: Option<bool>,
+    /// Optional. True, if the media in the link preview is supposed to be enlarged;
+    /// ignored if the URL isn't explicitly specified or media size change isn't
+    /// supported for the preview
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_large_media: Option<bool>,
+    /// Optional. True, if the link preview must be shown above the message text;
+    /// otherwise, the link preview will be shown below the message text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_above_text: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represent a user's profile pictures.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UserProfilePhotos {
+    /// Total number of profile pictures the target user has
+    pub total_count: i64,
+    /// Requested profile pictures (in up to 4 sizes each)
+    pub photos: Vec<Vec<PhotoSize>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a file ready to be downloaded. The file can be downloaded
+/// via the link https://api.telegram.org/file/bot<token>/<file_path>. It is
+/// guaranteed that the link will be valid for at least 1 hour. When the link
+/// expires, a new one can be requested by calling getFile.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct File {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Optional. File size in bytes. It can be bigger than 2^31 and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Optional. File path. Use
+    /// https://api.telegram.org/file/bot<token>/<file_path> to get the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes a Web App.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened with additional data as specified in
+    /// Initializing Web Apps
+    pub url: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a custom keyboard with reply options (see Introduction to
+/// bots for details and examples). Not supported in channels and for messages sent
+/// on behalf of a Telegram Business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReplyKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of KeyboardButton objects
+    pub keyboard: Vec<Vec<KeyboardButton>>,
+    /// Optional. Requests clients to always show the keyboard when the regular
+    /// keyboard is hidden. Defaults to false, in which case the custom keyboard can
+    /// be hidden and opened with a keyboard icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_persistent: Option<bool>,
+    /// Optional. Requests clients to resize the keyboard vertically for optimal fit
+    /// (e.g., make the keyboard smaller if there are just two rows of buttons).
+    /// Defaults to false, in which case the custom keyboard is always of the same
+    /// height as the app's standard keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resize_keyboard: Option<bool>,
+    /// Optional. Requests clients to hide the keyboard as soon as it's been used.
+    /// The keyboard will still be available, but clients will automatically display
+    /// the usual letter-keyboard in the chat – the user can press a special button
+    /// in the input field to see the custom keyboard again. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_keyboard: Option<bool>,
+    /// Optional. The placeholder to be shown in the input field when the keyboard
+    /// is active; 1-64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<String>,
+    /// Optional. Use this parameter if you want to show the keyboard to specific
+    /// users only. Targets: 1) users that are @mentioned in the text of the Message
+    /// object; 2) if the bot's message is a reply to a message in the same chat and
+    /// forum topic, sender of the original message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one button of the reply keyboard. At most one of the
+/// optional fields must be used to specify type of the button. For simple text
+/// buttons, String can be used instead of this object to specify the button text.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButton {
+    /// Text of the button. If none of the optional fields are used, it will be sent
+    /// as a message when the button is pressed
+    pub text: String,
+    /// Optional. If specified, pressing the button will open a list of suitable
+    /// users. Identifiers of selected users will be sent to the bot in a
+    /// “users_shared” service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_users: Option<KeyboardButtonRequestUsers>,
+    /// Optional. If specified, pressing the button will open a list of suitable
+    /// chats. Tapping on a chat will send its identifier to the bot in a
+    /// “chat_shared” service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_chat: Option<KeyboardButtonRequestChat>,
+    /// Optional. If True, the user's phone number will be sent as a contact when
+    /// the button is pressed. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_contact: Option<bool>,
+    /// Optional. If True, the user's current location will be sent when the button
+    /// is pressed. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_location: Option<bool>,
+    /// Optional. If specified, the user will be asked to create a poll and send it
+    /// to the bot when the button is pressed. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_poll: Option<KeyboardButtonPollType>,
+    /// Optional. If specified, the described Web App will be launched when the
+    /// button is pressed. The Web App will be able to send a “web_app_data” service
+    /// message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object defines the criteria used to request suitable users. Information
+/// about the selected users will be shared with the bot when the corresponding
+/// button is pressed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButtonRequestUsers {
+    /// Signed 32-bit identifier of the request that will be received back in the
+    /// UsersShared object. Must be unique within the message
+    pub request_id: i64,
+    /// Optional. Pass True to request bots, pass False to request regular users. If
+    /// not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_bot: Option<bool>,
+    /// Optional. Pass True to request premium users, pass False to request non-
+    /// premium users. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_premium: Option<bool>,
+    /// Optional. The maximum number of users to be selected; 1-10. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<i64>,
+    /// Optional. Pass True to request the users' first and last names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_name: Option<bool>,
+    /// Optional. Pass True to request the users' usernames
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_username: Option<bool>,
+    /// Optional. Pass True to request the users' photos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_photo: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object defines the criteria used to request a suitable chat. Information
+/// about the selected chat will be shared with the bot when the corresponding
+/// button is pressed. The bot will be granted requested rights in the chat if
+/// appropriate.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButtonRequestChat {
+    /// Signed 32-bit identifier of the request, which will be received back in the
+    /// ChatShared object. Must be unique within the message
+    pub request_id: i64,
+    /// Pass True to request a channel chat, pass False to request a group or a
+    /// supergroup chat.
+    pub chat_is_channel: bool,
+    /// Optional. Pass True to request a forum supergroup, pass False to request a
+    /// non-forum chat. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_forum: Option<bool>,
+    /// Optional. Pass True to request a supergroup or a channel with a username,
+    /// pass False to request a chat without a username. If not specified, no
+    /// additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_has_username: Option<bool>,
+    /// Optional. Pass True to request a chat owned by the user. Otherwise, no
+    /// additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_created: Option<bool>,
+    /// Optional. A JSON-serialized object listing the required administrator rights
+    /// of the user in the chat. The rights must be a superset of
+    /// bot_administrator_rights. If not specified, no additional restrictions are
+    /// applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_administrator_rights: Option<ChatAdministratorRights>,
+    /// Optional. A JSON-serialized object listing the required administrator rights
+    /// of the bot in the chat. The rights must be a subset of
+    /// user_administrator_rights. If not specified, no additional restrictions are
+    /// applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_administrator_rights: Option<ChatAdministratorRights>,
+    /// Optional. Pass True to request a chat with the bot as a member. Otherwise,
+    /// no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_is_member: Option<bool>,
+    /// Optional. Pass True to request the chat's title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_title: Option<bool>,
+    /// Optional. Pass True to request the chat's username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_username: Option<bool>,
+    /// Optional. Pass True to request the chat's photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_photo: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents type of a poll, which is allowed to be created and sent
+/// when the corresponding button is pressed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButtonPollType {
+    /// Optional. If quiz is passed, the user will be allowed to create only polls
+    /// in the quiz mode. If regular is passed, only regular polls will be allowed.
+    /// Otherwise, the user will be allowed to create a poll of any type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Upon receiving a message with this object, Telegram clients will remove the
+/// current custom keyboard and display the default letter-keyboard. By default,
+/// custom keyboards are displayed until a new keyboard is sent by a bot. An
+/// exception is made for one-time keyboards that are hidden immediately after the
+/// user presses a button (see ReplyKeyboardMarkup). Not supported in channels and
+/// for messages sent on behalf of a Telegram Business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReplyKeyboardRemove {
+    /// Requests clients to remove the custom keyboard (user will not be able to
+    /// summon this keyboard; if you want to hide the keyboard from sight but keep
+    /// it accessible, use one_time_keyboard in ReplyKeyboardMarkup)
+    pub remove_keyboard: bool,
+    /// Optional. Use this parameter if you want to remove the keyboard for specific
+    /// users only. Targets: 1) users that are @mentioned in the text of the Message
+    /// object; 2) if the bot's message is a reply to a message in the same chat and
+    /// forum topic, sender of the original message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an inline keyboard that appears right next to the message
+/// it belongs to.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of InlineKeyboardButton
+    /// objects
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one button of an inline keyboard. Exactly one of the
+/// optional fields must be used to specify type of the button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineKeyboardButton {
+    /// Label text on the button
+    pub text: String,
+    /// Optional. HTTP or tg:// URL to be opened when the button is pressed. Links
+    /// tg://user?id=<user_id> can be used to mention a user by their identifier
+    /// without using a username, if this is allowed by their privacy settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. Data to be sent in a callback query to the bot when the button is
+    /// pressed, 1-64 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+    /// Optional. Description of the Web App that will be launched when the user
+    /// presses the button. The Web App will be able to send an arbitrary message on
+    /// behalf of the user using the method answerWebAppQuery. Available only in
+    /// private chats between a user and the bot. Not supported for messages sent on
+    /// behalf of a Telegram Business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+    /// Optional. An HTTPS URL used to automatically authorize the user. Can be used
+    /// as a replacement for the Telegram Login Widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_url: Option<LoginUrl>,
+    /// Optional. If set, pressing the button will prompt the user to select one of
+    /// their chats, open that chat and insert the bot's username and the specified
+    /// inline query in the input field. May be empty, in which case just the bot's
+    /// username will be inserted. Not supported for messages sent on behalf of a
+    /// Telegram Business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query: Option<String>,
+    /// Optional. If set, pressing the button will insert the bot's username and the
+    /// specified inline query in the current chat's input field. May be empty, in
+    /// which case only the bot's username will be inserted. This offers a quick way
+    /// for the user to open your bot in inline mode in the same chat – good for
+    /// selecting something from multiple options. Not supported in channels and for
+    /// messages sent on behalf of a Telegram Business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query_current_chat: Option<String>,
+    /// Optional. If set, pressing the button will prompt the user to select one of
+    /// their chats of the specified type, open that chat and insert the bot's
+    /// username and the specified inline query in the input field. Not supported
+    /// for messages sent on behalf of a Telegram Business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query_chosen_chat: Option<SwitchInlineQueryChosenChat>,
+    /// Optional. Description of the game that will be launched when the user
+    /// presses the button. NOTE: This type of button must always be the first
+    /// button in the first row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_game: Option<CallbackGame>,
+    /// Optional. Specify True, to send a Pay button. Substrings “⭐” and “XTR” in
+    /// the buttons's text will be replaced with a Telegram Star icon. NOTE: This
+    /// type of button must always be the first button in the first row and can only
+    /// be used in invoice messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a parameter of the inline keyboard button used to
+/// automatically authorize a user. Serves as a great replacement for the Telegram
+/// Login Widget when the user is coming from Telegram. All the user needs to do is
+/// tap/click a button and confirm that they want to log in.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LoginUrl {
+    /// An HTTPS URL to be opened with user authorization data added to the query
+    /// string when the button is pressed. If the user refuses to provide
+    /// authorization data, the original URL without information about the user will
+    /// be opened. The data added is the same as described in Receiving
+    /// authorization data. NOTE: You must always check the hash of the received
+    /// data to verify the authentication and the integrity of the data as described
+    /// in Checking authorization.
+    pub url: String,
+    /// Optional. New text of the button in forwarded messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_text: Option<String>,
+    /// Optional. Username of a bot, which will be used for user authorization. See
+    /// Setting up a bot for more details. If not specified, the current bot's
+    /// username will be assumed. The url's domain must be the same as the domain
+    /// linked with the bot. See Linking your domain to the bot for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_username: Option<String>,
+    /// Optional. Pass True to request the permission for your bot to send messages
+    /// to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_write_access: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an inline button that switches the current user to inline
+/// mode in a chosen chat, with an optional default inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SwitchInlineQueryChosenChat {
+    /// Optional. The default inline query to be inserted in the input field. If
+    /// left empty, only the bot's username will be inserted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Optional. True, if private chats with users can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_user_chats: Option<bool>,
+    /// Optional. True, if private chats with bots can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_bot_chats: Option<bool>,
+    /// Optional. True, if group and supergroup chats can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_group_chats: Option<bool>,
+    /// Optional. True, if channel chats can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_channel_chats: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an incoming callback query from a callback button in an
+/// inline keyboard. If the button that originated the query was attached to a
+/// message sent by the bot, the field message will be present. If the button was
+/// attached to a message sent via the bot (in inline mode), the field
+/// inline_message_id will be present. Exactly one of the fields data or
+/// game_short_name will be present.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CallbackQuery {
+    /// Unique identifier for this query
+    pub id: String,
+    /// Sender
+    pub from: User,
+    /// Optional. Message sent by the bot with the callback button that originated
+    /// the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<MaybeInaccessibleMessage>,
+    /// Optional. Identifier of the message sent via the bot in inline mode, that
+    /// originated the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// Global identifier, uniquely corresponding to the chat to which the message
+    /// with the callback button was sent. Useful for high scores in games.
+    pub chat_instance: String,
+    /// Optional. Data associated with the callback button. Be aware that the
+    /// message originated the query can contain no callback buttons with this data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Optional. Short name of a Game to be returned, serves as the unique
+    /// identifier for the game
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_short_name: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Upon receiving a message with this object, Telegram clients will display a reply
+/// interface to the user (act as if the user has selected the bot's message and
+/// tapped 'Reply'). This can be extremely useful if you want to create user-
+/// friendly step-by-step interfaces without having to sacrifice privacy mode. Not
+/// supported in channels and for messages sent on behalf of a Telegram Business
+/// account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForceReply {
+    /// Shows reply interface to the user, as if they manually selected the bot's
+    /// message and tapped 'Reply'
+    pub force_reply: bool,
+    /// Optional. The placeholder to be shown in the input field when the reply is
+    /// active; 1-64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<String>,
+    /// Optional. Use this parameter if you want to force reply from specific users
+    /// only. Targets: 1) users that are @mentioned in the text of the Message
+    /// object; 2) if the bot's message is a reply to a message in the same chat and
+    /// forum topic, sender of the original message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a chat photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatPhoto {
+    /// File identifier of small (160x160) chat photo. This file_id can be used only
+    /// for photo download and only for as long as the photo is not changed.
+    pub small_file_id: String,
+    /// Unique file identifier of small (160x160) chat photo, which is supposed to
+    /// be the same over time and for different bots. Can't be used to download or
+    /// reuse the file.
+    pub small_file_unique_id: String,
+    /// File identifier of big (640x640) chat photo. This file_id can be used only
+    /// for photo download and only for as long as the photo is not changed.
+    pub big_file_id: String,
+    /// Unique file identifier of big (640x640) chat photo, which is supposed to be
+    /// the same over time and for different bots. Can't be used to download or
+    /// reuse the file.
+    pub big_file_unique_id: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an invite link for a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatInviteLink {
+    /// The invite link. If the link was created by another chat administrator, then
+    /// the second part of the link will be replaced with “…”.
+    pub invite_link: String,
+    /// Creator of the link
+    pub creator: User,
+    /// True, if users joining the chat via the link need to be approved by chat
+    /// administrators
+    pub creates_join_request: bool,
+    /// True, if the link is primary
+    pub is_primary: bool,
+    /// True, if the link is revoked
+    pub is_revoked: bool,
+    /// Optional. Invite link name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Optional. Point in time (Unix timestamp) when the link will expire or has
+    /// been expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    /// Optional. The maximum number of users that can be members of the chat
+    /// simultaneously after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    /// Optional. Number of pending join requests created using this link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_join_request_count: Option<i64>,
+    /// Optional. The number of seconds the subscription will be active for before
+    /// the next payment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i64>,
+    /// Optional. The amount of Telegram Stars a user must pay initially and after
+    /// each subsequent subscription period to be a member of the chat using the
+    /// link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_price: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the rights of an administrator in a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatAdministratorRights {
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+    /// True, if the administrator can access the chat event log, get boost list,
+    /// see hidden supergroup and channel members, report spam messages and ignore
+    /// slow mode. Implied by any other administrator privilege.
+    pub can_manage_chat: bool,
+    /// True, if the administrator can delete messages of other users
+    pub can_delete_messages: bool,
+    /// True, if the administrator can manage video chats
+    pub can_manage_video_chats: bool,
+    /// True, if the administrator can restrict, ban or unban chat members, or
+    /// access supergroup statistics
+    pub can_restrict_members: bool,
+    /// True, if the administrator can add new administrators with a subset of their
+    /// own privileges or demote administrators that they have promoted, directly or
+    /// indirectly (promoted by administrators that were appointed by the user)
+    pub can_promote_members: bool,
+    /// True, if the user is allowed to change the chat title, photo and other
+    /// settings
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+    /// True, if the administrator can post stories to the chat
+    pub can_post_stories: bool,
+    /// True, if the administrator can edit stories posted by other users, post
+    /// stories to the chat page, pin chat stories, and access the chat's story
+    /// archive
+    pub can_edit_stories: bool,
+    /// True, if the administrator can delete stories posted by other users
+    pub can_delete_stories: bool,
+    /// Optional. True, if the administrator can post messages in the channel, or
+    /// access channel statistics; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Optional. True, if the administrator can edit messages of other users and
+    /// can pin messages; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to pin messages; for groups and
+    /// supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to create, rename, close, and reopen
+    /// forum topics; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents changes in the status of a chat member.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberUpdated {
+    /// Chat the user belongs to
+    pub chat: Chat,
+    /// Performer of the action, which resulted in the change
+    pub from: User,
+    /// Date the change was done in Unix time
+    pub date: i64,
+    /// Previous information about the chat member
+    pub old_chat_member: ChatMember,
+    /// New information about the chat member
+    pub new_chat_member: ChatMember,
+    /// Optional. Chat invite link, which was used by the user to join the chat; for
+    /// joining by invite link events only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+    /// Optional. True, if the user joined the chat after sending a direct join
+    /// request without using an invite link and being approved by an administrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_join_request: Option<bool>,
+    /// Optional. True, if the user joined the chat via a chat folder invite link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_chat_folder_invite_link: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about one member of a chat. Currently, the
+/// following 6 types of chat members are supported:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum ChatMember {
+    ChatMemberOwner(ChatMemberOwner),
+    ChatMemberAdministrator(ChatMemberAdministrator),
+    ChatMemberMember(ChatMemberMember),
+    ChatMemberRestricted(ChatMemberRestricted),
+    ChatMemberLeft(ChatMemberLeft),
+    ChatMemberBanned(ChatMemberBanned),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for ChatMember {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("creator"), required: &["status", "user", "is_anonymous"] },
+            de::Variant { tag: Some("administrator"), required: &["status", "user", "can_be_edited", "is_anonymous", "can_manage_chat", "can_delete_messages", "can_manage_video_chats", "can_restrict_members", "can_promote_members", "can_change_info", "can_invite_users", "can_post_stories", "can_edit_stories", "can_delete_stories"] },
+            de::Variant { tag: Some("member"), required: &["status", "user"] },
+            de::Variant { tag: Some("restricted"), required: &["status", "user", "is_member", "can_send_messages", "can_send_audios", "can_send_documents", "can_send_photos", "can_send_videos", "can_send_video_notes", "can_send_voice_notes", "can_send_polls", "can_send_other_messages", "can_add_web_page_previews", "can_change_info", "can_invite_users", "can_pin_messages", "can_manage_topics", "until_date"] },
+            de::Variant { tag: Some("left"), required: &["status", "user"] },
+            de::Variant { tag: Some("kicked"), required: &["status", "user", "until_date"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("status"), VARIANTS) {
+            Some(0) => ChatMember::ChatMemberOwner(de::from_value(value)?),
+            Some(1) => ChatMember::ChatMemberAdministrator(de::from_value(value)?),
+            Some(2) => ChatMember::ChatMemberMember(de::from_value(value)?),
+            Some(3) => ChatMember::ChatMemberRestricted(de::from_value(value)?),
+            Some(4) => ChatMember::ChatMemberLeft(de::from_value(value)?),
+            Some(5) => ChatMember::ChatMemberBanned(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => ChatMember::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("ChatMember")),
+        })
+    }
+}
+
+
+/// Represents a chat member that owns the chat and has all administrator
+/// privileges.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberOwner {
+    /// The member's status in the chat, always “creator”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+    /// Optional. Custom title for this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_title: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a chat member that has some additional privileges.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberAdministrator {
+    /// The member's status in the chat, always “administrator”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// True, if the bot is allowed to edit administrator privileges of that user
+    pub can_be_edited: bool,
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+    /// True, if the administrator can access the chat event log, get boost list,
+    /// see hidden supergroup and channel members, report spam messages and ignore
+    /// slow mode. Implied by any other administrator privilege.
+    pub can_manage_chat: bool,
+    /// True, if the administrator can delete messages of other users
+    pub can_delete_messages: bool,
+    /// True, if the administrator can manage video chats
+    pub can_manage_video_chats: bool,
+    /// True, if the administrator can restrict, ban or unban chat members, or
+    /// access supergroup statistics
+    pub can_restrict_members: bool,
+    /// True, if the administrator can add new administrators with a subset of their
+    /// own privileges or demote administrators that they have promoted, directly or
+    /// indirectly (promoted by administrators that were appointed by the user)
+    pub can_promote_members: bool,
+    /// True, if the user is allowed to change the chat title, photo and other
+    /// settings
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+    /// True, if the administrator can post stories to the chat
+    pub can_post_stories: bool,
+    /// True, if the administrator can edit stories posted by other users, post
+    /// stories to the chat page, pin chat stories, and access the chat's story
+    /// archive
+    pub can_edit_stories: bool,
+    /// True, if the administrator can delete stories posted by other users
+    pub can_delete_stories: bool,
+    /// Optional. True, if the administrator can post messages in the channel, or
+    /// access channel statistics; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Optional. True, if the administrator can edit messages of other users and
+    /// can pin messages; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to pin messages; for groups and
+    /// supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to create, rename, close, and reopen
+    /// forum topics; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+    /// Optional. Custom title for this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_title: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a chat member that has no additional privileges or restrictions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberMember {
+    /// The member's status in the chat, always “member”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// Optional. Date when the user's subscription will expire; Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a chat member that is under certain restrictions in the chat.
+/// Supergroups only.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberRestricted {
+    /// The member's status in the chat, always “restricted”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// True, if the user is a member of the chat at the moment of the request
+    pub is_member: bool,
+    /// True, if the user is allowed to send text messages, contacts, giveaways,
+    /// giveaway winners, invoices, locations and venues
+    pub can_send_messages: bool,
+    /// True, if the user is allowed to send audios
+    pub can_send_audios: bool,
+    /// True, if the user is allowed to send documents
+    pub can_send_documents: bool,
+    /// True, if the user is allowed to send photos
+    pub can_send_photos: bool,
+    /// True, if the user is allowed to send videos
+    pub can_send_videos: bool,
+    /// True, if the user is allowed to send video notes
+    pub can_send_video_notes: bool,
+    /// True, if the user is allowed to send voice notes
+    pub can_send_voice_notes: bool,
+    /// True, if the user is allowed to send polls
+    pub can_send_polls: bool,
+    /// True, if the user is allowed to send animations, games, stickers and use
+    /// inline bots
+    pub can_send_other_messages: bool,
+    /// True, if the user is allowed to add web page previews to their messages
+    pub can_add_web_page_previews: bool,
+    /// True, if the user is allowed to change the chat title, photo and other
+    /// settings
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+    /// True, if the user is allowed to pin messages
+    pub can_pin_messages: bool,
+    /// True, if the user is allowed to create forum topics
+    pub can_manage_topics: bool,
+    /// Date when restrictions will be lifted for this user; Unix time. If 0, then
+    /// the user is restricted forever
+    pub until_date: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a chat member that isn't currently a member of the chat, but may join
+/// it themselves.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberLeft {
+    /// The member's status in the chat, always “left”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a chat member that was banned in the chat and can't return to the
+/// chat or view chat messages.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberBanned {
+    /// The member's status in the chat, always “kicked”
+    pub status: String,
+    /// Information about the user
+    pub user: User,
+    /// Date when restrictions will be lifted for this user; Unix time. If 0, then
+    /// the user is banned forever
+    pub until_date: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a join request sent to a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+    /// User that sent the join request
+    pub from: User,
+    /// Identifier of a private chat with the user who sent the join request. This
+    /// number may have more than 32 significant bits and some programming languages
+    /// may have difficulty/silent defects in interpreting it. But it has at most 52
+    /// significant bits, so a 64-bit integer or double-precision float type are
+    /// safe for storing this identifier. The bot can use this identifier for 5
+    /// minutes to send messages until the join request is processed, assuming no
+    /// other administrator contacted the user.
+    pub user_chat_id: i64,
+    /// Date the request was sent in Unix time
+    pub date: i64,
+    /// Optional. Bio of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// Optional. Chat invite link that was used by the user to send the join
+    /// request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes actions that a non-administrator user is allowed to take in a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatPermissions {
+    /// Optional. True, if the user is allowed to send text messages, contacts,
+    /// giveaways, giveaway winners, invoices, locations and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to send audios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_audios: Option<bool>,
+    /// Optional. True, if the user is allowed to send documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_documents: Option<bool>,
+    /// Optional. True, if the user is allowed to send photos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_photos: Option<bool>,
+    /// Optional. True, if the user is allowed to send videos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_videos: Option<bool>,
+    /// Optional. True, if the user is allowed to send video notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_video_notes: Option<bool>,
+    /// Optional. True, if the user is allowed to send voice notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_voice_notes: Option<bool>,
+    /// Optional. True, if the user is allowed to send polls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_polls: Option<bool>,
+    /// Optional. True, if the user is allowed to send animations, games, stickers
+    /// and use inline bots
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_other_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to add web page previews to their
+    /// messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_add_web_page_previews: Option<bool>,
+    /// Optional. True, if the user is allowed to change the chat title, photo and
+    /// other settings. Ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    /// Optional. True, if the user is allowed to invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    /// Optional. True, if the user is allowed to pin messages. Ignored in public
+    /// supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Optional. True, if the user is allowed to create forum topics. If omitted
+    /// defaults to the value of can_pin_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes the birthdate of a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Birthdate {
+    /// Day of the user's birth; 1-31
+    pub day: i64,
+    /// Month of the user's birth; 1-12
+    pub month: i64,
+    /// Optional. Year of the user's birth
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Contains information about the start page settings of a Telegram Business
+/// account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessIntro {
+    /// Optional. Title text of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Message text of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Optional. Sticker of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker: Option<Sticker>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Contains information about the location of a Telegram Business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessLocation {
+    /// Address of the business
+    pub address: String,
+    /// Optional. Location of the business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes an interval of time during which a business is open.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessOpeningHoursInterval {
+    /// The minute's sequence number in a week, starting on Monday, marking the
+    /// start of the time interval during which the business is open; 0 – 7 × 24 ×
+    /// 60
+    pub opening_minute: i64,
+    /// The minute's sequence number in a week, starting on Monday, marking the end
+    /// of the time interval during which the business is open; 0 – 8 × 24 × 60
+    pub closing_minute: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes the opening hours of a business.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessOpeningHours {
+    /// Unique name of the time zone for which the opening hours are defined
+    pub time_zone_name: String,
+    /// List of time intervals describing business opening hours
+    pub opening_hours: Vec<BusinessOpeningHoursInterval>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a location to which a chat is connected.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatLocation {
+    /// The location to which the supergroup is connected. Can't be a live location.
+    pub location: Location,
+    /// Location address; 1-64 characters, as defined by the chat owner
+    pub address: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the type of a reaction. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum ReactionType {
+    ReactionTypeEmoji(ReactionTypeEmoji),
+    ReactionTypeCustomEmoji(ReactionTypeCustomEmoji),
+    ReactionTypePaid(ReactionTypePaid),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for ReactionType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("emoji"), required: &["type", "emoji"] },
+            de::Variant { tag: Some("custom_emoji"), required: &["type", "custom_emoji_id"] },
+            de::Variant { tag: Some("paid"), required: &["type"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => ReactionType::ReactionTypeEmoji(de::from_value(value)?),
+            Some(1) => ReactionType::ReactionTypeCustomEmoji(de::from_value(value)?),
+            Some(2) => ReactionType::ReactionTypePaid(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => ReactionType::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("ReactionType")),
+        })
+    }
+}
+
+
+/// The reaction is based on an emoji.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypeEmoji {
+    /// Type of the reaction, always “emoji”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Reaction emoji. Currently, it can be one of "👍", "👎", "❤", "🔥", "🥰", "👏",
+    /// "😁", "🤔", "🤯", "😱", "🤬", "😢", "🎉", "🤩", "🤮", "💩", "🙏", "👌", "🕊", "🤡", "🥱",
+    /// "🥴", "😍", "🐳", "❤‍🔥", "🌚", "🌭", "💯", "🤣", "⚡", "🍌", "🏆", "💔", "🤨", "😐", "🍓",
+    /// "🍾", "💋", "🖕", "😈", "😴", "😭", "🤓", "👻", "👨‍💻", "👀", "🎃", "🙈", "😇", "😨", "🤝",
+    /// "✍", "🤗", "🫡", "🎅", "🎄", "☃", "💅", "🤪", "🗿", "🆒", "💘", "🙉", "🦄", "😘", "💊",
+    /// "🙊", "😎", "👾", "🤷‍♂", "🤷", "🤷‍♀", "😡"
+    pub emoji: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The reaction is based on a custom emoji.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypeCustomEmoji {
+    /// Type of the reaction, always “custom_emoji”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Custom emoji identifier
+    pub custom_emoji_id: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The reaction is paid.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypePaid {
+    /// Type of the reaction, always “paid”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a reaction added to a message along with the number of times it was
+/// added.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionCount {
+    /// Type of the reaction
+    #[serde(rename = "type")]
+    pub ty: ReactionType,
+    /// Number of times the reaction was added
+    pub total_count: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a change of a reaction on a message performed by a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageReactionUpdated {
+    /// The chat containing the message the user reacted to
+    pub chat: Chat,
+    /// Unique identifier of the message inside the chat
+    pub message_id: i64,
+    /// Optional. The user that changed the reaction, if the user isn't anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. The chat on behalf of which the reaction was changed, if the user
+    /// is anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_chat: Option<Chat>,
+    /// Date of the change in Unix time
+    pub date: i64,
+    /// Previous list of reaction types that were set by the user
+    pub old_reaction: Vec<ReactionType>,
+    /// New list of reaction types that have been set by the user
+    pub new_reaction: Vec<ReactionType>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents reaction changes on a message with anonymous reactions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageReactionCountUpdated {
+    /// The chat containing the message
+    pub chat: Chat,
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Date of the change in Unix time
+    pub date: i64,
+    /// List of reactions that are present on the message
+    pub reactions: Vec<ReactionCount>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a forum topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForumTopic {
+    /// Unique identifier of the forum topic
+    pub message_thread_id: i64,
+    /// Name of the topic
+    pub name: String,
+    /// Color of the topic icon in RGB format
+    pub icon_color: i64,
+    /// Optional. Unique identifier of the custom emoji shown as the topic icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a bot command.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommand {
+    /// Text of the command; 1-32 characters. Can contain only lowercase English
+    /// letters, digits and underscores.
+    pub command: String,
+    /// Description of the command; 1-256 characters.
+    pub description: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the scope to which bot commands are applied. Currently,
+/// the following 7 scopes are supported:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BotCommandScope {
+    BotCommandScopeDefault(BotCommandScopeDefault),
+    BotCommandScopeAllPrivateChats(BotCommandScopeAllPrivateChats),
+    BotCommandScopeAllGroupChats(BotCommandScopeAllGroupChats),
+    BotCommandScopeAllChatAdministrators(BotCommandScopeAllChatAdministrators),
+    BotCommandScopeChat(BotCommandScopeChat),
+    BotCommandScopeChatAdministrators(BotCommandScopeChatAdministrators),
+    BotCommandScopeChatMember(BotCommandScopeChatMember),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for BotCommandScope {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("default"), required: &["type"] },
+            de::Variant { tag: Some("all_private_chats"), required: &["type"] },
+            de::Variant { tag: Some("all_group_chats"), required: &["type"] },
+            de::Variant { tag: Some("all_chat_administrators"), required: &["type"] },
+            de::Variant { tag: Some("chat"), required: &["type", "chat_id"] },
+            de::Variant { tag: Some("chat_administrators"), required: &["type", "chat_id"] },
+            de::Variant { tag: Some("chat_member"), required: &["type", "chat_id", "user_id"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => BotCommandScope::BotCommandScopeDefault(de::from_value(value)?),
+            Some(1) => BotCommandScope::BotCommandScopeAllPrivateChats(de::from_value(value)?),
+            Some(2) => BotCommandScope::BotCommandScopeAllGroupChats(de::from_value(value)?),
+            Some(3) => BotCommandScope::BotCommandScopeAllChatAdministrators(de::from_value(value)?),
+            Some(4) => BotCommandScope::BotCommandScopeChat(de::from_value(value)?),
+            Some(5) => BotCommandScope::BotCommandScopeChatAdministrators(de::from_value(value)?),
+            Some(6) => BotCommandScope::BotCommandScopeChatMember(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => BotCommandScope::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("BotCommandScope")),
+        })
+    }
+}
+
+
+/// Represents the default scope of bot commands. Default commands are used if no
+/// commands with a narrower scope are specified for the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeDefault {
+    /// Scope type, must be default
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering all private chats.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeAllPrivateChats {
+    /// Scope type, must be all_private_chats
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering all group and supergroup chats.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeAllGroupChats {
+    /// Scope type, must be all_group_chats
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering all group and supergroup chat
+/// administrators.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeAllChatAdministrators {
+    /// Scope type, must be all_chat_administrators
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering a specific chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeChat {
+    /// Scope type, must be chat
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering all administrators of a specific
+/// group or supergroup chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeChatAdministrators {
+    /// Scope type, must be chat_administrators
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the scope of bot commands, covering a specific member of a group or
+/// supergroup chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommandScopeChatMember {
+    /// Scope type, must be chat_member
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the bot's name.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotName {
+    /// The bot's name
+    pub name: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the bot's description.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotDescription {
+    /// The bot's description
+    pub description: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the bot's short description.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotShortDescription {
+    /// The bot's short description
+    pub short_description: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the bot's menu button in a private chat. It should be one
+/// of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MenuButton {
+    MenuButtonCommands(MenuButtonCommands),
+    MenuButtonWebApp(MenuButtonWebApp),
+    MenuButtonDefault(MenuButtonDefault),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for MenuButton {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("commands"), required: &["type"] },
+            de::Variant { tag: Some("web_app"), required: &["type", "text", "web_app"] },
+            de::Variant { tag: Some("default"), required: &["type"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => MenuButton::MenuButtonCommands(de::from_value(value)?),
+            Some(1) => MenuButton::MenuButtonWebApp(de::from_value(value)?),
+            Some(2) => MenuButton::MenuButtonDefault(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => MenuButton::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("MenuButton")),
+        })
+    }
+}
+
+
+/// Represents a menu button, which opens the bot's list of commands.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MenuButtonCommands {
+    /// Type of the button, must be commands
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a menu button, which launches a Web App.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MenuButtonWebApp {
+    /// Type of the button, must be web_app
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Text on the button
+    pub text: String,
+    /// Description of the Web App that will be launched when the user presses the
+    /// button. The Web App will be able to send an arbitrary message on behalf of
+    /// the user using the method answerWebAppQuery. Alternatively, a t.me link to a
+    /// Web App of the bot can be specified in the object instead of the Web App's
+    /// URL, in which case the Web App will be opened as if the user pressed the
+    /// link.
+    pub web_app: WebAppInfo,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes that no specific value for the menu button was set.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MenuButtonDefault {
+    /// Type of the button, must be default
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the source of a chat boost. It can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum ChatBoostSource {
+    ChatBoostSourcePremium(ChatBoostSourcePremium),
+    ChatBoostSourceGiftCode(ChatBoostSourceGiftCode),
+    ChatBoostSourceGiveaway(ChatBoostSourceGiveaway),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for ChatBoostSource {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("premium"), required: &["source", "user"] },
+            de::Variant { tag: Some("gift_code"), required: &["source", "user"] },
+            de::Variant { tag: Some("giveaway"), required: &["source", "giveaway_message_id"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("source"), VARIANTS) {
+            Some(0) => ChatBoostSource::ChatBoostSourcePremium(de::from_value(value)?),
+            Some(1) => ChatBoostSource::ChatBoostSourceGiftCode(de::from_value(value)?),
+            Some(2) => ChatBoostSource::ChatBoostSourceGiveaway(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => ChatBoostSource::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("ChatBoostSource")),
+        })
+    }
+}
+
+
+/// The boost was obtained by subscribing to Telegram Premium or by gifting a
+/// Telegram Premium subscription to another user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourcePremium {
+    /// Source of the boost, always “premium”
+    pub source: String,
+    /// User that boosted the chat
+    pub user: User,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The boost was obtained by the creation of Telegram Premium gift codes to boost a
+/// chat. Each such code boosts the chat 4 times for the duration of the
+/// corresponding Telegram Premium subscription.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourceGiftCode {
+    /// Source of the boost, always “gift_code”
+    pub source: String,
+    /// User for which the gift code was created
+    pub user: User,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The boost was obtained by the creation of a Telegram Premium or a Telegram Star
+/// giveaway. This boosts the chat 4 times for the duration of the corresponding
+/// Telegram Premium subscription for Telegram Premium giveaways and
+/// prize_star_count / 500 times for one year for Telegram Star giveaways.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourceGiveaway {
+    /// Source of the boost, always “giveaway”
+    pub source: String,
+    /// Identifier of a message in the chat with the giveaway; the message could
+    /// have been deleted already. May be 0 if the message isn't sent yet.
+    pub giveaway_message_id: i64,
+    /// Optional. User that won the prize in the giveaway if any; for Telegram
+    /// Premium giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. The number of Telegram Stars to be split between giveaway winners;
+    /// for Telegram Star giveaways only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_star_count: Option<i64>,
+    /// Optional. True, if the giveaway was completed, but there was no user to win
+    /// the prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unclaimed: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about a chat boost.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoost {
+    /// Unique identifier of the boost
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the chat was boosted
+    pub add_date: i64,
+    /// Point in time (Unix timestamp) when the boost will automatically expire,
+    /// unless the booster's Telegram Premium subscription is prolonged
+    pub expiration_date: i64,
+    /// Source of the added boost
+    pub source: ChatBoostSource,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a boost added to a chat or changed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostUpdated {
+    /// Chat which was boosted
+    pub chat: Chat,
+    /// Information about the chat boost
+    pub boost: ChatBoost,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a boost removed from a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostRemoved {
+    /// Chat which was boosted
+    pub chat: Chat,
+    /// Unique identifier of the boost
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the boost was removed
+    pub remove_date: i64,
+    /// Source of the removed boost
+    pub source: ChatBoostSource,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a list of boosts added to a chat by a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UserChatBoosts {
+    /// The list of boosts added to the chat by the user
+    pub boosts: Vec<ChatBoost>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes the connection of the bot with a business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessConnection {
+    /// Unique identifier of the business connection
+    pub id: String,
+    /// Business account user that created the business connection
+    pub user: User,
+    /// Identifier of a private chat with the user who created the business
+    /// connection. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a 64-bit integer or double-
+    /// precision float type are safe for storing this identifier.
+    pub user_chat_id: i64,
+    /// Date the connection was established in Unix time
+    pub date: i64,
+    /// True, if the bot can act on behalf of the business account in chats that
+    /// were active in the last 24 hours
+    pub can_reply: bool,
+    /// True, if the connection is active
+    pub is_enabled: bool,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object is received when messages are deleted from a connected business
+/// account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessMessagesDeleted {
+    /// Unique identifier of the business connection
+    pub business_connection_id: String,
+    /// Information about a chat in the business account. The bot may not have
+    /// access to the chat or the corresponding user.
+    pub chat: Chat,
+    /// The list of identifiers of deleted messages in the chat of the business
+    /// account
+    pub message_ids: Vec<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes why a request was unsuccessful.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ResponseParameters {
+    /// Optional. The group has been migrated to a supergroup with the specified
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or
+    /// double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<i64>,
+    /// Optional. In case of exceeding flood control, the number of seconds left to
+    /// wait before the request can be repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the content of a media message to be sent. It should be
+/// one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum InputMedia {
+    InputMediaAnimation(InputMediaAnimation),
+    InputMediaDocument(InputMediaDocument),
+    InputMediaAudio(InputMediaAudio),
+    InputMediaPhoto(InputMediaPhoto),
+    InputMediaVideo(InputMediaVideo),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for InputMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("animation"), required: &["type", "media"] },
+            de::Variant { tag: Some("document"), required: &["type", "media"] },
+            de::Variant { tag: Some("audio"), required: &["type", "media"] },
+            de::Variant { tag: Some("photo"), required: &["type", "media"] },
+            de::Variant { tag: Some("video"), required: &["type", "media"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => InputMedia::InputMediaAnimation(de::from_value(value)?),
+            Some(1) => InputMedia::InputMediaDocument(de::from_value(value)?),
+            Some(2) => InputMedia::InputMediaAudio(de::from_value(value)?),
+            Some(3) => InputMedia::InputMediaPhoto(de::from_value(value)?),
+            Some(4) => InputMedia::InputMediaVideo(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => InputMedia::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("InputMedia")),
+        })
+    }
+}
+
+
+/// Represents a photo to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputMediaPhoto {
+    /// Type of the result, must be photo
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Pass True if the photo needs to be covered with a spoiler
+    /// animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a video to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputMediaVideo {
+    /// Type of the result, must be video
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation
+    /// for the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height should
+    /// not exceed 320. Ignored if the file is not uploaded using multipart/form-
+    /// data. Thumbnails can't be reused and can be only uploaded as a new file, so
+    /// you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+    /// using multipart/form-data under <file_attach_name>. More information on
+    /// Sending Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Optional. Caption of the video to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the video caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Optional. Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+    /// Optional. Pass True if the video needs to be covered with a spoiler
+    /// animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an animation file (GIF or H.264/MPEG-4 AVC video without sound) to be
+/// sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputMediaAnimation {
+    /// Type of the result, must be animation
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation
+    /// for the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height should
+    /// not exceed 320. Ignored if the file is not uploaded using multipart/form-
+    /// data. Thumbnails can't be reused and can be only uploaded as a new file, so
+    /// you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+    /// using multipart/form-data under <file_attach_name>. More information on
+    /// Sending Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Optional. Caption of the animation to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the animation caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Animation width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Animation height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Animation duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Optional. Pass True if the animation needs to be covered with a spoiler
+    /// animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an audio file to be treated as music to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputMediaAudio {
+    /// Type of the result, must be audio
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation
+    /// for the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height should
+    /// not exceed 320. Ignored if the file is not uploaded using multipart/form-
+    /// data. Thumbnails can't be reused and can be only uploaded as a new file, so
+    /// you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+    /// using multipart/form-data under <file_attach_name>. More information on
+    /// Sending Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Optional. Caption of the audio to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the audio caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Duration of the audio in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Optional. Performer of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Optional. Title of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a general file to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputMediaDocument {
+    /// Type of the result, must be document
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation
+    /// for the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height should
+    /// not exceed 320. Ignored if the file is not uploaded using multipart/form-
+    /// data. Thumbnails can't be reused and can be only uploaded as a new file, so
+    /// you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+    /// using multipart/form-data under <file_attach_name>. More information on
+    /// Sending Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Optional. Caption of the document to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Disables automatic server-side content type detection for files
+    /// uploaded using multipart/form-data. Always True, if the document is sent as
+    /// part of an album.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_content_type_detection: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the paid media to be sent. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum InputPaidMedia {
+    InputPaidMediaPhoto(InputPaidMediaPhoto),
+    InputPaidMediaVideo(InputPaidMediaVideo),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for InputPaidMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("photo"), required: &["type", "media"] },
+            de::Variant { tag: Some("video"), required: &["type", "media"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => InputPaidMedia::InputPaidMediaPhoto(de::from_value(value)?),
+            Some(1) => InputPaidMedia::InputPaidMediaVideo(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => InputPaidMedia::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("InputPaidMedia")),
+        })
+    }
+}
+
+
+/// The paid media to send is a photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputPaidMediaPhoto {
+    /// Type of the media, must be photo
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The paid media to send is a video.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputPaidMediaVideo {
+    /// Type of the media, must be video
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from the
+    /// Internet, or pass “attach://<file_attach_name>” to upload a new one using
+    /// multipart/form-data under <file_attach_name> name. More information on
+    /// Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation
+    /// for the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height should
+    /// not exceed 320. Ignored if the file is not uploaded using multipart/form-
+    /// data. Thumbnails can't be reused and can be only uploaded as a new file, so
+    /// you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+    /// using multipart/form-data under <file_attach_name>. More information on
+    /// Sending Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Optional. Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Type of the sticker, currently one of “regular”, “mask”, “custom_emoji”. The
+/// type of the sticker is independent from its format, which is determined by the
+/// fields is_animated and is_video.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerType {
+    Regular,
+    Mask,
+    CustomEmoji,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object represents a sticker.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Sticker {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Type of the sticker, currently one of “regular”, “mask”, “custom_emoji”. The
+    /// type of the sticker is independent from its format, which is determined by
+    /// the fields is_animated and is_video.
+    #[serde(rename = "type")]
+    pub ty: StickerType,
+    /// Sticker width
+    pub width: i64,
+    /// Sticker height
+    pub height: i64,
+    /// True, if the sticker is animated
+    pub is_animated: bool,
+    /// True, if the sticker is a video sticker
+    pub is_video: bool,
+    /// Optional. Sticker thumbnail in the .WEBP or .JPG format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. Emoji associated with the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Optional. Name of the sticker set to which the sticker belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_name: Option<String>,
+    /// Optional. For premium regular stickers, premium animation for the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_animation: Option<File>,
+    /// Optional. For mask stickers, the position where the mask should be placed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// Optional. For custom emoji stickers, unique identifier of the custom emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+    /// Optional. True, if the sticker must be repainted to a text color in
+    /// messages, the color of the Telegram Premium badge in emoji status, white
+    /// color on chat photos, or another appropriate color in other places
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_repainting: Option<bool>,
+    /// Optional. File size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a sticker set.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StickerSet {
+    /// Sticker set name
+    pub name: String,
+    /// Sticker set title
+    pub title: String,
+    /// Type of stickers in the set, currently one of “regular”, “mask”,
+    /// “custom_emoji”
+    pub sticker_type: StickerType,
+    /// List of all set stickers
+    pub stickers: Vec<Sticker>,
+    /// Optional. Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The part of the face relative to which the mask should be placed. One of
+/// “forehead”, “eyes”, “mouth”, or “chin”.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaskPositionPoint {
+    Forehead,
+    Eyes,
+    Mouth,
+    Chin,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object describes the position on faces where a mask should be placed by
+/// default.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MaskPosition {
+    /// The part of the face relative to which the mask should be placed. One of
+    /// “forehead”, “eyes”, “mouth”, or “chin”.
+    pub point: MaskPositionPoint,
+    /// Shift by X-axis measured in widths of the mask scaled to the face size, from
+    /// left to right. For example, choosing -1.0 will place mask just to the left
+    /// of the default mask position.
+    pub x_shift: f64,
+    /// Shift by Y-axis measured in heights of the mask scaled to the face size,
+    /// from top to bottom. For example, 1.0 will place the mask just below the
+    /// default mask position.
+    pub y_shift: f64,
+    /// Mask scaling coefficient. For example, 2.0 means double size.
+    pub scale: f64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Format of the added sticker, must be one of “static” for a .WEBP or .PNG image,
+/// “animated” for a .TGS animation, “video” for a WEBM video
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputStickerFormat {
+    Static,
+    Animated,
+    Video,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object describes a sticker to be added to a sticker set.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputSticker {
+    /// The added sticker. Pass a file_id as a String to send a file that already
+    /// exists on the Telegram servers, pass an HTTP URL as a String for Telegram to
+    /// get a file from the Internet, upload a new one using multipart/form-data, or
+    /// pass “attach://<file_attach_name>” to upload a new one using multipart/form-
+    /// data under <file_attach_name> name. Animated and video stickers can't be
+    /// uploaded via HTTP URL. More information on Sending Files »
+    pub sticker: String,
+    /// Format of the added sticker, must be one of “static” for a .WEBP or .PNG
+    /// image, “animated” for a .TGS animation, “video” for a WEBM video
+    pub format: InputStickerFormat,
+    /// List of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+    /// Optional. Position where the mask should be placed on faces. For “mask”
+    /// stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// Optional. List of 0-20 search keywords for the sticker with total length of
+    /// up to 64 characters. For “regular” and “custom_emoji” stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Type of the chat from which the inline query was sent. Can be either “sender”
+/// for a private chat with the inline query sender, “private”, “group”,
+/// “supergroup”, or “channel”. The chat type should be always known for requests
+/// sent from official clients and most third-party clients, unless the request was
+/// sent from a secret chat
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InlineQueryChatType {
+    Sender,
+    Private,
+    Group,
+    Supergroup,
+    Channel,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// This object represents an incoming inline query. When the user sends an empty
+/// query, your bot could return some default or trending results.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQuery {
+    /// Unique identifier for this query
+    pub id: String,
+    /// Sender
+    pub from: User,
+    /// Text of the query (up to 256 characters)
+    pub query: String,
+    /// Offset of the results to be returned, can be controlled by the bot
+    pub offset: String,
+    /// Optional. Type of the chat from which the inline query was sent. Can be
+    /// either “sender” for a private chat with the inline query sender, “private”,
+    /// “group”, “supergroup”, or “channel”. The chat type should be always known
+    /// for requests sent from official clients and most third-party clients, unless
+    /// the request was sent from a secret chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_type: Option<InlineQueryChatType>,
+    /// Optional. Sender location, only for bots that request user location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a button to be shown above inline query results. You must
+/// use exactly one of the optional fields.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultsButton {
+    /// Label text on the button
+    pub text: String,
+    /// Optional. Description of the Web App that will be launched when the user
+    /// presses the button. The Web App will be able to switch back to the inline
+    /// mode using the method switchInlineQuery inside the Web App.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+    /// Optional. Deep-linking parameter for the /start message sent to the bot when
+    /// a user presses the button. 1-64 characters, only A-Z, a-z, 0-9, “_” and “-”
+    /// are allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one result of an inline query. Telegram clients currently
+/// support results of the following 20 types:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum InlineQueryResult {
+    InlineQueryResultCachedAudio(InlineQueryResultCachedAudio),
+    InlineQueryResultCachedDocument(InlineQueryResultCachedDocument),
+    InlineQueryResultCachedGif(InlineQueryResultCachedGif),
+    InlineQueryResultCachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    InlineQueryResultCachedPhoto(InlineQueryResultCachedPhoto),
+    InlineQueryResultCachedSticker(InlineQueryResultCachedSticker),
+    InlineQueryResultCachedVideo(InlineQueryResultCachedVideo),
+    InlineQueryResultCachedVoice(InlineQueryResultCachedVoice),
+    InlineQueryResultArticle(InlineQueryResultArticle),
+    InlineQueryResultAudio(InlineQueryResultAudio),
+    InlineQueryResultContact(InlineQueryResultContact),
+    InlineQueryResultGame(InlineQueryResultGame),
+    InlineQueryResultDocument(InlineQueryResultDocument),
+    InlineQueryResultGif(InlineQueryResultGif),
+    InlineQueryResultLocation(InlineQueryResultLocation),
+    InlineQueryResultMpeg4Gif(InlineQueryResultMpeg4Gif),
+    InlineQueryResultPhoto(InlineQueryResultPhoto),
+    InlineQueryResultVenue(InlineQueryResultVenue),
+    InlineQueryResultVideo(InlineQueryResultVideo),
+    InlineQueryResultVoice(InlineQueryResultVoice),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("audio"), required: &["type", "id", "audio_file_id"] },
+            de::Variant { tag: Some("document"), required: &["type", "id", "title", "document_file_id"] },
+            de::Variant { tag: Some("gif"), required: &["type", "id", "gif_file_id"] },
+            de::Variant { tag: Some("mpeg"), required: &["type", "id", "mpeg4_file_id"] },
+            de::Variant { tag: Some("photo"), required: &["type", "id", "photo_file_id"] },
+            de::Variant { tag: Some("sticker"), required: &["type", "id", "sticker_file_id"] },
+            de::Variant { tag: Some("video"), required: &["type", "id", "video_file_id", "title"] },
+            de::Variant { tag: Some("voice"), required: &["type", "id", "voice_file_id", "title"] },
+            de::Variant { tag: Some("article"), required: &["type", "id", "title", "input_message_content"] },
+            de::Variant { tag: Some("audio"), required: &["type", "id", "audio_url", "title"] },
+            de::Variant { tag: Some("contact"), required: &["type", "id", "phone_number", "first_name"] },
+            de::Variant { tag: Some("game"), required: &["type", "id", "game_short_name"] },
+            de::Variant { tag: Some("document"), required: &["type", "id", "title", "document_url", "mime_type"] },
+            de::Variant { tag: Some("gif"), required: &["type", "id", "gif_url", "thumbnail_url"] },
+            de::Variant { tag: Some("location"), required: &["type", "id", "latitude", "longitude", "title"] },
+            de::Variant { tag: Some("mpeg"), required: &["type", "id", "mpeg4_url", "thumbnail_url"] },
+            de::Variant { tag: Some("photo"), required: &["type", "id", "photo_url", "thumbnail_url"] },
+            de::Variant { tag: Some("venue"), required: &["type", "id", "latitude", "longitude", "title", "address"] },
+            de::Variant { tag: Some("video"), required: &["type", "id", "video_url", "mime_type", "thumbnail_url", "title"] },
+            de::Variant { tag: Some("voice"), required: &["type", "id", "voice_url", "title"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => InlineQueryResult::InlineQueryResultCachedAudio(de::from_value(value)?),
+            Some(1) => InlineQueryResult::InlineQueryResultCachedDocument(de::from_value(value)?),
+            Some(2) => InlineQueryResult::InlineQueryResultCachedGif(de::from_value(value)?),
+            Some(3) => InlineQueryResult::InlineQueryResultCachedMpeg4Gif(de::from_value(value)?),
+            Some(4) => InlineQueryResult::InlineQueryResultCachedPhoto(de::from_value(value)?),
+            Some(5) => InlineQueryResult::InlineQueryResultCachedSticker(de::from_value(value)?),
+            Some(6) => InlineQueryResult::InlineQueryResultCachedVideo(de::from_value(value)?),
+            Some(7) => InlineQueryResult::InlineQueryResultCachedVoice(de::from_value(value)?),
+            Some(8) => InlineQueryResult::InlineQueryResultArticle(de::from_value(value)?),
+            Some(9) => InlineQueryResult::InlineQueryResultAudio(de::from_value(value)?),
+            Some(10) => InlineQueryResult::InlineQueryResultContact(de::from_value(value)?),
+            Some(11) => InlineQueryResult::InlineQueryResultGame(de::from_value(value)?),
+            Some(12) => InlineQueryResult::InlineQueryResultDocument(de::from_value(value)?),
+            Some(13) => InlineQueryResult::InlineQueryResultGif(de::from_value(value)?),
+            Some(14) => InlineQueryResult::InlineQueryResultLocation(de::from_value(value)?),
+            Some(15) => InlineQueryResult::InlineQueryResultMpeg4Gif(de::from_value(value)?),
+            Some(16) => InlineQueryResult::InlineQueryResultPhoto(de::from_value(value)?),
+            Some(17) => InlineQueryResult::InlineQueryResultVenue(de::from_value(value)?),
+            Some(18) => InlineQueryResult::InlineQueryResultVideo(de::from_value(value)?),
+            Some(19) => InlineQueryResult::InlineQueryResultVoice(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => InlineQueryResult::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("InlineQueryResult")),
+        })
+    }
+}
+
+
+/// Represents a link to an article or web page.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultArticle {
+    /// Type of the result, must be article
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Title of the result
+    pub title: String,
+    /// Content of the message to be sent
+    pub input_message_content: InputMessageContent,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. URL of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. Pass True if you don't want the URL to be shown in the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_url: Option<bool>,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a photo. By default, this photo will be sent by the user
+/// with optional caption. Alternatively, you can use input_message_content to send
+/// a message with the specified content instead of the photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultPhoto {
+    /// Type of the result, must be photo
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL of the photo. Photo must be in JPEG format. Photo size must not
+    /// exceed 5MB
+    pub photo_url: String,
+    /// URL of the thumbnail for the photo
+    pub thumbnail_url: String,
+    /// Optional. Width of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    /// Optional. Height of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to an animated GIF file. By default, this animated GIF file
+/// will be sent by the user with optional caption. Alternatively, you can use
+/// input_message_content to send a message with the specified content instead of
+/// the animation.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultGif {
+    /// Type of the result, must be gif
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL for the GIF file
+    pub gif_url: String,
+    /// Optional. Width of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_width: Option<i64>,
+    /// Optional. Height of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_height: Option<i64>,
+    /// Optional. Duration of the GIF in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_duration: Option<i64>,
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: String,
+    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”,
+    /// “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Caption of the animation to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the animation caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound).
+/// By default, this animated MPEG-4 file will be sent by the user with optional
+/// caption. Alternatively, you can use input_message_content to send a message with
+/// the specified content instead of the animation.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultMpeg4Gif {
+    /// Type of the result, must be mpeg4_gif
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL for the MPEG4 file
+    pub mpeg4_url: String,
+    /// Optional. Width of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_width: Option<i64>,
+    /// Optional. Height of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_height: Option<i64>,
+    /// Optional. Duration of the video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_duration: Option<i64>,
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: String,
+    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”,
+    /// “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Caption of the video animation to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the video animation caption. See
+    /// formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a page containing an embedded video player or a video file.
+/// By default, this video file will be sent by the user with an optional caption.
+/// Alternatively, you can use input_message_content to send a message with the
+/// specified content instead of the video.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultVideo {
+    /// Type of the result, must be video
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL for the embedded video player or video file
+    pub video_url: String,
+    /// MIME type of the content of the video URL, “text/html” or “video/mp4”
+    pub mime_type: String,
+    /// URL of the thumbnail (JPEG only) for the video
+    pub thumbnail_url: String,
+    /// Title for the result
+    pub title: String,
+    /// Optional. Caption of the video to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the video caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_width: Option<i64>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_height: Option<i64>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<i64>,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the video. This field
+    /// is required if InlineQueryResultVideo is used to send an HTML-page as a
+    /// result (e.g., a YouTube video).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to an MP3 audio file. By default, this audio file will be sent
+/// by the user. Alternatively, you can use input_message_content to send a message
+/// with the specified content instead of the audio.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultAudio {
+    /// Type of the result, must be audio
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL for the audio file
+    pub audio_url: String,
+    /// Title
+    pub title: String,
+    /// Optional. Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the audio caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Performer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Optional. Audio duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_duration: Option<i64>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a voice recording in an .OGG container encoded with OPUS.
+/// By default, this voice recording will be sent by the user. Alternatively, you
+/// can use input_message_content to send a message with the specified content
+/// instead of the the voice message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultVoice {
+    /// Type of the result, must be voice
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid URL for the voice recording
+    pub voice_url: String,
+    /// Recording title
+    pub title: String,
+    /// Optional. Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the voice message caption. See
+    /// formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Recording duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_duration: Option<i64>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the voice recording
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a file. By default, this file will be sent by the user with
+/// an optional caption. Alternatively, you can use input_message_content to send a
+/// message with the specified content instead of the file. Currently, only .PDF and
+/// .ZIP files can be sent using this method.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultDocument {
+    /// Type of the result, must be document
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Title for the result
+    pub title: String,
+    /// Optional. Caption of the document to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// A valid URL for the file
+    pub document_url: String,
+    /// MIME type of the content of the file, either “application/pdf” or
+    /// “application/zip”
+    pub mime_type: String,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Optional. URL of the thumbnail (JPEG only) for the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a location on a map. By default, the location will be sent by the
+/// user. Alternatively, you can use input_message_content to send a message with
+/// the specified content instead of the location.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultLocation {
+    /// Type of the result, must be location
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Location latitude in degrees
+    pub latitude: f64,
+    /// Location longitude in degrees
+    pub longitude: f64,
+    /// Location title
+    pub title: String,
+    /// Optional. The radius of uncertainty for the location, measured in meters;
+    /// 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Optional. Period in seconds during which the location can be updated, should
+    /// be between 60 and 86400, or 0x7FFFFFFF for live locations that can be edited
+    /// indefinitely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// Optional. For live locations, a direction in which the user is moving, in
+    /// degrees. Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// Optional. For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000 if
+    /// specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a venue. By default, the venue will be sent by the user.
+/// Alternatively, you can use input_message_content to send a message with the
+/// specified content instead of the venue.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultVenue {
+    /// Type of the result, must be venue
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Latitude of the venue location in degrees
+    pub latitude: f64,
+    /// Longitude of the venue location in degrees
+    pub longitude: f64,
+    /// Title of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Optional. Foursquare identifier of the venue if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Optional. Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or
+    /// “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    /// Optional. Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    /// Optional. Google Places type of the venue. (See supported types.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a contact with a phone number. By default, this contact will be sent
+/// by the user. Alternatively, you can use input_message_content to send a message
+/// with the specified content instead of the contact.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultContact {
+    /// Type of the result, must be contact
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Contact's phone number
+    pub phone_number: String,
+    /// Contact's first name
+    pub first_name: String,
+    /// Optional. Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. Additional data about the contact in the form of a vCard, 0-2048
+    /// bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a Game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultGame {
+    /// Type of the result, must be game
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Short name of the game
+    pub game_short_name: String,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a photo stored on the Telegram servers. By default, this
+/// photo will be sent by the user with an optional caption. Alternatively, you can
+/// use input_message_content to send a message with the specified content instead
+/// of the photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedPhoto {
+    /// Type of the result, must be photo
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier of the photo
+    pub photo_file_id: String,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to an animated GIF file stored on the Telegram servers. By
+/// default, this animated GIF file will be sent by the user with an optional
+/// caption. Alternatively, you can use input_message_content to send a message with
+/// specified content instead of the animation.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedGif {
+    /// Type of the result, must be gif
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the GIF file
+    pub gif_file_id: String,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Caption of the GIF file to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the GIF animation caption. See
+    /// formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound)
+/// stored on the Telegram servers. By default, this animated MPEG-4 file will be
+/// sent by the user with an optional caption. Alternatively, you can use
+/// input_message_content to send a message with the specified content instead of
+/// the animation.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedMpeg4Gif {
+    /// Type of the result, must be mpeg4_gif
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the MPEG4 file
+    pub mpeg4_file_id: String,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Caption of the MPEG-4 file to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the video animation caption. See
+    /// formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a sticker stored on the Telegram servers. By default, this
+/// sticker will be sent by the user. Alternatively, you can use
+/// input_message_content to send a message with the specified content instead of
+/// the sticker.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedSticker {
+    /// Type of the result, must be sticker
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier of the sticker
+    pub sticker_file_id: String,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a file stored on the Telegram servers. By default, this
+/// file will be sent by the user with an optional caption. Alternatively, you can
+/// use input_message_content to send a message with the specified content instead
+/// of the file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedDocument {
+    /// Type of the result, must be document
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Title for the result
+    pub title: String,
+    /// A valid file identifier for the file
+    pub document_file_id: String,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Caption of the document to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a video file stored on the Telegram servers. By default,
+/// this video file will be sent by the user with an optional caption.
+/// Alternatively, you can use input_message_content to send a message with the
+/// specified content instead of the video.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedVideo {
+    /// Type of the result, must be video
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the video file
+    pub video_file_id: String,
+    /// Title for the result
+    pub title: String,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Caption of the video to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the video caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to a voice message stored on the Telegram servers. By default,
+/// this voice message will be sent by the user. Alternatively, you can use
+/// input_message_content to send a message with the specified content instead of
+/// the voice message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedVoice {
+    /// Type of the result, must be voice
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the voice message
+    pub voice_file_id: String,
+    /// Voice message title
+    pub title: String,
+    /// Optional. Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the voice message caption. See
+    /// formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the voice message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a link to an MP3 audio file stored on the Telegram servers. By
+/// default, this audio file will be sent by the user. Alternatively, you can use
+/// input_message_content to send a message with the specified content instead of
+/// the audio.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InlineQueryResultCachedAudio {
+    /// Type of the result, must be audio
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the audio file
+    pub audio_file_id: String,
+    /// Optional. Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the audio caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents the content of a message to be sent as a result of an
+/// inline query. Telegram clients currently support the following 5 types:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum InputMessageContent {
+    InputTextMessageContent(InputTextMessageContent),
+    InputLocationMessageContent(InputLocationMessageContent),
+    InputVenueMessageContent(InputVenueMessageContent),
+    InputContactMessageContent(InputContactMessageContent),
+    InputInvoiceMessageContent(InputInvoiceMessageContent),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for InputMessageContent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: None, required: &["message_text"] },
+            de::Variant { tag: None, required: &["latitude", "longitude"] },
+            de::Variant { tag: None, required: &["latitude", "longitude", "title", "address"] },
+            de::Variant { tag: None, required: &["phone_number", "first_name"] },
+            de::Variant { tag: None, required: &["title", "description", "payload", "currency", "prices"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, None, VARIANTS) {
+            Some(0) => InputMessageContent::InputTextMessageContent(de::from_value(value)?),
+            Some(1) => InputMessageContent::InputLocationMessageContent(de::from_value(value)?),
+            Some(2) => InputMessageContent::InputVenueMessageContent(de::from_value(value)?),
+            Some(3) => InputMessageContent::InputContactMessageContent(de::from_value(value)?),
+            Some(4) => InputMessageContent::InputInvoiceMessageContent(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => InputMessageContent::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("InputMessageContent")),
+        })
+    }
+}
+
+
+/// Represents the content of a text message to be sent as the result of an inline
+/// query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputTextMessageContent {
+    /// Text of the message to be sent, 1-4096 characters
+    pub message_text: String,
+    /// Optional. Mode for parsing entities in the message text. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// Optional. List of special entities that appear in message text, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Optional. Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the content of a location message to be sent as the result of an
+/// inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputLocationMessageContent {
+    /// Latitude of the location in degrees
+    pub latitude: f64,
+    /// Longitude of the location in degrees
+    pub longitude: f64,
+    /// Optional. The radius of uncertainty for the location, measured in meters;
+    /// 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Optional. Period in seconds during which the location can be updated, should
+    /// be between 60 and 86400, or 0x7FFFFFFF for live locations that can be edited
+    /// indefinitely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// Optional. For live locations, a direction in which the user is moving, in
+    /// degrees. Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// Optional. For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000 if
+    /// specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the content of a venue message to be sent as the result of an inline
+/// query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputVenueMessageContent {
+    /// Latitude of the venue in degrees
+    pub latitude: f64,
+    /// Longitude of the venue in degrees
+    pub longitude: f64,
+    /// Name of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Optional. Foursquare identifier of the venue, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Optional. Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or
+    /// “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    /// Optional. Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    /// Optional. Google Places type of the venue. (See supported types.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the content of a contact message to be sent as the result of an
+/// inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputContactMessageContent {
+    /// Contact's phone number
+    pub phone_number: String,
+    /// Contact's first name
+    pub first_name: String,
+    /// Optional. Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. Additional data about the contact in the form of a vCard, 0-2048
+    /// bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents the content of an invoice message to be sent as the result of an
+/// inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputInvoiceMessageContent {
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the
+    /// user, use it for your internal processes.
+    pub payload: String,
+    /// Optional. Payment provider token, obtained via @BotFather. Pass an empty
+    /// string for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, see more on currencies. Pass “XTR” for
+    /// payments in Telegram Stars.
+    pub currency: String,
+    /// Price breakdown, a JSON-serialized list of components (e.g. product price,
+    /// tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain
+    /// exactly one item for payments in Telegram Stars.
+    pub prices: Vec<LabeledPrice>,
+    /// Optional. The maximum accepted amount for tips in the smallest units of the
+    /// currency (integer, not float/double). For example, for a maximum tip of US$
+    /// 1.45 pass max_tip_amount = 145. See the exp parameter in currencies.json, it
+    /// shows the number of digits past the decimal point for each currency (2 for
+    /// the majority of currencies). Defaults to 0. Not supported for payments in
+    /// Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+    /// Optional. A JSON-serialized array of suggested amounts of tip in the
+    /// smallest units of the currency (integer, not float/double). At most 4
+    /// suggested tip amounts can be specified. The suggested tip amounts must be
+    /// positive, passed in a strictly increased order and must not exceed
+    /// max_tip_amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+    /// Optional. A JSON-serialized object for data about the invoice, which will be
+    /// shared with the payment provider. A detailed description of the required
+    /// fields should be provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    /// Optional. URL of the product photo for the invoice. Can be a photo of the
+    /// goods or a marketing image for a service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    /// Optional. Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+    /// Optional. Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    /// Optional. Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    /// Optional. Pass True if you require the user's full name to complete the
+    /// order. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    /// Optional. Pass True if you require the user's phone number to complete the
+    /// order. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    /// Optional. Pass True if you require the user's email address to complete the
+    /// order. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    /// Optional. Pass True if you require the user's shipping address to complete
+    /// the order. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    /// Optional. Pass True if the user's phone number should be sent to the
+    /// provider. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    /// Optional. Pass True if the user's email address should be sent to the
+    /// provider. Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// Optional. Pass True if the final price depends on the shipping method.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents a result of an inline query that was chosen by the user and sent to
+/// their chat partner.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChosenInlineResult {
+    /// The unique identifier for the result that was chosen
+    pub result_id: String,
+    /// The user that chose the result
+    pub from: User,
+    /// Optional. Sender location, only for bots that require user location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Optional. Identifier of the sent inline message. Available only if there is
+    /// an inline keyboard attached to the message. Will be also received in
+    /// callback queries and can be used to edit the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// The query that was used to obtain the result
+    pub query: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes an inline message sent by a Web App on behalf of a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SentWebAppMessage {
+    /// Optional. Identifier of the sent inline message. Available only if there is
+    /// an inline keyboard attached to the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a portion of the price for goods or services.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LabeledPrice {
+    /// Portion label
+    pub label: String,
+    /// Price of the product in the smallest units of the currency (integer, not
+    /// float/double). For example, for a price of US$ 1.45 pass amount = 145. See
+    /// the exp parameter in currencies.json, it shows the number of digits past the
+    /// decimal point for each currency (2 for the majority of currencies).
+    pub amount: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains basic information about an invoice.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Invoice {
+    /// Product name
+    pub title: String,
+    /// Product description
+    pub description: String,
+    /// Unique bot deep-linking parameter that can be used to generate this invoice
+    pub start_parameter: String,
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not
+    /// float/double). For example, for a price of US$ 1.45 pass amount = 145. See
+    /// the exp parameter in currencies.json, it shows the number of digits past the
+    /// decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a shipping address.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingAddress {
+    /// Two-letter ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+    /// State, if applicable
+    pub state: String,
+    /// City
+    pub city: String,
+    /// First line for the address
+    pub street_line1: String,
+    /// Second line for the address
+    pub street_line2: String,
+    /// Address post code
+    pub post_code: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents information about an order.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OrderInfo {
+    /// Optional. User name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Optional. User's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Optional. User email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Optional. User shipping address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<ShippingAddress>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one shipping option.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingOption {
+    /// Shipping option identifier
+    pub id: String,
+    /// Option title
+    pub title: String,
+    /// List of price portions
+    pub prices: Vec<LabeledPrice>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains basic information about a successful payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not
+    /// float/double). For example, for a price of US$ 1.45 pass amount = 145. See
+    /// the exp parameter in currencies.json, it shows the number of digits past the
+    /// decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i64,
+    /// Bot-specified invoice payload
+    pub invoice_payload: String,
+    /// Optional. Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+    /// Optional. Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains basic information about a refunded payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RefundedPayment {
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram
+    /// Stars. Currently, always “XTR”
+    pub currency: String,
+    /// Total refunded price in the smallest units of the currency (integer, not
+    /// float/double). For example, for a price of US$ 1.45, total_amount = 145. See
+    /// the exp parameter in currencies.json, it shows the number of digits past the
+    /// decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i64,
+    /// Bot-specified invoice payload
+    pub invoice_payload: String,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Optional. Provider payment identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_payment_charge_id: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about an incoming shipping query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingQuery {
+    /// Unique query identifier
+    pub id: String,
+    /// User who sent the query
+    pub from: User,
+    /// Bot-specified invoice payload
+    pub invoice_payload: String,
+    /// User specified shipping address
+    pub shipping_address: ShippingAddress,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about an incoming pre-checkout query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PreCheckoutQuery {
+    /// Unique query identifier
+    pub id: String,
+    /// User who sent the query
+    pub from: User,
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not
+    /// float/double). For example, for a price of US$ 1.45 pass amount = 145. See
+    /// the exp parameter in currencies.json, it shows the number of digits past the
+    /// decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i64,
+    /// Bot-specified invoice payload
+    pub invoice_payload: String,
+    /// Optional. Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+    /// Optional. Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object contains information about a paid media purchase.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaPurchased {
+    /// User who purchased the media
+    pub from: User,
+    /// Bot-specified paid media payload
+    pub paid_media_payload: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the state of a revenue withdrawal operation. Currently, it
+/// can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum RevenueWithdrawalState {
+    RevenueWithdrawalStatePending(RevenueWithdrawalStatePending),
+    RevenueWithdrawalStateSucceeded(RevenueWithdrawalStateSucceeded),
+    RevenueWithdrawalStateFailed(RevenueWithdrawalStateFailed),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for RevenueWithdrawalState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("pending"), required: &["type"] },
+            de::Variant { tag: Some("succeeded"), required: &["type", "date", "url"] },
+            de::Variant { tag: Some("failed"), required: &["type"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => RevenueWithdrawalState::RevenueWithdrawalStatePending(de::from_value(value)?),
+            Some(1) => RevenueWithdrawalState::RevenueWithdrawalStateSucceeded(de::from_value(value)?),
+            Some(2) => RevenueWithdrawalState::RevenueWithdrawalStateFailed(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => RevenueWithdrawalState::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("RevenueWithdrawalState")),
+        })
+    }
+}
+
+
+/// The withdrawal is in progress.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStatePending {
+    /// Type of the state, always “pending”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The withdrawal succeeded.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStateSucceeded {
+    /// Type of the state, always “succeeded”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Date the withdrawal was completed in Unix time
+    pub date: i64,
+    /// An HTTPS URL that can be used to see transaction details
+    pub url: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// The withdrawal failed and the transaction was refunded.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStateFailed {
+    /// Type of the state, always “failed”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object describes the source of a transaction, or its recipient for outgoing
+/// transactions. Currently, it can be one of
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum TransactionPartner {
+    TransactionPartnerUser(TransactionPartnerUser),
+    TransactionPartnerFragment(TransactionPartnerFragment),
+    TransactionPartnerTelegramAds(TransactionPartnerTelegramAds),
+    TransactionPartnerOther(TransactionPartnerOther),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for TransactionPartner {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("user"), required: &["type", "user"] },
+            de::Variant { tag: Some("fragment"), required: &["type"] },
+            de::Variant { tag: Some("telegram_ads"), required: &["type"] },
+            de::Variant { tag: Some("other"), required: &["type"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("type"), VARIANTS) {
+            Some(0) => TransactionPartner::TransactionPartnerUser(de::from_value(value)?),
+            Some(1) => TransactionPartner::TransactionPartnerFragment(de::from_value(value)?),
+            Some(2) => TransactionPartner::TransactionPartnerTelegramAds(de::from_value(value)?),
+            Some(3) => TransactionPartner::TransactionPartnerOther(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => TransactionPartner::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("TransactionPartner")),
+        })
+    }
+}
+
+
+/// Describes a transaction with a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerUser {
+    /// Type of the transaction partner, always “user”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Information about the user
+    pub user: User,
+    /// Optional. Bot-specified invoice payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_payload: Option<String>,
+    /// Optional. Information about the paid media bought by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media: Option<Vec<PaidMedia>>,
+    /// Optional. Bot-specified paid media payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media_payload: Option<String>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes a withdrawal transaction with Fragment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerFragment {
+    /// Type of the transaction partner, always “fragment”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Optional. State of the transaction if the transaction is outgoing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_state: Option<RevenueWithdrawalState>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes a withdrawal transaction to the Telegram Ads platform.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerTelegramAds {
+    /// Type of the transaction partner, always “telegram_ads”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes a transaction with an unknown source or recipient.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerOther {
+    /// Type of the transaction partner, always “other”
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes a Telegram Star transaction.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of the
+    /// original transaction for refund transactions. Coincides with
+    /// SuccessfulPayment.telegram_payment_charge_id for successful incoming
+    /// payments from users.
+    pub id: String,
+    /// Number of Telegram Stars transferred by the transaction
+    pub amount: i64,
+    /// Date the transaction was created in Unix time
+    pub date: i64,
+    /// Optional. Source of an incoming transaction (e.g., a user purchasing goods
+    /// or services, Fragment refunding a failed withdrawal). Only for incoming
+    /// transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<TransactionPartner>,
+    /// Optional. Receiver of an outgoing transaction (e.g., a user for a purchase
+    /// refund, Fragment for a withdrawal). Only for outgoing transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<TransactionPartner>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Contains a list of Telegram Star transactions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Contains information about Telegram Passport data shared with the bot by the
+/// user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport elements
+    /// that was shared with the bot
+    pub data: Vec<EncryptedPassportElement>,
+    /// Encrypted credentials required to decrypt the data
+    pub credentials: EncryptedCredentials,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a file uploaded to Telegram Passport. Currently all
+/// Telegram Passport files are in JPEG format when decrypted and don't exceed 10MB.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportFile {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// File size in bytes
+    pub file_size: i64,
+    /// Unix time when the file was uploaded
+    pub file_date: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Element type. One of “personal_details”, “passport”, “driver_license”,
+/// “identity_card”, “internal_passport”, “address”, “utility_bill”,
+/// “bank_statement”, “rental_agreement”, “passport_registration”,
+/// “temporary_registration”, “phone_number”, “email”.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PassportElementType {
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
+    /// A value unknown to this version of the models, never deserialized
+    /// with `strict`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strict", serde(skip_deserializing))]
+    Unknown(String),
+}
+
+
+/// Describes documents or other Telegram Passport elements shared with the bot by
+/// the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EncryptedPassportElement {
+    /// Element type. One of “personal_details”, “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport”, “address”, “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration”,
+    /// “temporary_registration”, “phone_number”, “email”.
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Optional. Base64-encoded encrypted Telegram Passport element data provided
+    /// by the user, available for “personal_details”, “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport” and “address” types. Can be decrypted
+    /// and verified using the accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Optional. User's verified phone number, available only for “phone_number”
+    /// type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Optional. User's verified email address, available only for “email” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Optional. Array of encrypted files with documents provided by the user,
+    /// available for “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration” types. Files can be
+    /// decrypted and verified using the accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<PassportFile>>,
+    /// Optional. Encrypted file with the front side of the document, provided by
+    /// the user. Available for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport”. The file can be decrypted and verified using the
+    /// accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<PassportFile>,
+    /// Optional. Encrypted file with the reverse side of the document, provided by
+    /// the user. Available for “driver_license” and “identity_card”. The file can
+    /// be decrypted and verified using the accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<PassportFile>,
+    /// Optional. Encrypted file with the selfie of the user holding a document,
+    /// provided by the user; available for “passport”, “driver_license”,
+    /// “identity_card” and “internal_passport”. The file can be decrypted and
+    /// verified using the accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<PassportFile>,
+    /// Optional. Array of encrypted files with translated versions of documents
+    /// provided by the user. Available if requested for “passport”,
+    /// “driver_license”, “identity_card”, “internal_passport”, “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration” and
+    /// “temporary_registration” types. Files can be decrypted and verified using
+    /// the accompanying EncryptedCredentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Vec<PassportFile>>,
+    /// Base64-encoded element hash for using in PassportElementErrorUnspecified
+    pub hash: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Describes data required for decrypting and authenticating
+/// EncryptedPassportElement. See the Telegram Passport Documentation for a complete
+/// description of the data decryption and authentication processes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's payload,
+    /// data hashes and secrets required for EncryptedPassportElement decryption and
+    /// authentication
+    pub data: String,
+    /// Base64-encoded data hash for data authentication
+    pub hash: String,
+    /// Base64-encoded secret, encrypted with the bot's public RSA key, required for
+    /// data decryption
+    pub secret: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents an error in the Telegram Passport element which was
+/// submitted that should be resolved by the user. It should be one of:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum PassportElementError {
+    PassportElementErrorDataField(PassportElementErrorDataField),
+    PassportElementErrorFrontSide(PassportElementErrorFrontSide),
+    PassportElementErrorReverseSide(PassportElementErrorReverseSide),
+    PassportElementErrorSelfie(PassportElementErrorSelfie),
+    PassportElementErrorFile(PassportElementErrorFile),
+    PassportElementErrorFiles(PassportElementErrorFiles),
+    PassportElementErrorTranslationFile(PassportElementErrorTranslationFile),
+    PassportElementErrorTranslationFiles(PassportElementErrorTranslationFiles),
+    PassportElementErrorUnspecified(PassportElementErrorUnspecified),
+    /// A subtype unknown to this version of the models, with the
+    /// `preserve-unknown` feature.
+    Unknown(Raw<serde_json::Value>),
+}
+
+impl<'de> serde::Deserialize<'de> for PassportElementError {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[de::Variant] = &[
+            de::Variant { tag: Some("data"), required: &["source", "type", "field_name", "data_hash", "message"] },
+            de::Variant { tag: Some("front_side"), required: &["source", "type", "file_hash", "message"] },
+            de::Variant { tag: Some("reverse_side"), required: &["source", "type", "file_hash", "message"] },
+            de::Variant { tag: Some("selfie"), required: &["source", "type", "file_hash", "message"] },
+            de::Variant { tag: Some("file"), required: &["source", "type", "file_hash", "message"] },
+            de::Variant { tag: Some("files"), required: &["source", "type", "file_hashes", "message"] },
+            de::Variant { tag: Some("translation_file"), required: &["source", "type", "file_hash", "message"] },
+            de::Variant { tag: Some("translation_files"), required: &["source", "type", "file_hashes", "message"] },
+            de::Variant { tag: Some("unspecified"), required: &["source", "type", "element_hash", "message"] },
+        ];
+        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match de::select(&value, Some("source"), VARIANTS) {
+            Some(0) => PassportElementError::PassportElementErrorDataField(de::from_value(value)?),
+            Some(1) => PassportElementError::PassportElementErrorFrontSide(de::from_value(value)?),
+            Some(2) => PassportElementError::PassportElementErrorReverseSide(de::from_value(value)?),
+            Some(3) => PassportElementError::PassportElementErrorSelfie(de::from_value(value)?),
+            Some(4) => PassportElementError::PassportElementErrorFile(de::from_value(value)?),
+            Some(5) => PassportElementError::PassportElementErrorFiles(de::from_value(value)?),
+            Some(6) => PassportElementError::PassportElementErrorTranslationFile(de::from_value(value)?),
+            Some(7) => PassportElementError::PassportElementErrorTranslationFiles(de::from_value(value)?),
+            Some(8) => PassportElementError::PassportElementErrorUnspecified(de::from_value(value)?),
+            #[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+            _ => PassportElementError::Unknown(Raw(value)),
+            #[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+            _ => return Err(de::unknown("PassportElementError")),
+        })
+    }
+}
+
+
+/// Represents an issue in one of the data fields that was provided by the user. The
+/// error is considered resolved when the field's value changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorDataField {
+    /// Error source, must be data
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the error, one of
+    /// “personal_details”, “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport”, “address”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Name of the data field which has the error
+    pub field_name: String,
+    /// Base64-encoded data hash
+    pub data_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with the front side of a document. The error is considered
+/// resolved when the file with the front side of the document changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorFrontSide {
+    /// Error source, must be front_side
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded hash of the file with the front side of the document
+    pub file_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with the reverse side of a document. The error is considered
+/// resolved when the file with reverse side of the document changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorReverseSide {
+    /// Error source, must be reverse_side
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “driver_license”, “identity_card”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded hash of the file with the reverse side of the document
+    pub file_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with the selfie with a document. The error is considered
+/// resolved when the file with the selfie changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorSelfie {
+    /// Error source, must be selfie
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded hash of the file with the selfie
+    pub file_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with a document scan. The error is considered resolved when
+/// the file with the document scan changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorFile {
+    /// Error source, must be file
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded file hash
+    pub file_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with a list of scans. The error is considered resolved when
+/// the list of files containing the scans changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorFiles {
+    /// Error source, must be files
+    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with one of the files that constitute the translation of a
+/// document. The error is considered resolved when the file changes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorTranslationFile {
+    /// Error source, must be translation_file
+    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”,
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded file hash
+    pub file_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue with the translated version of a document. The error is
+/// considered resolved when a file with the document translation change.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorTranslationFiles {
+    /// Error source, must be translation_files
+    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”,
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// Represents an issue in an unspecified place. The error is considered resolved
+/// when new data is added.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportElementErrorUnspecified {
+    /// Error source, must be unspecified
+    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Base64-encoded element hash
+    pub element_hash: String,
+    /// Error message
+    pub message: String,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents a game. Use BotFather to create and edit games, their
+/// short names will act as unique identifiers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Game {
+    /// Title of the game
+    pub title: String,
+    /// Description of the game
+    pub description: String,
+    /// Photo that will be displayed in the game message in chats.
+    pub photo: Vec<PhotoSize>,
+    /// Optional. Brief description of the game or high scores included in the game
+    /// message. Can be automatically edited to include current high scores for the
+    /// game when the bot calls setGameScore, or manually edited using
+    /// editMessageText. 0-4096 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Optional. Special entities that appear in text, such as usernames, URLs, bot
+    /// commands, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Animation that will be displayed in the game message in chats.
+    /// Upload via BotFather
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// A placeholder, currently holds no information. Use BotFather to set up your
+/// game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CallbackGame {
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+/// This object represents one row of the high scores table for a game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameHighScore {
+    /// Position in high score table for the game
+    pub position: i64,
+    /// User
+    pub user: User,
+    /// Score
+    pub score: i64,
+    /// Fields unknown to this version of the models, with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(all(feature = "preserve-unknown", not(feature = "strict")), serde(flatten))]
+    #[cfg_attr(not(all(feature = "preserve-unknown", not(feature = "strict"))), serde(skip))]
+    pub extra: Extra,
+}
+
+
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PolymorphChatId {
+    Integer(i64),
+    String(String),
+}
+
+
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PolymorphReplyMarkup {
+    InlineKeyboardMarkup(InlineKeyboardMarkup),
+    ReplyKeyboardMarkup(ReplyKeyboardMarkup),
+    ReplyKeyboardRemove(ReplyKeyboardRemove),
+    ForceReply(ForceReply),
+}
+
+
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PolymorphFromChatId {
+    Integer(i64),
+    String(String),
+}
+
+
+
+/// Use this method to receive incoming updates using long polling (wiki). Returns
+/// an Array of Update objects.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetUpdates {
+    /// Identifier of the first update to be returned. Must be greater by one than
+    /// the highest among the identifiers of previously received updates. By
+    /// default, updates starting with the earliest unconfirmed update are returned.
+    /// An update is considered confirmed as soon as getUpdates is called with an
+    /// offset higher than its update_id. The negative offset can be specified to
+    /// retrieve updates starting from -offset update from the end of the updates
+    /// queue. All previous updates will be forgotten.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Limits the number of updates to be retrieved. Values between 1-100 are
+    /// accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Timeout in seconds for long polling. Defaults to 0, i.e. usual short
+    /// polling. Should be positive, short polling should be used for testing
+    /// purposes only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+    /// A JSON-serialized list of the update types you want your bot to receive. For
+    /// example, specify ["message", "edited_channel_post", "callback_query"] to
+    /// only receive updates of these types. See Update for a complete list of
+    /// available update types. Specify an empty list to receive all update types
+    /// except chat_member, message_reaction, and message_reaction_count (default).
+    /// If not specified, the previous setting will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+}
+
+/// Use this method to specify a URL and receive incoming updates via an outgoing
+/// webhook. Whenever there is an update for the bot, we will send an HTTPS POST
+/// request to the specified URL, containing a JSON-serialized Update. In case of an
+/// unsuccessful request, we will give up after a reasonable amount of attempts.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetWebhook {
+    /// HTTPS URL to send updates to. Use an empty string to remove webhook
+    /// integration
+    pub url: String,
+    /// Upload your public key certificate so that the root certificate in use can
+    /// be checked. See our self-signed guide for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    /// The fixed IP address which will be used to send webhook requests instead of
+    /// the IP address resolved through DNS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// The maximum allowed number of simultaneous HTTPS connections to the webhook
+    /// for update delivery, 1-100. Defaults to 40. Use lower values to limit the
+    /// load on your bot's server, and higher values to increase your bot's
+    /// throughput.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    /// A JSON-serialized list of the update types you want your bot to receive. For
+    /// example, specify ["message", "edited_channel_post", "callback_query"] to
+    /// only receive updates of these types. See Update for a complete list of
+    /// available update types. Specify an empty list to receive all update types
+    /// except chat_member, message_reaction, and message_reaction_count (default).
+    /// If not specified, the previous setting will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+    /// Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<bool>,
+    /// A secret token to be sent in a header “X-Telegram-Bot-Api-Secret-Token” in
+    /// every webhook request, 1-256 characters. Only characters A-Z, a-z, 0-9, “_”
+    /// and “-” are allowed. The header is useful to ensure that the request comes
+    /// from a webhook set by you.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_token: Option<String>,
+}
+
+/// Use this method to remove webhook integration if you decide to switch back to
+/// getUpdates. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteWebhook {
+    /// Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<bool>,
+}
+
+/// Use this method to get current webhook status. Requires no parameters. On
+/// success, returns a WebhookInfo object. If the bot is using getUpdates, will
+/// return an object with the url field empty.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetWebhookInfo {
+}
+
+/// A simple method for testing your bot's authentication token. Requires no
+/// parameters. Returns basic information about the bot in form of a User object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMe {
+}
+
+/// Use this method to log out from the cloud Bot API server before launching the
+/// bot locally. You must log out the bot before running it locally, otherwise there
+/// is no guarantee that the bot will receive updates. After a successful call, you
+/// can immediately log in on a local server, but will not be able to log in back to
+/// the cloud Bot API server for 10 minutes. Returns True on success. Requires no
+/// parameters.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LogOut {
+}
+
+/// Use this method to close the bot instance before moving it from one local server
+/// to another. You need to delete the webhook before calling this method to ensure
+/// that the bot isn't launched again after server restart. The method will return
+/// error 429 in the first 10 minutes after the bot is launched. Returns True on
+/// success. Requires no parameters.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Close {
+}
+
+/// Use this method to send text messages. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendMessage {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Text of the message to be sent, 1-4096 characters after entities parsing
+    pub text: String,
+    /// Mode for parsing entities in the message text. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in message text,
+    /// which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to forward messages of any kind. Service messages and messages
+/// with protected content can't be forwarded. On success, the sent Message is
+/// returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForwardMessage {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Unique identifier for the chat where the original message was sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: PolymorphFromChatId,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the forwarded message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Message identifier in the chat specified in from_chat_id
+    pub message_id: i64,
+}
+
+/// Use this method to forward multiple messages of any kind. If some of the
+/// specified messages can't be found or forwarded, they are skipped. Service
+/// messages and messages with protected content can't be forwarded. Album grouping
+/// is kept for forwarded messages. On success, an array of MessageId of the sent
+/// messages is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ForwardMessages {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Unique identifier for the chat where the original messages were sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: PolymorphFromChatId,
+    /// A JSON-serialized list of 1-100 identifiers of messages in the chat
+    /// from_chat_id to forward. The identifiers must be specified in a strictly
+    /// increasing order.
+    pub message_ids: Vec<i64>,
+    /// Sends the messages silently. Users will receive a notification with no
+    /// sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the forwarded messages from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+}
+
+/// Use this method to copy messages of any kind. Service messages, paid media
+/// messages, giveaway messages, giveaway winners messages, and invoice messages
+/// can't be copied. A quiz poll can be copied only if the value of the field
+/// correct_option_id is known to the bot. The method is analogous to the method
+/// forwardMessage, but the copied message doesn't have a link to the original
+/// message. Returns the MessageId of the sent message on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CopyMessage {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Unique identifier for the chat where the original message was sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: PolymorphFromChatId,
+    /// Message identifier in the chat specified in from_chat_id
+    pub message_id: i64,
+    /// New caption for media, 0-1024 characters after entities parsing. If not
+    /// specified, the original caption is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the new caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the new caption,
+    /// which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media. Ignored if
+    /// a new caption isn't specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to copy messages of any kind. If some of the specified messages
+/// can't be found or copied, they are skipped. Service messages, paid media
+/// messages, giveaway messages, giveaway winners messages, and invoice messages
+/// can't be copied. A quiz poll can be copied only if the value of the field
+/// correct_option_id is known to the bot. The method is analogous to the method
+/// forwardMessages, but the copied messages don't have a link to the original
+/// message. Album grouping is kept for copied messages. On success, an array of
+/// MessageId of the sent messages is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CopyMessages {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Unique identifier for the chat where the original messages were sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: PolymorphFromChatId,
+    /// A JSON-serialized list of 1-100 identifiers of messages in the chat
+    /// from_chat_id to copy. The identifiers must be specified in a strictly
+    /// increasing order.
+    pub message_ids: Vec<i64>,
+    /// Sends the messages silently. Users will receive a notification with no
+    /// sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent messages from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Pass True to copy the messages without their captions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_caption: Option<bool>,
+}
+
+/// Use this method to send photos. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendPhoto {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Photo to send. Pass a file_id as String to send a photo that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL as a String for Telegram to
+    /// get a photo from the Internet, or upload a new photo using multipart/form-
+    /// data. The photo must be at most 10 MB in size. The photo's width and height
+    /// must not exceed 10000 in total. Width and height ratio must be at most 20.
+    /// More information on Sending Files »
+    pub photo: String,
+    /// Photo caption (may also be used when resending photos by file_id), 0-1024
+    /// characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the photo caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the photo needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send audio files, if you want Telegram clients to display
+/// them in the music player. Your audio must be in the .MP3 or .M4A format. On
+/// success, the sent Message is returned. Bots can currently send audio files of up
+/// to 50 MB in size, this limit may be changed in the future.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendAudio {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Audio file to send. Pass a file_id as String to send a file that exists on
+    /// the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More information on Sending Files »
+    pub audio: String,
+    /// Audio caption (may also be used when resending audios by file_id), 0-1024
+    /// characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the audio caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Duration of the audio in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Performer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Track name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the
+    /// file is supported server-side. The thumbnail should be in JPEG format and
+    /// less than 200 kB in size. A thumbnail's width and height should not exceed
+    /// 320. Ignored if the file is not uploaded using multipart/form-data.
+    /// Thumbnails can't be reused and can be only uploaded as a new file, so you
+    /// can pass “attach://<file_attach_name>” if the thumbnail was uploaded using
+    /// multipart/form-data under <file_attach_name>. More information on Sending
+    /// Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send general files. On success, the sent Message is returned.
+/// Bots can currently send files of any type of up to 50 MB in size, this limit may
+/// be changed in the future.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendDocument {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// File to send. Pass a file_id as String to send a file that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL as a String for Telegram to
+    /// get a file from the Internet, or upload a new one using multipart/form-data.
+    /// More information on Sending Files »
+    pub document: String,
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the
+    /// file is supported server-side. The thumbnail should be in JPEG format and
+    /// less than 200 kB in size. A thumbnail's width and height should not exceed
+    /// 320. Ignored if the file is not uploaded using multipart/form-data.
+    /// Thumbnails can't be reused and can be only uploaded as a new file, so you
+    /// can pass “attach://<file_attach_name>” if the thumbnail was uploaded using
+    /// multipart/form-data under <file_attach_name>. More information on Sending
+    /// Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Document caption (may also be used when resending documents by file_id),
+    /// 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the document caption. See formatting options
+    /// for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Disables automatic server-side content type detection for files uploaded
+    /// using multipart/form-data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_content_type_detection: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send video files, Telegram clients support MPEG4 videos
+/// (other formats may be sent as Document). On success, the sent Message is
+/// returned. Bots can currently send video files of up to 50 MB in size, this limit
+/// may be changed in the future.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendVideo {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Video to send. Pass a file_id as String to send a video that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL as a String for Telegram to
+    /// get a video from the Internet, or upload a new video using multipart/form-
+    /// data. More information on Sending Files »
+    pub video: String,
+    /// Duration of sent video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the
+    /// file is supported server-side. The thumbnail should be in JPEG format and
+    /// less than 200 kB in size. A thumbnail's width and height should not exceed
+    /// 320. Ignored if the file is not uploaded using multipart/form-data.
+    /// Thumbnails can't be reused and can be only uploaded as a new file, so you
+    /// can pass “attach://<file_attach_name>” if the thumbnail was uploaded using
+    /// multipart/form-data under <file_attach_name>. More information on Sending
+    /// Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Video caption (may also be used when resending videos by file_id), 0-1024
+    /// characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the video caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+    /// Pass True if the video needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without
+/// sound). On success, the sent Message is returned. Bots can currently send
+/// animation files of up to 50 MB in size, this limit may be changed in the future.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendAnimation {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Animation to send. Pass a file_id as String to send a animation that exists
+    /// on the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a animation from the Internet, or upload a new animation
+    /// using multipart/form-data. More information on Sending Files »
+    pub animation: String,
+    /// Duration of sent animation in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Animation width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Animation height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the
+    /// file is supported server-side. The thumbnail should be in JPEG format and
+    /// less than 200 kB in size. A thumbnail's width and height should not exceed
+    /// 320. Ignored if the file is not uploaded using multipart/form-data.
+    /// Thumbnails can't be reused and can be only uploaded as a new file, so you
+    /// can pass “attach://<file_attach_name>” if the thumbnail was uploaded using
+    /// multipart/form-data under <file_attach_name>. More information on Sending
+    /// Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Animation caption (may also be used when resending animations by file_id),
+    /// 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the animation caption. See formatting options
+    /// for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the animation needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send audio files, if you want Telegram clients to display the
+/// file as a playable voice message. For this to work, your audio must be in an
+/// .OGG file encoded with OPUS, or in .MP3 format, or in .M4A format (other formats
+/// may be sent as Audio or Document). On success, the sent Message is returned.
+/// Bots can currently send voice messages of up to 50 MB in size, this limit may be
+/// changed in the future.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendVoice {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Audio file to send. Pass a file_id as String to send a file that exists on
+    /// the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More information on Sending Files »
+    pub voice: String,
+    /// Voice message caption (may also be used when resending voice messages by
+    /// file_id), 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the voice message caption. See formatting
+    /// options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Duration of the voice message in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// As of v.4.0, Telegram clients support rounded square MPEG4 videos of up to 1
+/// minute long. Use this method to send video messages. On success, the sent
+/// Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendVideoNote {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Video note to send. Pass a file_id as String to send a video note that
+    /// exists on the Telegram servers (recommended) or upload a new video using
+    /// multipart/form-data. More information on Sending Files ». Sending video
+    /// notes by a URL is currently unsupported
+    pub video_note: String,
+    /// Duration of sent video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Video width and height, i.e. diameter of the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i64>,
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the
+    /// file is supported server-side. The thumbnail should be in JPEG format and
+    /// less than 200 kB in size. A thumbnail's width and height should not exceed
+    /// 320. Ignored if the file is not uploaded using multipart/form-data.
+    /// Thumbnails can't be reused and can be only uploaded as a new file, so you
+    /// can pass “attach://<file_attach_name>” if the thumbnail was uploaded using
+    /// multipart/form-data under <file_attach_name>. More information on Sending
+    /// Files »
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send paid media. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendPaidMedia {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername). If the chat is a channel, all Telegram Star
+    /// proceeds from this media will be credited to the chat's balance. Otherwise,
+    /// they will be credited to the bot's balance.
+    pub chat_id: PolymorphChatId,
+    /// The number of Telegram Stars that must be paid to buy access to the media;
+    /// 1-2500
+    pub star_count: i64,
+    /// A JSON-serialized array describing the media to be sent; up to 10 items
+    pub media: Vec<InputPaidMedia>,
+    /// Bot-defined paid media payload, 0-128 bytes. This will not be displayed to
+    /// the user, use it for your internal processes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    /// Media caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the media caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send a group of photos, videos, documents or audios as an
+/// album. Documents and audio files can be only grouped in an album with messages
+/// of the same type. On success, an array of Messages that were sent is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendMediaGroup {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// A JSON-serialized array describing messages to be sent, must include 2-10
+    /// items
+    pub media: Vec<InputMedia>,
+    /// Sends messages silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent messages from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+}
+
+/// Use this method to send point on the map. On success, the sent Message is
+/// returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendLocation {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Latitude of the location
+    pub latitude: f64,
+    /// Longitude of the location
+    pub longitude: f64,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Period in seconds during which the location will be updated (see Live
+    /// Locations, should be between 60 and 86400, or 0x7FFFFFFF for live locations
+    /// that can be edited indefinitely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// For live locations, a direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000 if
+    /// specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send information about a venue. On success, the sent Message
+/// is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendVenue {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Latitude of the venue
+    pub latitude: f64,
+    /// Longitude of the venue
+    pub longitude: f64,
+    /// Name of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or
+    /// “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    /// Google Places type of the venue. (See supported types.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send phone contacts. On success, the sent Message is
+/// returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendContact {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Contact's phone number
+    pub phone_number: String,
+    /// Contact's first name
+    pub first_name: String,
+    /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send a native poll. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendPoll {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Poll question, 1-300 characters
+    pub question: String,
+    /// Mode for parsing entities in the question. See formatting options for more
+    /// details. Currently, only custom emoji entities are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the poll question.
+    /// It can be specified instead of question_parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_entities: Option<Vec<MessageEntity>>,
+    /// A JSON-serialized list of 2-10 answer options
+    pub options: Vec<InputPollOption>,
+    /// True, if the poll needs to be anonymous, defaults to True
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    /// Poll type, “quiz” or “regular”, defaults to “regular”
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<PollType>,
+    /// True, if the poll allows multiple answers, ignored for polls in quiz mode,
+    /// defaults to False
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_multiple_answers: Option<bool>,
+    /// 0-based identifier of the correct answer option, required for polls in quiz
+    /// mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i64>,
+    /// Text that is shown when a user chooses an incorrect answer or taps on the
+    /// lamp icon in a quiz-style poll, 0-200 characters with at most 2 line feeds
+    /// after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    /// Mode for parsing entities in the explanation. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the poll
+    /// explanation. It can be specified instead of explanation_parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<MessageEntity>>,
+    /// Amount of time in seconds the poll will be active after creation, 5-600.
+    /// Can't be used together with close_date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i64>,
+    /// Point in time (Unix timestamp) when the poll will be automatically closed.
+    /// Must be at least 5 and no more than 600 seconds in the future. Can't be used
+    /// together with open_period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+    /// Pass True if the poll needs to be immediately closed. This can be useful for
+    /// poll preview.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_closed: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to send an animated emoji that will display a random value. On
+/// success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendDice {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Emoji on which the dice throw animation is based. Currently, must be one of
+    /// “🎲”, “🎯”, “🏀”, “⚽”, “🎳”, or “🎰”. Dice can have values 1-6 for “🎲”, “🎯” and
+    /// “🎳”, values 1-5 for “🏀” and “⚽”, and values 1-64 for “🎰”. Defaults to “🎲”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method when you need to tell the user that something is happening on
+/// the bot's side. The status is set for 5 seconds or less (when a message arrives
+/// from your bot, Telegram clients clear its typing status). Returns True on
+/// success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendChatAction {
+    /// Unique identifier of the business connection on behalf of which the action
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Type of action to broadcast. Choose one, depending on what the user is about
+    /// to receive: typing for text messages, upload_photo for photos, record_video
+    /// or upload_video for videos, record_voice or upload_voice for voice notes,
+    /// upload_document for general files, choose_sticker for stickers,
+    /// find_location for location data, record_video_note or upload_video_note for
+    /// video notes.
+    pub action: String,
+}
+
+/// Use this method to change the chosen reactions on a message. Service messages
+/// can't be reacted to. Automatically forwarded messages from a channel to its
+/// discussion group have the same available reactions as messages in the channel.
+/// Bots can't use paid reactions. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMessageReaction {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Identifier of the target message. If the message belongs to a media group,
+    /// the reaction is set to the first non-deleted message in the group instead.
+    pub message_id: i64,
+    /// A JSON-serialized list of reaction types to set on the message. Currently,
+    /// as non-premium users, bots can set up to one reaction per message. A custom
+    /// emoji reaction can be used if it is either already present on the message or
+    /// explicitly allowed by chat administrators. Paid reactions can't be used by
+    /// bots.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reaction: Option<Vec<ReactionType>>,
+    /// Pass True to set the reaction with a big animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_big: Option<bool>,
+}
+
+/// Use this method to get a list of profile pictures for a user. Returns a
+/// UserProfilePhotos object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetUserProfilePhotos {
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Sequential number of the first photo to be returned. By default, all photos
+    /// are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Limits the number of photos to be retrieved. Values between 1-100 are
+    /// accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Use this method to get basic information about a file and prepare it for
+/// downloading. For the moment, bots can download files of up to 20MB in size. On
+/// success, a File object is returned. The file can then be downloaded via the link
+/// https://api.telegram.org/file/bot<token>/<file_path>, where <file_path> is taken
+/// from the response. It is guaranteed that the link will be valid for at least 1
+/// hour. When the link expires, a new one can be requested by calling getFile
+/// again.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetFile {
+    /// File identifier to get information about
+    pub file_id: String,
+}
+
+/// Use this method to ban a user in a group, a supergroup or a channel. In the case
+/// of supergroups and channels, the user will not be able to return to the chat on
+/// their own using invite links, etc., unless unbanned first. The bot must be an
+/// administrator in the chat for this to work and must have the appropriate
+/// administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BanChatMember {
+    /// Unique identifier for the target group or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Date when the user will be unbanned; Unix time. If user is banned for more
+    /// than 366 days or less than 30 seconds from the current time they are
+    /// considered to be banned forever. Applied for supergroups and channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+    /// Pass True to delete all messages from the chat for the user that is being
+    /// removed. If False, the user will be able to see messages in the group that
+    /// were sent before the user was removed. Always True for supergroups and
+    /// channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_messages: Option<bool>,
+}
+
+/// Use this method to unban a previously banned user in a supergroup or channel.
+/// The user will not return to the group or channel automatically, but will be able
+/// to join via link, etc. The bot must be an administrator for this to work. By
+/// default, this method guarantees that after the call the user is not a member of
+/// the chat, but will be able to join it. So if the user is a member of the chat
+/// they will also be removed from the chat. If you don't want this, use the
+/// parameter only_if_banned. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnbanChatMember {
+    /// Unique identifier for the target group or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Do nothing if the user is not banned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_if_banned: Option<bool>,
+}
+
+/// Use this method to restrict a user in a supergroup. The bot must be an
+/// administrator in the supergroup for this to work and must have the appropriate
+/// administrator rights. Pass True for all permissions to lift restrictions from a
+/// user. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RestrictChatMember {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// A JSON-serialized object for new user permissions
+    pub permissions: ChatPermissions,
+    /// Pass True if chat permissions are set independently. Otherwise, the
+    /// can_send_other_messages and can_add_web_page_previews permissions will imply
+    /// the can_send_messages, can_send_audios, can_send_documents, can_send_photos,
+    /// can_send_videos, can_send_video_notes, and can_send_voice_notes permissions;
+    /// the can_send_polls permission will imply the can_send_messages permission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+    /// Date when restrictions will be lifted for the user; Unix time. If user is
+    /// restricted for more than 366 days or less than 30 seconds from the current
+    /// time, they are considered to be restricted forever
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+}
+
+/// Use this method to promote or demote a user in a supergroup or a channel. The
+/// bot must be an administrator in the chat for this to work and must have the
+/// appropriate administrator rights. Pass False for all boolean parameters to
+/// demote a user. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PromoteChatMember {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Pass True if the administrator's presence in the chat is hidden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    /// Pass True if the administrator can access the chat event log, get boost
+    /// list, see hidden supergroup and channel members, report spam messages and
+    /// ignore slow mode. Implied by any other administrator privilege.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_chat: Option<bool>,
+    /// Pass True if the administrator can delete messages of other users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_messages: Option<bool>,
+    /// Pass True if the administrator can manage video chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_video_chats: Option<bool>,
+    /// Pass True if the administrator can restrict, ban or unban chat members, or
+    /// access supergroup statistics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restrict_members: Option<bool>,
+    /// Pass True if the administrator can add new administrators with a subset of
+    /// their own privileges or demote administrators that they have promoted,
+    /// directly or indirectly (promoted by administrators that were appointed by
+    /// him)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_promote_members: Option<bool>,
+    /// Pass True if the administrator can change chat title, photo and other
+    /// settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    /// Pass True if the administrator can invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    /// Pass True if the administrator can post stories to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_stories: Option<bool>,
+    /// Pass True if the administrator can edit stories posted by other users, post
+    /// stories to the chat page, pin chat stories, and access the chat's story
+    /// archive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_stories: Option<bool>,
+    /// Pass True if the administrator can delete stories posted by other users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_stories: Option<bool>,
+    /// Pass True if the administrator can post messages in the channel, or access
+    /// channel statistics; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Pass True if the administrator can edit messages of other users and can pin
+    /// messages; for channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Pass True if the administrator can pin messages; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Pass True if the user is allowed to create, rename, close, and reopen forum
+    /// topics; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+/// Use this method to set a custom title for an administrator in a supergroup
+/// promoted by the bot. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatAdministratorCustomTitle {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// New custom title for the administrator; 0-16 characters, emoji are not
+    /// allowed
+    pub custom_title: String,
+}
+
+/// Use this method to ban a channel chat in a supergroup or a channel. Until the
+/// chat is unbanned, the owner of the banned chat won't be able to send messages on
+/// behalf of any of their channels. The bot must be an administrator in the
+/// supergroup or channel for this to work and must have the appropriate
+/// administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BanChatSenderChat {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target sender chat
+    pub sender_chat_id: i64,
+}
+
+/// Use this method to unban a previously banned channel chat in a supergroup or
+/// channel. The bot must be an administrator for this to work and must have the
+/// appropriate administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnbanChatSenderChat {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target sender chat
+    pub sender_chat_id: i64,
+}
+
+/// Use this method to set default chat permissions for all members. The bot must be
+/// an administrator in the group or a supergroup for this to work and must have the
+/// can_restrict_members administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatPermissions {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// A JSON-serialized object for new default chat permissions
+    pub permissions: ChatPermissions,
+    /// Pass True if chat permissions are set independently. Otherwise, the
+    /// can_send_other_messages and can_add_web_page_previews permissions will imply
+    /// the can_send_messages, can_send_audios, can_send_documents, can_send_photos,
+    /// can_send_videos, can_send_video_notes, and can_send_voice_notes permissions;
+    /// the can_send_polls permission will imply the can_send_messages permission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+}
+
+/// Use this method to generate a new primary invite link for a chat; any previously
+/// generated primary link is revoked. The bot must be an administrator in the chat
+/// for this to work and must have the appropriate administrator rights. Returns the
+/// new invite link as String on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ExportChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to create an additional invite link for a chat. The bot must be
+/// an administrator in the chat for this to work and must have the appropriate
+/// administrator rights. The link can be revoked using the method
+/// revokeChatInviteLink. Returns the new invite link as ChatInviteLink object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    /// True, if users joining the chat via the link need to be approved by chat
+    /// administrators. If True, member_limit can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+/// Use this method to edit a non-primary invite link created by the bot. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// appropriate administrator rights. Returns the edited invite link as a
+/// ChatInviteLink object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EditChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// The invite link to edit
+    pub invite_link: String,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    /// True, if users joining the chat via the link need to be approved by chat
+    /// administrators. If True, member_limit can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+/// Use this method to create a subscription invite link for a channel chat. The bot
+/// must have the can_invite_users administrator rights. The link can be edited
+/// using the method editChatSubscriptionInviteLink or revoked using the method
+/// revokeChatInviteLink. Returns the new invite link as a ChatInviteLink object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateChatSubscriptionInviteLink {
+    /// Unique identifier for the target channel chat or username of the target
+    /// channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of seconds the subscription will be active for before the next
+    /// payment. Currently, it must always be 2592000 (30 days).
+    pub subscription_period: i64,
+    /// The amount of Telegram Stars a user must pay initially and after each
+    /// subsequent subscription period to be a member of the chat; 1-2500
+    pub subscription_price: i64,
+}
+
+/// Use this method to edit a subscription invite link created by the bot. The bot
+/// must have the can_invite_users administrator rights. Returns the edited invite
+/// link as a ChatInviteLink object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EditChatSubscriptionInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// The invite link to edit
+    pub invite_link: String,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Use this method to revoke an invite link created by the bot. If the primary link
+/// is revoked, a new link is automatically generated. The bot must be an
+/// administrator in the chat for this to work and must have the appropriate
+/// administrator rights. Returns the revoked invite link as ChatInviteLink object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevokeChatInviteLink {
+    /// Unique identifier of the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// The invite link to revoke
+    pub invite_link: String,
+}
+
+/// Use this method to approve a chat join request. The bot must be an administrator
+/// in the chat for this to work and must have the can_invite_users administrator
+/// right. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ApproveChatJoinRequest {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// Use this method to decline a chat join request. The bot must be an administrator
+/// in the chat for this to work and must have the can_invite_users administrator
+/// right. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeclineChatJoinRequest {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// Use this method to set a new profile photo for the chat. Photos can't be changed
+/// for private chats. The bot must be an administrator in the chat for this to work
+/// and must have the appropriate administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// New chat photo, uploaded using multipart/form-data
+    pub photo: String,
+}
+
+/// Use this method to delete a chat photo. Photos can't be changed for private
+/// chats. The bot must be an administrator in the chat for this to work and must
+/// have the appropriate administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to change the title of a chat. Titles can't be changed for
+/// private chats. The bot must be an administrator in the chat for this to work and
+/// must have the appropriate administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatTitle {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// New chat title, 1-128 characters
+    pub title: String,
+}
+
+/// Use this method to change the description of a group, a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have the
+/// appropriate administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatDescription {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// New chat description, 0-255 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Use this method to add a message to the list of pinned messages in a chat. If
+/// the chat is not a private chat, the bot must be an administrator in the chat for
+/// this to work and must have the 'can_pin_messages' administrator right in a
+/// supergroup or 'can_edit_messages' administrator right in a channel. Returns True
+/// on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PinChatMessage {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be pinned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Identifier of a message to pin
+    pub message_id: i64,
+    /// Pass True if it is not necessary to send a notification to all chat members
+    /// about the new pinned message. Notifications are always disabled in channels
+    /// and private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+}
+
+/// Use this method to remove a message from the list of pinned messages in a chat.
+/// If the chat is not a private chat, the bot must be an administrator in the chat
+/// for this to work and must have the 'can_pin_messages' administrator right in a
+/// supergroup or 'can_edit_messages' administrator right in a channel. Returns True
+/// on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnpinChatMessage {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be unpinned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Identifier of the message to unpin. Required if business_connection_id is
+    /// specified. If not specified, the most recent pinned message (by sending
+    /// date) will be unpinned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+}
+
+/// Use this method to clear the list of pinned messages in a chat. If the chat is
+/// not a private chat, the bot must be an administrator in the chat for this to
+/// work and must have the 'can_pin_messages' administrator right in a supergroup or
+/// 'can_edit_messages' administrator right in a channel. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnpinAllChatMessages {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method for your bot to leave a group, supergroup or channel. Returns
+/// True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LeaveChat {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to get up-to-date information about the chat. Returns a
+/// ChatFullInfo object on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetChat {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to get a list of administrators in a chat, which aren't bots.
+/// Returns an Array of ChatMember objects.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetChatAdministrators {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to get the number of members in a chat. Returns Int on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetChatMemberCount {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to get information about a member of a chat. The method is only
+/// guaranteed to work for other users if the bot is an administrator in the chat.
+/// Returns a ChatMember object on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetChatMember {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// or channel (in the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// Use this method to set a new group sticker set for a supergroup. The bot must be
+/// an administrator in the chat for this to work and must have the appropriate
+/// administrator rights. Use the field can_set_sticker_set optionally returned in
+/// getChat requests to check if the bot can use this method. Returns True on
+/// success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatStickerSet {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Name of the sticker set to be set as the group sticker set
+    pub sticker_set_name: String,
+}
+
+/// Use this method to delete a group sticker set from a supergroup. The bot must be
+/// an administrator in the chat for this to work and must have the appropriate
+/// administrator rights. Use the field can_set_sticker_set optionally returned in
+/// getChat requests to check if the bot can use this method. Returns True on
+/// success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteChatStickerSet {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to get custom emoji stickers, which can be used as a forum topic
+/// icon by any user. Requires no parameters. Returns an Array of Sticker objects.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetForumTopicIconStickers {
+}
+
+/// Use this method to create a topic in a forum supergroup chat. The bot must be an
+/// administrator in the chat for this to work and must have the can_manage_topics
+/// administrator rights. Returns information about the created topic as a
+/// ForumTopic object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Topic name, 1-128 characters
+    pub name: String,
+    /// Color of the topic icon in RGB format. Currently, must be one of 7322096
+    /// (0x6FB9F0), 16766590 (0xFFD67E), 13338331 (0xCB86DB), 9367192 (0x8EEE98),
+    /// 16749490 (0xFF93B2), or 16478047 (0xFB6F5F)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<i64>,
+    /// Unique identifier of the custom emoji shown as the topic icon. Use
+    /// getForumTopicIconStickers to get all allowed custom emoji identifiers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Use this method to edit name and icon of a topic in a forum supergroup chat. The
+/// bot must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights, unless it is the creator of the topic.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EditForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+    /// New topic name, 0-128 characters. If not specified or empty, the current
+    /// name of the topic will be kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New unique identifier of the custom emoji shown as the topic icon. Use
+    /// getForumTopicIconStickers to get all allowed custom emoji identifiers. Pass
+    /// an empty string to remove the icon. If not specified, the current icon will
+    /// be kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Use this method to close an open topic in a forum supergroup chat. The bot must
+/// be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights, unless it is the creator of the topic.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CloseForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// Use this method to reopen a closed topic in a forum supergroup chat. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights, unless it is the creator of the topic.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReopenForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// Use this method to delete a forum topic along with all its messages in a forum
+/// supergroup chat. The bot must be an administrator in the chat for this to work
+/// and must have the can_delete_messages administrator rights. Returns True on
+/// success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// Use this method to clear the list of pinned messages in a forum topic. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// can_pin_messages administrator right in the supergroup. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnpinAllForumTopicMessages {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// Use this method to edit the name of the 'General' topic in a forum supergroup
+/// chat. The bot must be an administrator in the chat for this to work and must
+/// have the can_manage_topics administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EditGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+    /// New topic name, 1-128 characters
+    pub name: String,
+}
+
+/// Use this method to close an open 'General' topic in a forum supergroup chat. The
+/// bot must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CloseGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to reopen a closed 'General' topic in a forum supergroup chat.
+/// The bot must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights. The topic will be automatically unhidden
+/// if it was hidden. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReopenGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to hide the 'General' topic in a forum supergroup chat. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights. The topic will be automatically closed
+/// if it was open. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct HideGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to unhide the 'General' topic in a forum supergroup chat. The
+/// bot must be an administrator in the chat for this to work and must have the
+/// can_manage_topics administrator rights. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnhideGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to clear the list of pinned messages in a General forum topic.
+/// The bot must be an administrator in the chat for this to work and must have the
+/// can_pin_messages administrator right in the supergroup. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UnpinAllGeneralForumTopicMessages {
+    /// Unique identifier for the target chat or username of the target supergroup
+    /// (in the format @supergroupusername)
+    pub chat_id: PolymorphChatId,
+}
+
+/// Use this method to send answers to callback queries sent from inline keyboards.
+/// The answer will be displayed to the user as a notification at the top of the
+/// chat screen or as an alert. On success, True is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnswerCallbackQuery {
+    /// Unique identifier for the query to be answered
+    pub callback_query_id: String,
+    /// Text of the notification. If not specified, nothing will be shown to the
+    /// user, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// If True, an alert will be shown by the client instead of a notification at
+    /// the top of the chat screen. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_alert: Option<bool>,
+    /// URL that will be opened by the user's client. If you have created a Game and
+    /// accepted the conditions via @BotFather, specify the URL that opens your game
+    /// – note that this will only work if the query comes from a callback_game
+    /// button. Otherwise, you may use links like t.me/your_bot?start=XXXX that open
+    /// your bot with a parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The maximum amount of time in seconds that the result of the callback query
+    /// may be cached client-side. Telegram apps will support caching starting in
+    /// version 3.14. Defaults to 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i64>,
+}
+
+/// Use this method to get the list of boosts added to a chat by a user. Requires
+/// administrator rights in the chat. Returns a UserChatBoosts object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetUserChatBoosts {
+    /// Unique identifier for the chat or username of the channel (in the format
+    /// @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// Use this method to get information about the connection of the bot with a
+/// business account. Returns a BusinessConnection object on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetBusinessConnection {
+    /// Unique identifier of the business connection
+    pub business_connection_id: String,
+}
+
+/// Use this method to change the list of the bot's commands. See this manual for
+/// more details about bot commands. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMyCommands {
+    /// A JSON-serialized list of bot commands to be set as the list of the bot's
+    /// commands. At most 100 commands can be specified.
+    pub commands: Vec<BotCommand>,
+    /// A JSON-serialized object, describing scope of users for which the commands
+    /// are relevant. Defaults to BotCommandScopeDefault.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to
+    /// all users from the given scope, for whose language there are no dedicated
+    /// commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to delete the list of the bot's commands for the given scope and
+/// user language. After deletion, higher level commands will be shown to affected
+/// users. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteMyCommands {
+    /// A JSON-serialized object, describing scope of users for which the commands
+    /// are relevant. Defaults to BotCommandScopeDefault.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to
+    /// all users from the given scope, for whose language there are no dedicated
+    /// commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to get the current list of the bot's commands for the given
+/// scope and user language. Returns an Array of BotCommand objects. If commands
+/// aren't set, an empty list is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMyCommands {
+    /// A JSON-serialized object, describing scope of users. Defaults to
+    /// BotCommandScopeDefault.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to change the bot's name. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMyName {
+    /// New bot name; 0-64 characters. Pass an empty string to remove the dedicated
+    /// name for the given language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the name will be shown to
+    /// all users for whose language there is no dedicated name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to get the current bot name for the given user language. Returns
+/// BotName on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMyName {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to change the bot's description, which is shown in the chat with
+/// the bot if the chat is empty. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMyDescription {
+    /// New bot description; 0-512 characters. Pass an empty string to remove the
+    /// dedicated description for the given language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the description will be
+    /// applied to all users for whose language there is no dedicated description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to get the current bot description for the given user language.
+/// Returns BotDescription on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMyDescription {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to change the bot's short description, which is shown on the
+/// bot's profile page and is sent together with the link when users share the bot.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMyShortDescription {
+    /// New short description for the bot; 0-120 characters. Pass an empty string to
+    /// remove the dedicated short description for the given language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the short description will
+    /// be applied to all users for whose language there is no dedicated short
+    /// description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to get the current bot short description for the given user
+/// language. Returns BotShortDescription on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMyShortDescription {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Use this method to change the bot's menu button in a private chat, or the
+/// default menu button. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetChatMenuButton {
+    /// Unique identifier for the target private chat. If not specified, default
+    /// bot's menu button will be changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    /// A JSON-serialized object for the bot's new menu button. Defaults to
+    /// MenuButtonDefault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton>,
+}
+
+/// Use this method to get the current value of the bot's menu button in a private
+/// chat, or the default menu button. Returns MenuButton on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetChatMenuButton {
+    /// Unique identifier for the target private chat. If not specified, default
+    /// bot's menu button will be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
+
+/// Use this method to change the default administrator rights requested by the bot
+/// when it's added as an administrator to groups or channels. These rights will be
+/// suggested to users, but they are free to modify the list before adding the bot.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetMyDefaultAdministratorRights {
+    /// A JSON-serialized object describing new default administrator rights. If not
+    /// specified, the default administrator rights will be cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rights: Option<ChatAdministratorRights>,
+    /// Pass True to change the default administrator rights of the bot in channels.
+    /// Otherwise, the default administrator rights of the bot for groups and
+    /// supergroups will be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_channels: Option<bool>,
+}
+
+/// Use this method to get the current default administrator rights of the bot.
+/// Returns ChatAdministratorRights on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetMyDefaultAdministratorRights {
+    /// Pass True to get default administrator rights of the bot in channels.
+    /// Otherwise, default administrator rights of the bot for groups and
+    /// supergroups will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_channels: Option<bool>,
+}
+
+/// Use this method to edit text and game messages. On success, if the edited
+/// message is not an inline message, the edited Message is returned, otherwise True
+/// is returned. Note that business messages that were not sent by the bot and do
+/// not contain an inline keyboard can only be edited within 48 hours from the time
+/// they were sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EditMessageText {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// New text of the message, 1-4096 characters after entities parsing
+    pub text: String,
+    /// Mode for parsing entities in the message text. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in message text,
+    /// which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// A JSON-serialized object for an inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to edit captions of messages. On success, if the edited message
+/// is not an inline message, the edited Message is returned, otherwise True is
+/// returned. Note that business messages that were not sent by the bot and do not
+/// contain an inline keyboard can only be edited within 48 hours from the time they
+/// were sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EditMessageCaption {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// New caption of the message, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the message caption. See formatting options for
+    /// more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which
+    /// can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media. Supported
+    /// only for animation, photo and video messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// A JSON-serialized object for an inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to edit animation, audio, document, photo, or video messages, or
+/// to add media to text messages. If a message is part of a message album, then it
+/// can be edited only to an audio for audio albums, only to a document for document
+/// albums and to a photo or a video otherwise. When an inline message is edited, a
+/// new file can't be uploaded; use a previously uploaded file via its file_id or
+/// specify a URL. On success, if the edited message is not an inline message, the
+/// edited Message is returned, otherwise True is returned. Note that business
+/// messages that were not sent by the bot and do not contain an inline keyboard can
+/// only be edited within 48 hours from the time they were sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EditMessageMedia {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// A JSON-serialized object for a new media content of the message
+    pub media: InputMedia,
+    /// A JSON-serialized object for an inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to edit live location messages. A location can be edited until
+/// its live_period expires or editing is explicitly disabled by a call to
+/// stopMessageLiveLocation. On success, if the edited message is not an inline
+/// message, the edited Message is returned, otherwise True is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EditMessageLiveLocation {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// Latitude of new location
+    pub latitude: f64,
+    /// Longitude of new location
+    pub longitude: f64,
+    /// New period in seconds during which the location can be updated, starting
+    /// from the message send date. If 0x7FFFFFFF is specified, then the location
+    /// can be updated forever. Otherwise, the new value must not exceed the current
+    /// live_period by more than a day, and the live location expiration date must
+    /// remain within the next 90 days. If not specified, then live_period remains
+    /// unchanged
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Direction in which the user is moving, in degrees. Must be between 1 and 360
+    /// if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// The maximum distance for proximity alerts about approaching another chat
+    /// member, in meters. Must be between 1 and 100000 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    /// A JSON-serialized object for a new inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to stop updating a live location message before live_period
+/// expires. On success, if the message is not an inline message, the edited Message
+/// is returned, otherwise True is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct StopMessageLiveLocation {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// A JSON-serialized object for a new inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to edit only the reply markup of messages. On success, if the
+/// edited message is not an inline message, the edited Message is returned,
+/// otherwise True is returned. Note that business messages that were not sent by
+/// the bot and do not contain an inline keyboard can only be edited within 48 hours
+/// from the time they were sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EditMessageReplyMarkup {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    /// A JSON-serialized object for an inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to stop a poll which was sent by the bot. On success, the
+/// stopped Poll is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StopPoll {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Identifier of the original message with the poll
+    pub message_id: i64,
+    /// A JSON-serialized object for a new message inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to delete a message, including service messages, with the
+/// following limitations: – A message can only be deleted if it was sent less than
+/// 48 hours ago. – Service messages about a supergroup, channel, or forum topic
+/// creation can't be deleted. – A dice message in a private chat can only be
+/// deleted if it was sent more than 24 hours ago. – Bots can delete outgoing
+/// messages in private chats, groups, and supergroups. – Bots can delete incoming
+/// messages in private chats. – Bots granted can_post_messages permissions can
+/// delete outgoing messages in channels. – If the bot is an administrator of a
+/// group, it can delete any message there. – If the bot has can_delete_messages
+/// permission in a supergroup or a channel, it can delete any message there.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteMessage {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Identifier of the message to delete
+    pub message_id: i64,
+}
+
+/// Use this method to delete multiple messages simultaneously. If some of the
+/// specified messages can't be found, they are skipped. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteMessages {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// A JSON-serialized list of 1-100 identifiers of messages to delete. See
+    /// deleteMessage for limitations on which messages can be deleted
+    pub message_ids: Vec<i64>,
+}
+
+/// Use this method to send static .WEBP, animated .TGS, or video .WEBM stickers. On
+/// success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendSticker {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Sticker to send. Pass a file_id as String to send a file that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL as a String for Telegram to
+    /// get a .WEBP sticker from the Internet, or upload a new .WEBP, .TGS, or .WEBM
+    /// sticker using multipart/form-data. More information on Sending Files ».
+    /// Video and animated stickers can't be sent via an HTTP URL.
+    pub sticker: String,
+    /// Emoji associated with the sticker; only for just uploaded stickers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove a reply keyboard or
+    /// to force a reply from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<PolymorphReplyMarkup>,
+}
+
+/// Use this method to get a sticker set. On success, a StickerSet object is
+/// returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetStickerSet {
+    /// Sticker set name
+    pub name: String,
+}
+
+/// Use this method to get information about custom emoji stickers by their
+/// identifiers. Returns an Array of Sticker objects.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetCustomEmojiStickers {
+    /// A JSON-serialized list of custom emoji identifiers. At most 200 custom emoji
+    /// identifiers can be specified.
+    pub custom_emoji_ids: Vec<String>,
+}
+
+/// Use this method to upload a file with a sticker for later use in the
+/// createNewStickerSet, addStickerToSet, or replaceStickerInSet methods (the file
+/// can be used multiple times). Returns the uploaded File on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UploadStickerFile {
+    /// User identifier of sticker file owner
+    pub user_id: i64,
+    /// A file with the sticker in .WEBP, .PNG, .TGS, or .WEBM format. See
+    /// https://core.telegram.org/stickers for technical requirements. More
+    /// information on Sending Files »
+    pub sticker: String,
+    /// Format of the sticker, must be one of “static”, “animated”, “video”
+    pub sticker_format: InputStickerFormat,
+}
+
+/// Use this method to create a new sticker set owned by a user. The bot will be
+/// able to edit the sticker set thus created. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateNewStickerSet {
+    /// User identifier of created sticker set owner
+    pub user_id: i64,
+    /// Short name of sticker set, to be used in t.me/addstickers/ URLs (e.g.,
+    /// animals). Can contain only English letters, digits and underscores. Must
+    /// begin with a letter, can't contain consecutive underscores and must end in
+    /// "_by_<bot_username>". <bot_username> is case insensitive. 1-64 characters.
+    pub name: String,
+    /// Sticker set title, 1-64 characters
+    pub title: String,
+    /// A JSON-serialized list of 1-50 initial stickers to be added to the sticker
+    /// set
+    pub stickers: Vec<InputSticker>,
+    /// Type of stickers in the set, pass “regular”, “mask”, or “custom_emoji”. By
+    /// default, a regular sticker set is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_type: Option<StickerType>,
+    /// Pass True if stickers in the sticker set must be repainted to the color of
+    /// text when used in messages, the accent color if used as emoji status, white
+    /// on chat photos, or another appropriate color based on context; for custom
+    /// emoji sticker sets only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_repainting: Option<bool>,
+}
+
+/// Use this method to add a new sticker to a set created by the bot. Emoji sticker
+/// sets can have up to 200 stickers. Other sticker sets can have up to 120
+/// stickers. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AddStickerToSet {
+    /// User identifier of sticker set owner
+    pub user_id: i64,
+    /// Sticker set name
+    pub name: String,
+    /// A JSON-serialized object with information about the added sticker. If
+    /// exactly the same sticker had already been added to the set, then the set
+    /// isn't changed.
+    pub sticker: InputSticker,
+}
+
+/// Use this method to move a sticker in a set created by the bot to a specific
+/// position. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerPositionInSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// New sticker position in the set, zero-based
+    pub position: i64,
+}
+
+/// Use this method to delete a sticker from a set created by the bot. Returns True
+/// on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteStickerFromSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+}
+
+/// Use this method to replace an existing sticker in a sticker set with a new one.
+/// The method is equivalent to calling deleteStickerFromSet, then addStickerToSet,
+/// then setStickerPositionInSet. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReplaceStickerInSet {
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+    /// Sticker set name
+    pub name: String,
+    /// File identifier of the replaced sticker
+    pub old_sticker: String,
+    /// A JSON-serialized object with information about the added sticker. If
+    /// exactly the same sticker had already been added to the set, then the set
+    /// remains unchanged.
+    pub sticker: InputSticker,
+}
+
+/// Use this method to change the list of emoji assigned to a regular or custom
+/// emoji sticker. The sticker must belong to a sticker set created by the bot.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerEmojiList {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized list of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+}
+
+/// Use this method to change search keywords assigned to a regular or custom emoji
+/// sticker. The sticker must belong to a sticker set created by the bot. Returns
+/// True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerKeywords {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized list of 0-20 search keywords for the sticker with total
+    /// length of up to 64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+
+/// Use this method to change the mask position of a mask sticker. The sticker must
+/// belong to a sticker set that was created by the bot. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerMaskPosition {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized object with the position where the mask should be placed
+    /// on faces. Omit the parameter to remove the mask position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+
+/// Use this method to set the title of a created sticker set. Returns True on
+/// success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerSetTitle {
+    /// Sticker set name
+    pub name: String,
+    /// Sticker set title, 1-64 characters
+    pub title: String,
+}
+
+/// Use this method to set the thumbnail of a regular or mask sticker set. The
+/// format of the thumbnail file must match the format of the stickers in the set.
+/// Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetStickerSetThumbnail {
+    /// Sticker set name
+    pub name: String,
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+    /// A .WEBP or .PNG image with the thumbnail, must be up to 128 kilobytes in
+    /// size and have a width and height of exactly 100px, or a .TGS animation with
+    /// a thumbnail up to 32 kilobytes in size (see
+    /// https://core.telegram.org/stickers#animation-requirements for animated
+    /// sticker technical requirements), or a WEBM video with the thumbnail up to 32
+    /// kilobytes in size; see https://core.telegram.org/stickers#video-requirements
+    /// for video sticker technical requirements. Pass a file_id as a String to send
+    /// a file that already exists on the Telegram servers, pass an HTTP URL as a
+    /// String for Telegram to get a file from the Internet, or upload a new one
+    /// using multipart/form-data. More information on Sending Files ». Animated and
+    /// video sticker set thumbnails can't be uploaded via HTTP URL. If omitted,
+    /// then the thumbnail is dropped and the first sticker is used as the
+    /// thumbnail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    /// Format of the thumbnail, must be one of “static” for a .WEBP or .PNG image,
+    /// “animated” for a .TGS animation, or “video” for a WEBM video
+    pub format: InputStickerFormat,
+}
+
+/// Use this method to set the thumbnail of a custom emoji sticker set. Returns True
+/// on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetCustomEmojiStickerSetThumbnail {
+    /// Sticker set name
+    pub name: String,
+    /// Custom emoji identifier of a sticker from the sticker set; pass an empty
+    /// string to drop the thumbnail and use the first sticker as the thumbnail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+}
+
+/// Use this method to delete a sticker set that was created by the bot. Returns
+/// True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DeleteStickerSet {
+    /// Sticker set name
+    pub name: String,
+}
+
+/// Use this method to send answers to an inline query. On success, True is
+/// returned. No more than 50 results per query are allowed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnswerInlineQuery {
+    /// Unique identifier for the answered query
+    pub inline_query_id: String,
+    /// A JSON-serialized array of results for the inline query
+    pub results: Vec<InlineQueryResult>,
+    /// The maximum amount of time in seconds that the result of the inline query
+    /// may be cached on the server. Defaults to 300.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i64>,
+    /// Pass True if results may be cached on the server side only for the user that
+    /// sent the query. By default, results may be returned to any user who sends
+    /// the same query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_personal: Option<bool>,
+    /// Pass the offset that a client should send in the next query with the same
+    /// text to receive more results. Pass an empty string if there are no more
+    /// results or if you don't support pagination. Offset length can't exceed 64
+    /// bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<String>,
+    /// A JSON-serialized object describing a button to be shown above inline query
+    /// results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub button: Option<InlineQueryResultsButton>,
+}
+
+/// Use this method to set the result of an interaction with a Web App and send a
+/// corresponding message on behalf of the user to the chat from which the query
+/// originated. On success, a SentWebAppMessage object is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnswerWebAppQuery {
+    /// Unique identifier for the query to be answered
+    pub web_app_query_id: String,
+    /// A JSON-serialized object describing the message to be sent
+    pub result: InlineQueryResult,
+}
+
+/// Use this method to send invoices. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendInvoice {
+    /// Unique identifier for the target chat or username of the target channel (in
+    /// the format @channelusername)
+    pub chat_id: PolymorphChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the
+    /// user, use it for your internal processes.
+    pub payload: String,
+    /// Payment provider token, obtained via @BotFather. Pass an empty string for
+    /// payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, see more on currencies. Pass “XTR” for
+    /// payments in Telegram Stars.
+    pub currency: String,
+    /// Price breakdown, a JSON-serialized list of components (e.g. product price,
+    /// tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain
+    /// exactly one item for payments in Telegram Stars.
+    pub prices: Vec<LabeledPrice>,
+    /// The maximum accepted amount for tips in the smallest units of the currency
+    /// (integer, not float/double). For example, for a maximum tip of US$ 1.45 pass
+    /// max_tip_amount = 145. See the exp parameter in currencies.json, it shows the
+    /// number of digits past the decimal point for each currency (2 for the
+    /// majority of currencies). Defaults to 0. Not supported for payments in
+    /// Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+    /// A JSON-serialized array of suggested amounts of tips in the smallest units
+    /// of the currency (integer, not float/double). At most 4 suggested tip amounts
+    /// can be specified. The suggested tip amounts must be positive, passed in a
+    /// strictly increased order and must not exceed max_tip_amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+    /// Unique deep-linking parameter. If left empty, forwarded copies of the sent
+    /// message will have a Pay button, allowing multiple users to pay directly from
+    /// the forwarded message, using the same invoice. If non-empty, forwarded
+    /// copies of the sent message will have a URL button with a deep link to the
+    /// bot (instead of a Pay button), with the value used as the start parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+    /// JSON-serialized data about the invoice, which will be shared with the
+    /// payment provider. A detailed description of required fields should be
+    /// provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    /// URL of the product photo for the invoice. Can be a photo of the goods or a
+    /// marketing image for a service. People like it better when they see what they
+    /// are paying for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    /// Pass True if you require the user's full name to complete the order. Ignored
+    /// for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    /// Pass True if you require the user's phone number to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    /// Pass True if you require the user's email address to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    /// Pass True if you require the user's shipping address to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    /// Pass True if the user's phone number should be sent to the provider. Ignored
+    /// for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    /// Pass True if the user's email address should be sent to the provider.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// Pass True if the final price depends on the shipping method. Ignored for
+    /// payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// A JSON-serialized object for an inline keyboard. If empty, one 'Pay total
+    /// price' button will be shown. If not empty, the first button must be a Pay
+    /// button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to create a link for an invoice. Returns the created invoice
+/// link as String on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateInvoiceLink {
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the
+    /// user, use it for your internal processes.
+    pub payload: String,
+    /// Payment provider token, obtained via @BotFather. Pass an empty string for
+    /// payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, see more on currencies. Pass “XTR” for
+    /// payments in Telegram Stars.
+    pub currency: String,
+    /// Price breakdown, a JSON-serialized list of components (e.g. product price,
+    /// tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain
+    /// exactly one item for payments in Telegram Stars.
+    pub prices: Vec<LabeledPrice>,
+    /// The maximum accepted amount for tips in the smallest units of the currency
+    /// (integer, not float/double). For example, for a maximum tip of US$ 1.45 pass
+    /// max_tip_amount = 145. See the exp parameter in currencies.json, it shows the
+    /// number of digits past the decimal point for each currency (2 for the
+    /// majority of currencies). Defaults to 0. Not supported for payments in
+    /// Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+    /// A JSON-serialized array of suggested amounts of tips in the smallest units
+    /// of the currency (integer, not float/double). At most 4 suggested tip amounts
+    /// can be specified. The suggested tip amounts must be positive, passed in a
+    /// strictly increased order and must not exceed max_tip_amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+    /// JSON-serialized data about the invoice, which will be shared with the
+    /// payment provider. A detailed description of required fields should be
+    /// provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    /// URL of the product photo for the invoice. Can be a photo of the goods or a
+    /// marketing image for a service. People like it better when they see what they
+    /// are paying for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    /// Pass True if you require the user's full name to complete the order. Ignored
+    /// for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    /// Pass True if you require the user's phone number to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    /// Pass True if you require the user's email address to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    /// Pass True if you require the user's shipping address to complete the order.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    /// Pass True if the user's phone number should be sent to the provider. Ignored
+    /// for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    /// Pass True if the user's email address should be sent to the provider.
+    /// Ignored for payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// Pass True if the final price depends on the shipping method. Ignored for
+    /// payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+}
+
+/// If you sent an invoice requesting a shipping address and the parameter
+/// is_flexible was specified, the Bot API will send an Update with a shipping_query
+/// field to the bot. Use this method to reply to shipping queries. On success, True
+/// is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnswerShippingQuery {
+    /// Unique identifier for the query to be answered
+    pub shipping_query_id: String,
+    /// Pass True if delivery to the specified address is possible and False if
+    /// there are any problems (for example, if delivery to the specified address is
+    /// not possible)
+    pub ok: bool,
+    /// Required if ok is True. A JSON-serialized array of available shipping
+    /// options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<ShippingOption>>,
+    /// Required if ok is False. Error message in human readable form that explains
+    /// why it is impossible to complete the order (e.g. "Sorry, delivery to your
+    /// desired address is unavailable'). Telegram will display this message to the
+    /// user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// Once the user has confirmed their payment and shipping details, the Bot API
+/// sends the final confirmation in the form of an Update with the field
+/// pre_checkout_query. Use this method to respond to such pre-checkout queries. On
+/// success, True is returned. Note: The Bot API must receive an answer within 10
+/// seconds after the pre-checkout query was sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnswerPreCheckoutQuery {
+    /// Unique identifier for the query to be answered
+    pub pre_checkout_query_id: String,
+    /// Specify True if everything is alright (goods are available, etc.) and the
+    /// bot is ready to proceed with the order. Use False if there are any problems.
+    pub ok: bool,
+    /// Required if ok is False. Error message in human readable form that explains
+    /// the reason for failure to proceed with the checkout (e.g. "Sorry, somebody
+    /// just bought the last of our amazing black T-shirts while you were busy
+    /// filling out your payment details. Please choose a different color or
+    /// garment!"). Telegram will display this message to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// Returns the bot's Telegram Star transactions in chronological order. On success,
+/// returns a StarTransactions object.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetStarTransactions {
+    /// Number of transactions to skip in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// The maximum number of transactions to be retrieved. Values between 1-100 are
+    /// accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Refunds a successful payment in Telegram Stars. Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RefundStarPayment {
+    /// Identifier of the user whose payment will be refunded
+    pub user_id: i64,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+}
+
+/// Informs a user that some of the Telegram Passport elements they provided
+/// contains errors. The user will not be able to re-submit their Passport to you
+/// until the errors are fixed (the contents of the field for which you returned the
+/// error must change). Returns True on success.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SetPassportDataErrors {
+    /// User identifier
+    pub user_id: i64,
+    /// A JSON-serialized array describing the errors
+    pub errors: Vec<PassportElementError>,
+}
+
+/// Use this method to send a game. On success, the sent Message is returned.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendGame {
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum; for
+    /// forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Short name of the game, serves as the unique identifier for the game. Set up
+    /// your games via @BotFather.
+    pub game_short_name: String,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for
+    /// private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// A JSON-serialized object for an inline keyboard. If empty, one 'Play
+    /// game_title' button will be shown. If not empty, the first button must launch
+    /// the game.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Use this method to set the score of the specified user in a game message. On
+/// success, if the message is not an inline message, the Message is returned,
+/// otherwise True is returned. Returns an error, if the new score is not greater
+/// than the user's current score in the chat and force is False.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SetGameScore {
+    /// User identifier
+    pub user_id: i64,
+    /// New score, must be non-negative
+    pub score: i64,
+    /// Pass True if the high score is allowed to decrease. This can be useful when
+    /// fixing mistakes or banning cheaters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    /// Pass True if the game message should not be automatically edited to include
+    /// the current scoreboard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_edit_message: Option<bool>,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget<i64>,
+}
+
+/// Use this method to get data for high score tables. Will return the score of the
+/// specified user and several of their neighbors in a game. Returns an Array of
+/// GameHighScore objects.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct GetGameHighScores {
+    /// Target user id
+    pub user_id: i64,
+    /// Message to act on, in a chat or sent via the bot in inline mode.
+    #[serde(flatten)]
+    pub target: MessageTarget<i64>,
+}
@@ -0,0 +1,213 @@
+//! Decryption of Telegram Passport data.
+//!
+//! Every encrypted blob is handled the same way: the secret and the hash are
+//! concatenated and hashed with SHA-512, the first 32 bytes of the digest are the
+//! AES-256 key and the next 16 bytes the CBC IV. The decrypted payload must hash
+//! (SHA-256) to the accompanying hash, and its first byte tells how many bytes of
+//! random padding precede the actual content.
+
+use std::error::Error;
+use std::fmt;
+
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockDecryptMut, KeyIvInit};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::Oaep;
+use serde::de::DeserializeOwned;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+pub use rsa::RsaPrivateKey;
+
+use super::{Credentials, DataCredentials, FileCredentials, IdDocumentData, PersonalDetails,
+            ResidentialAddress};
+use crate::{EncryptedCredentials, EncryptedPassportElement, PassportData, PassportElementType};
+
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+
+/// Errors which can occur while decrypting Telegram Passport data.
+#[derive(Debug)]
+pub enum PassportError {
+    /// A field that must be base64-encoded could not be decoded.
+    Base64(base64::DecodeError),
+    /// The credentials secret could not be decrypted with the bot's private key.
+    Rsa(rsa::Error),
+    /// The decrypted payload does not match its hash, i.e. the data was tampered
+    /// with or a wrong secret was used.
+    HashMismatch,
+    /// The encrypted payload is not a whole number of AES blocks.
+    InvalidLength,
+    /// The padding prefix of the decrypted payload is malformed.
+    InvalidPadding,
+    /// The decrypted payload is not the expected JSON.
+    Json(serde_json::Error),
+    /// The element carries no encrypted data or the credentials for it are missing.
+    MissingData,
+    /// The bot's private key is neither a PKCS#1 nor a PKCS#8 PEM document.
+    InvalidKey,
+}
+
+impl fmt::Display for PassportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PassportError::Base64(e) => write!(f, "invalid base64: {}", e),
+            PassportError::Rsa(e) => write!(f, "failed to decrypt secret: {}", e),
+            PassportError::HashMismatch => f.write_str("decrypted data does not match its hash"),
+            PassportError::InvalidLength => f.write_str("encrypted data has an invalid length"),
+            PassportError::InvalidPadding => f.write_str("decrypted data has an invalid padding"),
+            PassportError::Json(e) => write!(f, "invalid decrypted data: {}", e),
+            PassportError::MissingData => f.write_str("no encrypted data or credentials for it"),
+            PassportError::InvalidKey => f.write_str("invalid private key"),
+        }
+    }
+}
+
+impl Error for PassportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PassportError::Base64(e) => Some(e),
+            PassportError::Rsa(e) => Some(e),
+            PassportError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<base64::DecodeError> for PassportError {
+    fn from(e: base64::DecodeError) -> Self {
+        PassportError::Base64(e)
+    }
+}
+
+impl From<rsa::Error> for PassportError {
+    fn from(e: rsa::Error) -> Self {
+        PassportError::Rsa(e)
+    }
+}
+
+impl From<serde_json::Error> for PassportError {
+    fn from(e: serde_json::Error) -> Self {
+        PassportError::Json(e)
+    }
+}
+
+
+/// Decrypted data of an `EncryptedPassportElement`, typed by the element type.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum ElementData {
+    /// Data of a “personal_details” element
+    PersonalDetails(PersonalDetails),
+    /// Data of a “passport”, “driver_license”, “identity_card” or
+    /// “internal_passport” element
+    IdDocument(IdDocumentData),
+    /// Data of an “address” element
+    ResidentialAddress(ResidentialAddress),
+}
+
+
+impl EncryptedCredentials {
+    /// Decrypts the credentials with the bot's private key. The returned
+    /// credentials hold the secrets of every element shared with the bot.
+    pub fn decrypt(&self, key: &RsaPrivateKey) -> Result<Credentials, PassportError> {
+        let secret = key.decrypt(Oaep::new::<Sha1>(), &BASE64.decode(&self.secret)?)?;
+        let hash = BASE64.decode(&self.hash)?;
+        let data = BASE64.decode(&self.data)?;
+        let plain = decrypt(&data, &secret, &hash)?;
+        Ok(serde_json::from_slice(&plain)?)
+    }
+}
+
+
+impl PassportData {
+    /// Decrypts the credentials shared along with the elements. Shortcut for
+    /// `self.credentials.decrypt(key)`.
+    pub fn decrypt_credentials(&self, key: &RsaPrivateKey) -> Result<Credentials, PassportError> {
+        self.credentials.decrypt(key)
+    }
+}
+
+
+impl EncryptedPassportElement {
+    /// Decrypts the `data` field of the element into `T`.
+    pub fn decrypt_data_as<T: DeserializeOwned>(
+        &self,
+        credentials: &DataCredentials,
+    ) -> Result<T, PassportError> {
+        let data = self.data.as_ref().ok_or(PassportError::MissingData)?;
+        let data = BASE64.decode(data)?;
+        let secret = BASE64.decode(&credentials.secret)?;
+        let hash = BASE64.decode(&credentials.data_hash)?;
+        let plain = decrypt(&data, &secret, &hash)?;
+        Ok(serde_json::from_slice(&plain)?)
+    }
+
+    /// Decrypts the `data` field of the element using the matching entry of the
+    /// decrypted credentials. Returns `Ok(None)` for element types which carry no
+    /// data (documents scans, phone number, email, types unknown to this version
+    /// of the models).
+    pub fn decrypt_data(&self, credentials: &Credentials) -> Result<Option<ElementData>, PassportError> {
+        let data_credentials = || {
+            credentials
+                .secure_data
+                .get(&self.ty)
+                .and_then(|value| value.data.as_ref())
+                .ok_or(PassportError::MissingData)
+        };
+        let data = match &self.ty {
+            PassportElementType::PersonalDetails => {
+                ElementData::PersonalDetails(self.decrypt_data_as(data_credentials()?)?)
+            }
+            PassportElementType::Address => {
+                ElementData::ResidentialAddress(self.decrypt_data_as(data_credentials()?)?)
+            }
+            ty if ty.is_identity_document() => {
+                ElementData::IdDocument(self.decrypt_data_as(data_credentials()?)?)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(data))
+    }
+}
+
+
+/// Parses the bot's private key from a PEM document, either PKCS#1
+/// (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`).
+pub fn private_key_from_pem(pem: &str) -> Result<RsaPrivateKey, PassportError> {
+    RsaPrivateKey::from_pkcs1_pem(pem)
+        .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+        .map_err(|_| PassportError::InvalidKey)
+}
+
+
+/// Decrypts the contents of a downloaded `PassportFile` with its credentials.
+pub fn decrypt_file(content: &[u8], credentials: &FileCredentials) -> Result<Vec<u8>, PassportError> {
+    let secret = BASE64.decode(&credentials.secret)?;
+    let hash = BASE64.decode(&credentials.file_hash)?;
+    decrypt(content, &secret, &hash)
+}
+
+
+fn decrypt(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, PassportError> {
+    if data.is_empty() || data.len() % 16 != 0 {
+        return Err(PassportError::InvalidLength);
+    }
+    let digest = Sha512::new().chain_update(secret).chain_update(hash).finalize();
+    let (key, iv) = (&digest[..32], &digest[32..48]);
+    let mut buf = data.to_vec();
+    Aes256CbcDec::new(key.into(), iv.into())
+        .decrypt_padded_mut::<NoPadding>(&mut buf)
+        .map_err(|_| PassportError::InvalidLength)?;
+    if Sha256::digest(&buf).as_slice() != hash {
+        return Err(PassportError::HashMismatch);
+    }
+    let padding = buf[0] as usize;
+    if padding < 32 || padding > buf.len() {
+        return Err(PassportError::InvalidPadding);
+    }
+    buf.drain(..padding);
+    Ok(buf)
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use crate::PassportElementType;
+
+
+impl PassportElementType {
+    /// All the element types known to this version of the models, in the order of
+    /// the Telegram Passport documentation.
+    pub const ALL: [PassportElementType; 13] = [
+        PassportElementType::PersonalDetails,
+        PassportElementType::Passport,
+        PassportElementType::DriverLicense,
+        PassportElementType::IdentityCard,
+        PassportElementType::InternalPassport,
+        PassportElementType::Address,
+        PassportElementType::UtilityBill,
+        PassportElementType::BankStatement,
+        PassportElementType::RentalAgreement,
+        PassportElementType::PassportRegistration,
+        PassportElementType::TemporaryRegistration,
+        PassportElementType::PhoneNumber,
+        PassportElementType::Email,
+    ];
+
+    /// The element type as it appears on the wire, e.g. “driver_license”.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PassportElementType::PersonalDetails => "personal_details",
+            PassportElementType::Passport => "passport",
+            PassportElementType::DriverLicense => "driver_license",
+            PassportElementType::IdentityCard => "identity_card",
+            PassportElementType::InternalPassport => "internal_passport",
+            PassportElementType::Address => "address",
+            PassportElementType::UtilityBill => "utility_bill",
+            PassportElementType::BankStatement => "bank_statement",
+            PassportElementType::RentalAgreement => "rental_agreement",
+            PassportElementType::PassportRegistration => "passport_registration",
+            PassportElementType::TemporaryRegistration => "temporary_registration",
+            PassportElementType::PhoneNumber => "phone_number",
+            PassportElementType::Email => "email",
+            PassportElementType::Unknown(ty) => ty,
+        }
+    }
+
+    /// True for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport”.
+    pub fn is_identity_document(&self) -> bool {
+        matches!(
+            self,
+            PassportElementType::Passport
+                | PassportElementType::DriverLicense
+                | PassportElementType::IdentityCard
+                | PassportElementType::InternalPassport
+        )
+    }
+
+    /// True for “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration”.
+    pub fn is_address_document(&self) -> bool {
+        matches!(
+            self,
+            PassportElementType::UtilityBill
+                | PassportElementType::BankStatement
+                | PassportElementType::RentalAgreement
+                | PassportElementType::PassportRegistration
+                | PassportElementType::TemporaryRegistration
+        )
+    }
+
+    /// True if elements of this type carry encrypted `data`.
+    pub fn has_data(&self) -> bool {
+        matches!(self, PassportElementType::PersonalDetails | PassportElementType::Address) || self.is_identity_document()
+    }
+
+    /// True if elements of this type carry a `front_side` scan.
+    pub fn has_front_side(&self) -> bool {
+        self.is_identity_document()
+    }
+
+    /// True if elements of this type carry a `reverse_side` scan.
+    pub fn has_reverse_side(&self) -> bool {
+        matches!(self, PassportElementType::DriverLicense | PassportElementType::IdentityCard)
+    }
+
+    /// True if elements of this type carry a `selfie`.
+    pub fn has_selfie(&self) -> bool {
+        self.is_identity_document()
+    }
+
+    /// True if elements of this type carry `files`.
+    pub fn has_files(&self) -> bool {
+        self.is_address_document()
+    }
+
+    /// True if elements of this type may carry a `translation`.
+    pub fn has_translation(&self) -> bool {
+        self.is_identity_document() || self.is_address_document()
+    }
+}
+
+impl fmt::Display for PassportElementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
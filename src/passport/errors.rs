@@ -0,0 +1,359 @@
+//! Construction of `PassportElementError`s and `SetPassportDataErrors` requests.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{Credentials, SecureValue};
+use crate::{EncryptedPassportElement, PassportElementError, PassportElementErrorDataField,
+            PassportElementErrorFile, PassportElementErrorFiles, PassportElementErrorFrontSide,
+            PassportElementErrorReverseSide, PassportElementErrorSelfie,
+            PassportElementErrorTranslationFile, PassportElementErrorTranslationFiles,
+            PassportElementErrorUnspecified, PassportElementType, SetPassportDataErrors};
+
+
+/// Reasons for a `PassportElementError` to be rejected before it is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidPassportError {
+    /// Errors with this source can't be reported for elements of this type, e.g. a
+    /// reverse side for a passport.
+    UnsupportedSource {
+        ty: PassportElementType,
+        source: &'static str,
+    },
+    /// The credentials hold no hash for the data or file the error refers to.
+    MissingHash {
+        ty: PassportElementType,
+        source: &'static str,
+    },
+}
+
+impl fmt::Display for InvalidPassportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidPassportError::UnsupportedSource { ty, source } => {
+                write!(f, "“{}” elements have no {}", ty, source)
+            }
+            InvalidPassportError::MissingHash { ty, source } => {
+                write!(f, "no hash of the {} of the “{}” element", source, ty)
+            }
+        }
+    }
+}
+
+impl Error for InvalidPassportError {}
+
+
+fn check(ty: &PassportElementType, source: &'static str, allowed: bool) -> Result<(), InvalidPassportError> {
+    if allowed {
+        Ok(())
+    } else {
+        Err(InvalidPassportError::UnsupportedSource { ty: ty.clone(), source })
+    }
+}
+
+
+impl PassportElementErrorDataField {
+    /// Creates an error for elements with `data`: “personal_details”, “passport”,
+    /// “driver_license”, “identity_card”, “internal_passport” and “address”.
+    pub fn new<F, H, M>(ty: PassportElementType, field_name: F, data_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        F: Into<String>,
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "data", ty.has_data())?;
+        Ok(PassportElementErrorDataField {
+            source: "data".to_string(),
+            ty,
+            field_name: field_name.into(),
+            data_hash: data_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorFrontSide {
+    /// Creates an error for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport” elements.
+    pub fn new<H, M>(ty: PassportElementType, file_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "front_side", ty.has_front_side())?;
+        Ok(PassportElementErrorFrontSide {
+            source: "front_side".to_string(),
+            ty,
+            file_hash: file_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorReverseSide {
+    /// Creates an error for “driver_license” and “identity_card” elements.
+    pub fn new<H, M>(ty: PassportElementType, file_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "reverse_side", ty.has_reverse_side())?;
+        Ok(PassportElementErrorReverseSide {
+            source: "reverse_side".to_string(),
+            ty,
+            file_hash: file_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorSelfie {
+    /// Creates an error for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport” elements.
+    pub fn new<H, M>(ty: PassportElementType, file_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "selfie", ty.has_selfie())?;
+        Ok(PassportElementErrorSelfie {
+            source: "selfie".to_string(),
+            ty,
+            file_hash: file_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorFile {
+    /// Creates an error for “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration” elements.
+    pub fn new<H, M>(ty: PassportElementType, file_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "file", ty.has_files())?;
+        Ok(PassportElementErrorFile {
+            source: "file".to_string(),
+            ty,
+            file_hash: file_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorFiles {
+    /// Creates an error for “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration” elements.
+    pub fn new<M: Into<String>>(ty: PassportElementType, file_hashes: Vec<String>, message: M) -> Result<Self, InvalidPassportError> {
+        check(&ty, "files", ty.has_files())?;
+        Ok(PassportElementErrorFiles {
+            source: "files".to_string(),
+            ty,
+            file_hashes,
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorTranslationFile {
+    /// Creates an error for identity documents and proofs of address.
+    pub fn new<H, M>(ty: PassportElementType, file_hash: H, message: M) -> Result<Self, InvalidPassportError>
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        check(&ty, "translation_file", ty.has_translation())?;
+        Ok(PassportElementErrorTranslationFile {
+            source: "translation_file".to_string(),
+            ty,
+            file_hash: file_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorTranslationFiles {
+    /// Creates an error for identity documents and proofs of address.
+    pub fn new<M: Into<String>>(ty: PassportElementType, file_hashes: Vec<String>, message: M) -> Result<Self, InvalidPassportError> {
+        check(&ty, "translation_files", ty.has_translation())?;
+        Ok(PassportElementErrorTranslationFiles {
+            source: "translation_files".to_string(),
+            ty,
+            file_hashes,
+            message: message.into(),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl PassportElementErrorUnspecified {
+    /// Creates an error for an element of any type.
+    pub fn new<H, M>(ty: PassportElementType, element_hash: H, message: M) -> Self
+    where
+        H: Into<String>,
+        M: Into<String>,
+    {
+        PassportElementErrorUnspecified {
+            source: "unspecified".to_string(),
+            ty,
+            element_hash: element_hash.into(),
+            message: message.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+
+macro_rules! impl_from_variant {
+    ($($variant:ident),*) => {
+        $(
+            impl From<$variant> for PassportElementError {
+                fn from(error: $variant) -> Self {
+                    PassportElementError::$variant(error)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_variant!(
+    PassportElementErrorDataField,
+    PassportElementErrorFrontSide,
+    PassportElementErrorReverseSide,
+    PassportElementErrorSelfie,
+    PassportElementErrorFile,
+    PassportElementErrorFiles,
+    PassportElementErrorTranslationFile,
+    PassportElementErrorTranslationFiles,
+    PassportElementErrorUnspecified
+);
+
+
+/// A problem found while checking a decrypted Telegram Passport element. The
+/// hashes required by `PassportElementError` are filled in by
+/// `SetPassportDataErrors::from_issues`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassportIssue {
+    /// The data field with the given name is wrong.
+    DataField { field_name: String, message: String },
+    /// The front side scan is wrong.
+    FrontSide(String),
+    /// The reverse side scan is wrong.
+    ReverseSide(String),
+    /// The selfie is wrong.
+    Selfie(String),
+    /// The scan at the given index of `files` is wrong.
+    File { index: usize, message: String },
+    /// The list of scans is wrong as a whole.
+    Files(String),
+    /// The translation file at the given index is wrong.
+    TranslationFile { index: usize, message: String },
+    /// The translation is wrong as a whole.
+    TranslationFiles(String),
+    /// Something else is wrong with the element.
+    Unspecified(String),
+}
+
+impl PassportIssue {
+    /// Converts the issue into the error to report for `element`, taking the data
+    /// and file hashes from the element's credentials.
+    pub fn into_error(
+        self,
+        element: &EncryptedPassportElement,
+        credentials: &SecureValue,
+    ) -> Result<PassportElementError, InvalidPassportError> {
+        let ty = &element.ty;
+        let missing = |source| InvalidPassportError::MissingHash { ty: ty.clone(), source };
+        let hashes = |files: &Option<Vec<super::FileCredentials>>, source| {
+            files
+                .as_ref()
+                .map(|files| files.iter().map(|file| file.file_hash.clone()).collect::<Vec<_>>())
+                .ok_or_else(|| missing(source))
+        };
+        let error = match self {
+            PassportIssue::DataField { field_name, message } => {
+                let data = credentials.data.as_ref().ok_or_else(|| missing("data"))?;
+                PassportElementErrorDataField::new(ty.clone(), field_name, data.data_hash.clone(), message)?.into()
+            }
+            PassportIssue::FrontSide(message) => {
+                let file = credentials.front_side.as_ref().ok_or_else(|| missing("front_side"))?;
+                PassportElementErrorFrontSide::new(ty.clone(), file.file_hash.clone(), message)?.into()
+            }
+            PassportIssue::ReverseSide(message) => {
+                let file = credentials.reverse_side.as_ref().ok_or_else(|| missing("reverse_side"))?;
+                PassportElementErrorReverseSide::new(ty.clone(), file.file_hash.clone(), message)?.into()
+            }
+            PassportIssue::Selfie(message) => {
+                let file = credentials.selfie.as_ref().ok_or_else(|| missing("selfie"))?;
+                PassportElementErrorSelfie::new(ty.clone(), file.file_hash.clone(), message)?.into()
+            }
+            PassportIssue::File { index, message } => {
+                let hash = hashes(&credentials.files, "file")?
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| missing("file"))?;
+                PassportElementErrorFile::new(ty.clone(), hash, message)?.into()
+            }
+            PassportIssue::Files(message) => {
+                PassportElementErrorFiles::new(ty.clone(), hashes(&credentials.files, "files")?, message)?.into()
+            }
+            PassportIssue::TranslationFile { index, message } => {
+                let hash = hashes(&credentials.translation, "translation_file")?
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| missing("translation_file"))?;
+                PassportElementErrorTranslationFile::new(ty.clone(), hash, message)?.into()
+            }
+            PassportIssue::TranslationFiles(message) => {
+                let hashes = hashes(&credentials.translation, "translation_files")?;
+                PassportElementErrorTranslationFiles::new(ty.clone(), hashes, message)?.into()
+            }
+            PassportIssue::Unspecified(message) => {
+                PassportElementErrorUnspecified::new(ty.clone(), element.hash.clone(), message).into()
+            }
+        };
+        Ok(error)
+    }
+}
+
+
+impl SetPassportDataErrors {
+    /// Creates the request reporting `errors` to the user.
+    pub fn new(user_id: i64, errors: Vec<PassportElementError>) -> Self {
+        SetPassportDataErrors { user_id, errors }
+    }
+
+    /// Creates the request from the issues found in the elements shared by the
+    /// user. `credentials` are the decrypted credentials shared along with the
+    /// elements.
+    pub fn from_issues<'a, I>(user_id: i64, credentials: &Credentials, issues: I) -> Result<Self, InvalidPassportError>
+    where
+        I: IntoIterator<Item = (&'a EncryptedPassportElement, PassportIssue)>,
+    {
+        let empty = SecureValue {
+            data: None,
+            front_side: None,
+            reverse_side: None,
+            selfie: None,
+            translation: None,
+            files: None,
+        };
+        let errors = issues
+            .into_iter()
+            .map(|(element, issue)| {
+                let value = credentials.secure_data.get(&element.ty).unwrap_or(&empty);
+                issue.into_error(element, value)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(SetPassportDataErrors::new(user_id, errors))
+    }
+}
@@ -0,0 +1,187 @@
+//! Telegram Passport helpers.
+//!
+//! The raw `PassportData`, `EncryptedPassportElement` and `EncryptedCredentials`
+//! objects live in the crate root. This module adds the types described in the
+//! Telegram Passport documentation, the authorization request builder, typed
+//! constructors of `PassportElementError`s and, with
+//! the `passport` feature enabled, the decryption of the data shared with the bot.
+
+#[cfg(feature = "passport")]
+mod crypto;
+mod element_type;
+mod errors;
+mod scope;
+
+#[cfg(feature = "passport")]
+pub use self::crypto::{decrypt_file, private_key_from_pem, ElementData, PassportError,
+                       RsaPrivateKey};
+pub use self::errors::{InvalidPassportError, PassportIssue};
+pub use self::scope::{PassportRequest, PassportScope, PassportScopeElement, PassportScopeElementOne,
+                      PassportScopeElementOneOfSeveral, ScopeError};
+
+use crate::PassportElementType;
+
+
+/// Credentials required to decrypt and authenticate the Telegram Passport
+/// elements shared with the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Credentials {
+    /// Credentials for encrypted data
+    pub secure_data: SecureData,
+    /// Bot-specified nonce
+    pub nonce: String,
+}
+
+
+/// Data credentials for all the elements requested by the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SecureData {
+    /// Optional. Credentials for encrypted personal details
+    pub personal_details: Option<SecureValue>,
+    /// Optional. Credentials for encrypted passport
+    pub passport: Option<SecureValue>,
+    /// Optional. Credentials for encrypted internal passport
+    pub internal_passport: Option<SecureValue>,
+    /// Optional. Credentials for encrypted driver license
+    pub driver_license: Option<SecureValue>,
+    /// Optional. Credentials for encrypted ID card
+    pub identity_card: Option<SecureValue>,
+    /// Optional. Credentials for encrypted residential address
+    pub address: Option<SecureValue>,
+    /// Optional. Credentials for encrypted utility bill
+    pub utility_bill: Option<SecureValue>,
+    /// Optional. Credentials for encrypted bank statement
+    pub bank_statement: Option<SecureValue>,
+    /// Optional. Credentials for encrypted rental agreement
+    pub rental_agreement: Option<SecureValue>,
+    /// Optional. Credentials for encrypted registration from internal passport
+    pub passport_registration: Option<SecureValue>,
+    /// Optional. Credentials for encrypted temporary registration
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Returns the credentials for the element of the given type.
+    pub fn get(&self, ty: &PassportElementType) -> Option<&SecureValue> {
+        match ty {
+            PassportElementType::PersonalDetails => self.personal_details.as_ref(),
+            PassportElementType::Passport => self.passport.as_ref(),
+            PassportElementType::InternalPassport => self.internal_passport.as_ref(),
+            PassportElementType::DriverLicense => self.driver_license.as_ref(),
+            PassportElementType::IdentityCard => self.identity_card.as_ref(),
+            PassportElementType::Address => self.address.as_ref(),
+            PassportElementType::UtilityBill => self.utility_bill.as_ref(),
+            PassportElementType::BankStatement => self.bank_statement.as_ref(),
+            PassportElementType::RentalAgreement => self.rental_agreement.as_ref(),
+            PassportElementType::PassportRegistration => self.passport_registration.as_ref(),
+            PassportElementType::TemporaryRegistration => self.temporary_registration.as_ref(),
+            PassportElementType::PhoneNumber | PassportElementType::Email | PassportElementType::Unknown(_) => None,
+        }
+    }
+}
+
+
+/// This object represents the credentials required to decrypt encrypted data.
+/// All fields are optional and depend on fields that were requested.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SecureValue {
+    /// Optional. Credentials for encrypted Telegram Passport data. Available for
+    /// “personal_details”, “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport” and “address” types.
+    pub data: Option<DataCredentials>,
+    /// Optional. Credentials for an encrypted document's front side. Available for
+    /// “passport”, “driver_license”, “identity_card” and “internal_passport”.
+    pub front_side: Option<FileCredentials>,
+    /// Optional. Credentials for an encrypted document's reverse side. Available
+    /// for “driver_license” and “identity_card”.
+    pub reverse_side: Option<FileCredentials>,
+    /// Optional. Credentials for an encrypted selfie of the user with a document.
+    /// Available for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport”.
+    pub selfie: Option<FileCredentials>,
+    /// Optional. Credentials for an encrypted translation of the document.
+    /// Available for “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration”.
+    pub translation: Option<Vec<FileCredentials>>,
+    /// Optional. Credentials for encrypted files. Available for “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration” and
+    /// “temporary_registration” types.
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+
+/// These credentials can be used to decrypt encrypted data from the data field in
+/// EncryptedPassportElement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct DataCredentials {
+    /// Checksum of encrypted data
+    pub data_hash: String,
+    /// Secret of encrypted data
+    pub secret: String,
+}
+
+
+/// These credentials can be used to decrypt encrypted files from the front_side,
+/// reverse_side, selfie, files and translation fields in EncryptedPassportElement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FileCredentials {
+    /// Checksum of encrypted file
+    pub file_hash: String,
+    /// Secret of encrypted file
+    pub secret: String,
+}
+
+
+/// This object represents personal details.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PersonalDetails {
+    /// First Name
+    pub first_name: String,
+    /// Last Name
+    pub last_name: String,
+    /// Optional. Middle Name
+    pub middle_name: Option<String>,
+    /// Date of birth in DD.MM.YYYY format
+    pub birth_date: String,
+    /// Gender, male or female
+    pub gender: String,
+    /// Citizenship (ISO 3166-1 alpha-2 country code)
+    pub country_code: String,
+    /// Country of residence (ISO 3166-1 alpha-2 country code)
+    pub residence_country_code: String,
+    /// First Name in the language of the user's country of residence
+    pub first_name_native: String,
+    /// Last Name in the language of the user's country of residence
+    pub last_name_native: String,
+    /// Optional. Middle Name in the language of the user's country of residence
+    pub middle_name_native: Option<String>,
+}
+
+
+/// This object represents a residential address.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ResidentialAddress {
+    /// First line for the address
+    pub street_line1: String,
+    /// Optional. Second line for the address
+    pub street_line2: Option<String>,
+    /// City
+    pub city: String,
+    /// Optional. State
+    pub state: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+    /// Address post code
+    pub post_code: String,
+}
+
+
+/// This object represents the data of an identity document.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct IdDocumentData {
+    /// Document number
+    pub document_no: String,
+    /// Optional. Date of expiry, in DD.MM.YYYY format
+    pub expiry_date: Option<String>,
+}
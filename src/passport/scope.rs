@@ -0,0 +1,321 @@
+//! Telegram Passport authorization requests.
+//!
+//! A bot asks for Passport data by sending the user a
+//! `tg://resolve?domain=telegrampassport` link carrying the requested scope, a
+//! nonce and the bot's public key. The data comes back in `Message::passport_data`.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::PassportElementType;
+
+
+/// This object represents the data to be requested.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PassportScope {
+    /// List of requested elements, each type may be used only once in the entire
+    /// array of PassportScopeElement objects
+    pub data: Vec<PassportScopeElement>,
+    /// Scope version, must be 1
+    pub v: i64,
+}
+
+
+/// This object represents a requested element, should be one of:
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PassportScopeElement {
+    PassportScopeElementOneOfSeveral(PassportScopeElementOneOfSeveral),
+    PassportScopeElementOne(PassportScopeElementOne),
+}
+
+
+/// This object represents several elements one of which must be provided.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PassportScopeElementOneOfSeveral {
+    /// List of elements one of which must be provided; must contain either several
+    /// of “passport”, “driver_license”, “identity_card”, “internal_passport” or
+    /// several of “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    pub one_of: Vec<PassportScopeElementOne>,
+    /// Optional. Use this parameter if you want to request a selfie with the
+    /// document from this list that the user chooses to upload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<bool>,
+    /// Optional. Use this parameter if you want to request a translation of the
+    /// document from this list that the user chooses to upload. Note: We suggest to
+    /// only request translations after you have received a valid document that
+    /// requires one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<bool>,
+}
+
+
+/// This object represents one particular element that must be provided. If no
+/// options are needed, String can be used instead of this object to specify the
+/// type of the element.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PassportScopeElementOne {
+    /// Element type. One of “personal_details”, “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport”, “address”, “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration”,
+    /// “temporary_registration”, “phone_number”, “email”
+    #[serde(rename = "type")]
+    pub ty: PassportElementType,
+    /// Optional. Use this parameter if you want to request a selfie with the
+    /// document as well. Available for “passport”, “driver_license”,
+    /// “identity_card” and “internal_passport”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<bool>,
+    /// Optional. Use this parameter if you want to request a translation of the
+    /// document as well. Available for “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”,
+    /// “rental_agreement”, “passport_registration” and “temporary_registration”.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<bool>,
+    /// Optional. Use this parameter to request the first, last and middle name of
+    /// the user in the language of the user's country of residence. Available for
+    /// “personal_details”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native_names: Option<bool>,
+}
+
+
+impl PassportScope {
+    /// Creates a version 1 scope requesting the given elements.
+    pub fn new(data: Vec<PassportScopeElement>) -> Self {
+        PassportScope { data, v: 1 }
+    }
+
+    /// Checks the scope against the rules of the Telegram Passport documentation.
+    /// Element types unknown to this version of the models are let through, but
+    /// without options, and not in a `one_of`.
+    pub fn validate(&self) -> Result<(), ScopeError> {
+        if self.v != 1 {
+            return Err(ScopeError::UnsupportedVersion(self.v));
+        }
+        if self.data.is_empty() {
+            return Err(ScopeError::Empty);
+        }
+        let mut types: Vec<&PassportElementType> = Vec::new();
+        for element in &self.data {
+            match element {
+                PassportScopeElement::PassportScopeElementOne(one) => {
+                    one.validate()?;
+                    types.push(&one.ty);
+                }
+                PassportScopeElement::PassportScopeElementOneOfSeveral(several) => {
+                    several.validate()?;
+                    types.extend(several.one_of.iter().map(|one| &one.ty));
+                }
+            }
+        }
+        for (i, ty) in types.iter().enumerate() {
+            if types[..i].contains(ty) {
+                return Err(ScopeError::DuplicateType((*ty).clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+
+impl PassportScopeElementOne {
+    /// Requests an element of the given type without any options.
+    pub fn new(ty: PassportElementType) -> Self {
+        PassportScopeElementOne {
+            ty,
+            selfie: None,
+            translation: None,
+            native_names: None,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ScopeError> {
+        let ty = &self.ty;
+        if self.selfie == Some(true) && !ty.has_selfie() {
+            return Err(ScopeError::OptionNotAvailable { ty: ty.clone(), option: "selfie" });
+        }
+        if self.translation == Some(true) && !ty.has_translation() {
+            return Err(ScopeError::OptionNotAvailable { ty: ty.clone(), option: "translation" });
+        }
+        if self.native_names == Some(true) && *ty != PassportElementType::PersonalDetails {
+            return Err(ScopeError::OptionNotAvailable { ty: ty.clone(), option: "native_names" });
+        }
+        Ok(())
+    }
+}
+
+
+impl From<PassportScopeElementOne> for PassportScopeElement {
+    fn from(one: PassportScopeElementOne) -> Self {
+        PassportScopeElement::PassportScopeElementOne(one)
+    }
+}
+
+
+impl PassportScopeElementOneOfSeveral {
+    /// Requests one of the given documents, chosen by the user.
+    pub fn new(one_of: Vec<PassportScopeElementOne>) -> Self {
+        PassportScopeElementOneOfSeveral {
+            one_of,
+            selfie: None,
+            translation: None,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ScopeError> {
+        let first = match &self.one_of[..] {
+            [] => return Err(ScopeError::EmptyOneOf),
+            [one] => return Err(ScopeError::SingleOneOf(one.ty.clone())),
+            [first, ..] => first,
+        };
+        let group = if first.ty.is_identity_document() {
+            PassportElementType::is_identity_document
+        } else {
+            PassportElementType::is_address_document
+        };
+        for one in &self.one_of {
+            one.validate()?;
+            if self.translation == Some(true) && !one.ty.has_translation() {
+                return Err(ScopeError::OptionNotAvailable { ty: one.ty.clone(), option: "translation" });
+            }
+            if !group(&one.ty) {
+                return Err(ScopeError::MixedOneOf(one.ty.clone()));
+            }
+        }
+        if self.selfie == Some(true) && !first.ty.has_selfie() {
+            return Err(ScopeError::OptionNotAvailable { ty: first.ty.clone(), option: "selfie" });
+        }
+        Ok(())
+    }
+}
+
+
+impl From<PassportScopeElementOneOfSeveral> for PassportScopeElement {
+    fn from(several: PassportScopeElementOneOfSeveral) -> Self {
+        PassportScopeElement::PassportScopeElementOneOfSeveral(several)
+    }
+}
+
+
+/// Reasons for a Telegram Passport authorization request to be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeError {
+    /// The scope version is not 1.
+    UnsupportedVersion(i64),
+    /// The scope requests nothing.
+    Empty,
+    /// A `PassportScopeElementOneOfSeveral` lists no elements.
+    EmptyOneOf,
+    /// A `PassportScopeElementOneOfSeveral` lists a single element, which should
+    /// be requested as a `PassportScopeElementOne` instead.
+    SingleOneOf(PassportElementType),
+    /// The element type is requested more than once.
+    DuplicateType(PassportElementType),
+    /// A `PassportScopeElementOneOfSeveral` mixes identity documents with proofs of
+    /// address or lists an element that is not a document.
+    MixedOneOf(PassportElementType),
+    /// The option is not available for the element type.
+    OptionNotAvailable {
+        ty: PassportElementType,
+        option: &'static str,
+    },
+    /// The nonce is empty.
+    EmptyNonce,
+    /// The public key is not a PEM-encoded key.
+    InvalidPublicKey,
+}
+
+impl fmt::Display for ScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScopeError::UnsupportedVersion(v) => write!(f, "unsupported scope version {}", v),
+            ScopeError::Empty => f.write_str("scope requests no elements"),
+            ScopeError::EmptyOneOf => f.write_str("one_of lists no elements"),
+            ScopeError::SingleOneOf(ty) => write!(f, "one_of lists “{}” alone, it needs several elements", ty),
+            ScopeError::DuplicateType(ty) => write!(f, "element type “{}” is requested twice", ty),
+            ScopeError::MixedOneOf(ty) => write!(f, "element type “{}” does not belong in this one_of", ty),
+            ScopeError::OptionNotAvailable { ty, option } => {
+                write!(f, "{} is not available for “{}”", option, ty)
+            }
+            ScopeError::EmptyNonce => f.write_str("nonce must not be empty"),
+            ScopeError::InvalidPublicKey => f.write_str("public key must be PEM-encoded"),
+        }
+    }
+}
+
+impl Error for ScopeError {}
+
+
+/// Builder of the `tg://resolve?domain=telegrampassport` link that asks a user to
+/// share Telegram Passport data with the bot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassportRequest {
+    bot_id: i64,
+    scope: PassportScope,
+    public_key: String,
+    nonce: String,
+    callback_url: Option<String>,
+}
+
+impl PassportRequest {
+    /// Creates a request. `nonce` is the bot-specified payload returned in
+    /// `Credentials::nonce`, `public_key` is the PEM-encoded public key of the bot.
+    pub fn new<K, N>(bot_id: i64, scope: PassportScope, public_key: K, nonce: N) -> Self
+    where
+        K: Into<String>,
+        N: Into<String>,
+    {
+        PassportRequest {
+            bot_id,
+            scope,
+            public_key: public_key.into(),
+            nonce: nonce.into(),
+            callback_url: None,
+        }
+    }
+
+    /// Sets the URL the user is redirected to after sharing the data or cancelling.
+    pub fn callback_url<S: Into<String>>(mut self, callback_url: S) -> Self {
+        self.callback_url = Some(callback_url.into());
+        self
+    }
+
+    /// Validates the request and generates the deep link.
+    pub fn link(&self) -> Result<String, ScopeError> {
+        self.scope.validate()?;
+        if self.nonce.is_empty() {
+            return Err(ScopeError::EmptyNonce);
+        }
+        let key = self.public_key.trim();
+        if !key.starts_with("-----BEGIN ") || !key.ends_with("-----") {
+            return Err(ScopeError::InvalidPublicKey);
+        }
+        let scope = serde_json::to_string(&self.scope).expect("scope is always serializable");
+        let mut link = format!(
+            "tg://resolve?domain=telegrampassport&bot_id={}&scope={}&public_key={}&nonce={}",
+            self.bot_id,
+            percent_encode(&scope),
+            percent_encode(key),
+            percent_encode(&self.nonce),
+        );
+        if let Some(ref url) = self.callback_url {
+            link.push_str("&callback_url=");
+            link.push_str(&percent_encode(url));
+        }
+        Ok(link)
+    }
+}
+
+
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(byte as char),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
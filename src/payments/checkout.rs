@@ -0,0 +1,344 @@
+//! Tracking of orders through the payment flow.
+//!
+//! An order starts with `SendInvoice`, may go through a `ShippingQuery` (flexible
+//! invoices only) and a `PreCheckoutQuery`, and ends with a `SuccessfulPayment`
+//! in `Message::successful_payment`. The stages are correlated by the invoice
+//! payload, which therefore must be unique among the orders in progress.
+//!
+//! The Bot API expects shipping and pre-checkout queries to be answered within
+//! 10 seconds, so the resolvers passed to `Checkout` should not block for long.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use super::currency::Currency;
+use super::invoice::InvoiceError;
+use super::money::{Money, MoneyError};
+use crate::{AnswerPreCheckoutQuery, AnswerShippingQuery, LabeledPrice, Message, OrderInfo,
+            PreCheckoutQuery, SendInvoice, ShippingAddress, ShippingOption, ShippingQuery,
+            SuccessfulPayment, User};
+
+
+/// Stage of an order in the payment flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderStage {
+    /// The invoice was issued.
+    Invoiced,
+    /// Shipping options were offered for the user's address.
+    ShippingQuoted,
+    /// The pre-checkout query was approved, the payment is in progress.
+    Approved,
+}
+
+
+/// An order in progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    /// Bot-defined invoice payload identifying the order
+    pub payload: String,
+    /// Currency of the invoice
+    pub currency: Currency,
+    /// Price breakdown of the invoice
+    pub prices: Vec<LabeledPrice>,
+    /// True, if the final price depends on the shipping method
+    pub is_flexible: bool,
+    /// Current stage of the order
+    pub stage: OrderStage,
+    /// User who is paying, known after the first query
+    pub user: Option<User>,
+    /// Shipping address from the last shipping query
+    pub shipping_address: Option<ShippingAddress>,
+    /// Shipping options offered for the shipping address
+    pub shipping_options: Vec<ShippingOption>,
+    /// Maximum accepted amount for tips in the smallest units of the currency, 0
+    /// if the invoice accepts no tips
+    pub max_tip_amount: i64,
+}
+
+impl Order {
+    /// Price of the order with the given shipping option.
+    pub fn total(&self, shipping_option_id: Option<&str>) -> Result<Money, CheckoutError> {
+        let mut total = Money::total(&self.prices, self.currency).map_err(CheckoutError::Money)?;
+        if let Some(id) = shipping_option_id {
+            let option = self
+                .shipping_options
+                .iter()
+                .find(|option| option.id == id)
+                .ok_or_else(|| CheckoutError::UnknownShippingOption(id.to_string()))?;
+            let shipping = Money::total(&option.prices, self.currency).map_err(CheckoutError::Money)?;
+            total = total.checked_add(shipping).map_err(CheckoutError::Money)?;
+        }
+        Ok(total)
+    }
+
+    /// Tip included in `amount` of `currency` paid with the given shipping
+    /// option. The amount must be the price of the order plus a tip of at most
+    /// `max_tip_amount`.
+    pub fn tip(&self, shipping_option_id: Option<&str>, currency: &str, amount: i64) -> Result<Money, CheckoutError> {
+        let expected = self.total(shipping_option_id)?;
+        let tip = amount.saturating_sub(expected.amount());
+        if expected.currency().code() != currency || !(0..=self.max_tip_amount).contains(&tip) {
+            return Err(CheckoutError::AmountMismatch {
+                expected,
+                actual: amount,
+                currency: currency.to_string(),
+            });
+        }
+        Ok(Money::new(tip, self.currency))
+    }
+}
+
+
+/// A completed payment, reconciled with the order it pays for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentRecord {
+    /// Bot-defined invoice payload identifying the order
+    pub payload: String,
+    /// User who paid, if known
+    pub user: Option<User>,
+    /// Total amount paid, tip included
+    pub total: Money,
+    /// Tip added by the user
+    pub tip: Money,
+    /// Price breakdown of the invoice
+    pub prices: Vec<LabeledPrice>,
+    /// Shipping option chosen by the user
+    pub shipping_option: Option<ShippingOption>,
+    /// Order info provided by the user
+    pub order_info: Option<OrderInfo>,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+}
+
+
+/// Errors of the payment flow.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckoutError {
+    /// The invoice does not pass `SendInvoice::check_invoice`.
+    InvalidInvoice(InvoiceError),
+    /// An order with this payload is already in progress.
+    DuplicatePayload(String),
+    /// No order in progress has this payload.
+    UnknownPayload(String),
+    /// The order is not at a stage where this step is possible.
+    UnexpectedStage { payload: String, stage: OrderStage },
+    /// The shipping option was not offered for the order.
+    UnknownShippingOption(String),
+    /// The amount paid or about to be paid differs from the price of the order,
+    /// beyond the accepted tip.
+    AmountMismatch { expected: Money, actual: i64, currency: String },
+    /// The price of the order can't be computed.
+    Money(MoneyError),
+}
+
+impl fmt::Display for CheckoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckoutError::InvalidInvoice(e) => write!(f, "invalid invoice: {}", e),
+            CheckoutError::DuplicatePayload(p) => write!(f, "order “{}” is already in progress", p),
+            CheckoutError::UnknownPayload(p) => write!(f, "no order “{}” in progress", p),
+            CheckoutError::UnexpectedStage { payload, stage } => {
+                write!(f, "order “{}” is at unexpected stage {:?}", payload, stage)
+            }
+            CheckoutError::UnknownShippingOption(id) => write!(f, "shipping option “{}” was not offered", id),
+            CheckoutError::AmountMismatch { expected, actual, currency } => {
+                write!(f, "expected {}, got {} {}", expected, actual, currency)
+            }
+            CheckoutError::Money(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for CheckoutError {}
+
+
+/// Computes the shipping options available for a shipping address. An `Err`
+/// carries the message shown to the user when delivery is impossible.
+pub trait ShippingResolver {
+    fn shipping_options(&self, order: &Order, address: &ShippingAddress) -> Result<Vec<ShippingOption>, String>;
+}
+
+impl<F> ShippingResolver for F
+where
+    F: Fn(&Order, &ShippingAddress) -> Result<Vec<ShippingOption>, String>,
+{
+    fn shipping_options(&self, order: &Order, address: &ShippingAddress) -> Result<Vec<ShippingOption>, String> {
+        self(order, address)
+    }
+}
+
+
+/// Checks that the goods are still available right before the payment. An `Err`
+/// carries the message shown to the user.
+pub trait AvailabilityCheck {
+    fn check(&self, order: &Order, query: &PreCheckoutQuery) -> Result<(), String>;
+}
+
+impl<F> AvailabilityCheck for F
+where
+    F: Fn(&Order, &PreCheckoutQuery) -> Result<(), String>,
+{
+    fn check(&self, order: &Order, query: &PreCheckoutQuery) -> Result<(), String> {
+        self(order, query)
+    }
+}
+
+
+/// Orders in progress, keyed by invoice payload.
+pub struct Checkout<S, A> {
+    orders: HashMap<String, Order>,
+    shipping: S,
+    availability: A,
+    /// Message shown to the user when a query can't be matched with an order in
+    /// progress, e.g. after a restart of the bot.
+    pub failure_message: String,
+}
+
+impl<S: ShippingResolver, A: AvailabilityCheck> Checkout<S, A> {
+    /// Creates an empty checkout with the given shipping resolver and availability
+    /// check.
+    pub fn new(shipping: S, availability: A) -> Self {
+        Checkout {
+            orders: HashMap::new(),
+            shipping,
+            availability,
+            failure_message: "Sorry, this order can no longer be completed.".to_string(),
+        }
+    }
+
+    /// The order with the given payload, if in progress.
+    pub fn order(&self, payload: &str) -> Option<&Order> {
+        self.orders.get(payload)
+    }
+
+    /// Iterates over the orders in progress.
+    pub fn orders(&self) -> impl Iterator<Item = &Order> {
+        self.orders.values()
+    }
+
+    /// Drops the order with the given payload, e.g. when the invoice expires.
+    pub fn cancel(&mut self, payload: &str) -> Option<Order> {
+        self.orders.remove(payload)
+    }
+
+    /// Validates the invoice and starts tracking its order. Returns the request to
+    /// send.
+    pub fn invoice(&mut self, invoice: SendInvoice) -> Result<SendInvoice, CheckoutError> {
+        invoice.check_invoice().map_err(CheckoutError::InvalidInvoice)?;
+        if self.orders.contains_key(&invoice.payload) {
+            return Err(CheckoutError::DuplicatePayload(invoice.payload));
+        }
+        let currency = invoice.currency.parse().map_err(|e| CheckoutError::Money(MoneyError::from(e)))?;
+        // Flexible pricing is ignored for payments in Telegram Stars.
+        let is_flexible = invoice.is_flexible == Some(true) && currency != Currency::Xtr;
+        let order = Order {
+            payload: invoice.payload.clone(),
+            currency,
+            prices: invoice.prices.clone(),
+            is_flexible,
+            stage: OrderStage::Invoiced,
+            user: None,
+            shipping_address: None,
+            shipping_options: Vec::new(),
+            max_tip_amount: invoice.max_tip_amount.unwrap_or(0),
+        };
+        self.orders.insert(order.payload.clone(), order);
+        Ok(invoice)
+    }
+
+    /// Answers a shipping query with the options of the shipping resolver.
+    pub fn shipping_query(&mut self, query: &ShippingQuery) -> AnswerShippingQuery {
+        match self.quote(query) {
+            Ok(options) => query.answer(options),
+            Err(message) => query.reject(message),
+        }
+    }
+
+    fn quote(&mut self, query: &ShippingQuery) -> Result<Vec<ShippingOption>, String> {
+        let order = match self.orders.get_mut(&query.invoice_payload) {
+            Some(order) if order.is_flexible && order.stage != OrderStage::Approved => order,
+            _ => return Err(self.failure_message.clone()),
+        };
+        let options = self.shipping.shipping_options(order, &query.shipping_address)?;
+        order.stage = OrderStage::ShippingQuoted;
+        order.user = Some(query.from.clone());
+        order.shipping_address = Some(query.shipping_address.clone());
+        order.shipping_options = options.clone();
+        Ok(options)
+    }
+
+    /// Answers a pre-checkout query. The query is approved if it matches the
+    /// order in progress, i.e. the currency, the total price give or take the
+    /// accepted tip and the shipping option are the expected ones, and the
+    /// availability check passes.
+    pub fn pre_checkout_query(&mut self, query: &PreCheckoutQuery) -> AnswerPreCheckoutQuery {
+        match self.approve(query) {
+            Ok(()) => query.approve(),
+            Err(message) => query.reject(message),
+        }
+    }
+
+    fn approve(&mut self, query: &PreCheckoutQuery) -> Result<(), String> {
+        let failure = self.failure_message.clone();
+        let order = self.orders.get_mut(&query.invoice_payload).ok_or_else(|| failure.clone())?;
+        if order.is_flexible != query.shipping_option_id.is_some() {
+            return Err(failure);
+        }
+        if order.tip(query.shipping_option_id.as_deref(), &query.currency, query.total_amount).is_err() {
+            return Err(failure);
+        }
+        self.availability.check(order, query)?;
+        order.stage = OrderStage::Approved;
+        order.user = Some(query.from.clone());
+        Ok(())
+    }
+
+    /// Completes the order paid by `payment` and returns the reconciled record.
+    pub fn successful_payment(&mut self, payment: &SuccessfulPayment) -> Result<PaymentRecord, CheckoutError> {
+        let payload = &payment.invoice_payload;
+        let order = self
+            .orders
+            .get(payload)
+            .ok_or_else(|| CheckoutError::UnknownPayload(payload.clone()))?;
+        if order.stage != OrderStage::Approved {
+            return Err(CheckoutError::UnexpectedStage {
+                payload: payload.clone(),
+                stage: order.stage,
+            });
+        }
+        let tip = order.tip(payment.shipping_option_id.as_deref(), &payment.currency, payment.total_amount)?;
+        let order = self.orders.remove(payload).expect("order is in progress");
+        let shipping_option = payment
+            .shipping_option_id
+            .as_ref()
+            .and_then(|id| order.shipping_options.iter().find(|option| &option.id == id))
+            .cloned();
+        Ok(PaymentRecord {
+            payload: order.payload,
+            user: order.user,
+            total: Money::new(payment.total_amount, order.currency),
+            tip,
+            prices: order.prices,
+            shipping_option,
+            order_info: payment.order_info.clone(),
+            telegram_payment_charge_id: payment.telegram_payment_charge_id.clone(),
+            provider_payment_charge_id: payment.provider_payment_charge_id.clone(),
+        })
+    }
+
+    /// Completes the order paid in the message, if it is a successful payment
+    /// service message.
+    pub fn message(&mut self, message: &Message) -> Option<Result<PaymentRecord, CheckoutError>> {
+        let payment = message.successful_payment.as_ref()?;
+        let mut record = self.successful_payment(payment);
+        if let Ok(ref mut record) = record {
+            if record.user.is_none() {
+                record.user = message.from.clone();
+            }
+        }
+        Some(record)
+    }
+}
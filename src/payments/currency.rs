@@ -0,0 +1,340 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+
+macro_rules! currencies {
+    ($($variant:ident => $code:expr, $exp:expr, $title:expr;)*) => {
+        /// ISO 4217 currency or Telegram Stars, as used by `currency` fields of the
+        /// payment objects.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Currency {
+            $(
+                #[doc = $title]
+                $variant,
+            )*
+        }
+
+        impl Currency {
+            /// All the supported currencies, sorted by code.
+            pub const ALL: &'static [Currency] = &[$(Currency::$variant),*];
+
+            /// Three-letter ISO 4217 code, e.g. “USD”.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Currency::$variant => $code,)*
+                }
+            }
+
+            /// Number of digits past the decimal point, i.e. the amount of smallest
+            /// units of the currency in one unit is `10.pow(exponent)`.
+            pub fn exponent(self) -> u32 {
+                match self {
+                    $(Currency::$variant => $exp,)*
+                }
+            }
+
+            /// English name of the currency.
+            pub fn title(self) -> &'static str {
+                match self {
+                    $(Currency::$variant => $title,)*
+                }
+            }
+
+            /// Looks the currency up by its ISO 4217 code.
+            pub fn from_code(code: &str) -> Option<Currency> {
+                match code {
+                    $($code => Some(Currency::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+currencies! {
+    Aed => "AED", 2, "UAE Dirham";
+    Afn => "AFN", 2, "Afghani";
+    All => "ALL", 2, "Lek";
+    Amd => "AMD", 2, "Armenian Dram";
+    Ang => "ANG", 2, "Netherlands Antillean Guilder";
+    Aoa => "AOA", 2, "Kwanza";
+    Ars => "ARS", 2, "Argentine Peso";
+    Aud => "AUD", 2, "Australian Dollar";
+    Awg => "AWG", 2, "Aruban Florin";
+    Azn => "AZN", 2, "Azerbaijan Manat";
+    Bam => "BAM", 2, "Convertible Mark";
+    Bbd => "BBD", 2, "Barbados Dollar";
+    Bdt => "BDT", 2, "Taka";
+    Bgn => "BGN", 2, "Bulgarian Lev";
+    Bhd => "BHD", 3, "Bahraini Dinar";
+    Bif => "BIF", 0, "Burundi Franc";
+    Bmd => "BMD", 2, "Bermudian Dollar";
+    Bnd => "BND", 2, "Brunei Dollar";
+    Bob => "BOB", 2, "Boliviano";
+    Bov => "BOV", 2, "Mvdol";
+    Brl => "BRL", 2, "Brazilian Real";
+    Bsd => "BSD", 2, "Bahamian Dollar";
+    Btn => "BTN", 2, "Ngultrum";
+    Bwp => "BWP", 2, "Pula";
+    Byn => "BYN", 2, "Belarusian Ruble";
+    Bzd => "BZD", 2, "Belize Dollar";
+    Cad => "CAD", 2, "Canadian Dollar";
+    Cdf => "CDF", 2, "Congolese Franc";
+    Che => "CHE", 2, "WIR Euro";
+    Chf => "CHF", 2, "Swiss Franc";
+    Chw => "CHW", 2, "WIR Franc";
+    Clf => "CLF", 4, "Unidad de Fomento";
+    Clp => "CLP", 0, "Chilean Peso";
+    Cny => "CNY", 2, "Yuan Renminbi";
+    Cop => "COP", 2, "Colombian Peso";
+    Cou => "COU", 2, "Unidad de Valor Real";
+    Crc => "CRC", 2, "Costa Rican Colon";
+    Cup => "CUP", 2, "Cuban Peso";
+    Cve => "CVE", 2, "Cabo Verde Escudo";
+    Czk => "CZK", 2, "Czech Koruna";
+    Djf => "DJF", 0, "Djibouti Franc";
+    Dkk => "DKK", 2, "Danish Krone";
+    Dop => "DOP", 2, "Dominican Peso";
+    Dzd => "DZD", 2, "Algerian Dinar";
+    Egp => "EGP", 2, "Egyptian Pound";
+    Ern => "ERN", 2, "Nakfa";
+    Etb => "ETB", 2, "Ethiopian Birr";
+    Eur => "EUR", 2, "Euro";
+    Fjd => "FJD", 2, "Fiji Dollar";
+    Fkp => "FKP", 2, "Falkland Islands Pound";
+    Gbp => "GBP", 2, "Pound Sterling";
+    Gel => "GEL", 2, "Lari";
+    Ghs => "GHS", 2, "Ghana Cedi";
+    Gip => "GIP", 2, "Gibraltar Pound";
+    Gmd => "GMD", 2, "Dalasi";
+    Gnf => "GNF", 0, "Guinean Franc";
+    Gtq => "GTQ", 2, "Quetzal";
+    Gyd => "GYD", 2, "Guyana Dollar";
+    Hkd => "HKD", 2, "Hong Kong Dollar";
+    Hnl => "HNL", 2, "Lempira";
+    Htg => "HTG", 2, "Gourde";
+    Huf => "HUF", 2, "Forint";
+    Idr => "IDR", 2, "Rupiah";
+    Ils => "ILS", 2, "New Israeli Sheqel";
+    Inr => "INR", 2, "Indian Rupee";
+    Iqd => "IQD", 3, "Iraqi Dinar";
+    Irr => "IRR", 2, "Iranian Rial";
+    Isk => "ISK", 0, "Iceland Krona";
+    Jmd => "JMD", 2, "Jamaican Dollar";
+    Jod => "JOD", 3, "Jordanian Dinar";
+    Jpy => "JPY", 0, "Yen";
+    Kes => "KES", 2, "Kenyan Shilling";
+    Kgs => "KGS", 2, "Som";
+    Khr => "KHR", 2, "Riel";
+    Kmf => "KMF", 0, "Comorian Franc";
+    Kpw => "KPW", 2, "North Korean Won";
+    Krw => "KRW", 0, "Won";
+    Kwd => "KWD", 3, "Kuwaiti Dinar";
+    Kyd => "KYD", 2, "Cayman Islands Dollar";
+    Kzt => "KZT", 2, "Tenge";
+    Lak => "LAK", 2, "Lao Kip";
+    Lbp => "LBP", 2, "Lebanese Pound";
+    Lkr => "LKR", 2, "Sri Lanka Rupee";
+    Lrd => "LRD", 2, "Liberian Dollar";
+    Lsl => "LSL", 2, "Loti";
+    Lyd => "LYD", 3, "Libyan Dinar";
+    Mad => "MAD", 2, "Moroccan Dirham";
+    Mdl => "MDL", 2, "Moldovan Leu";
+    Mga => "MGA", 2, "Malagasy Ariary";
+    Mkd => "MKD", 2, "Denar";
+    Mmk => "MMK", 2, "Kyat";
+    Mnt => "MNT", 2, "Tugrik";
+    Mop => "MOP", 2, "Pataca";
+    Mru => "MRU", 2, "Ouguiya";
+    Mur => "MUR", 2, "Mauritius Rupee";
+    Mvr => "MVR", 2, "Rufiyaa";
+    Mwk => "MWK", 2, "Malawi Kwacha";
+    Mxn => "MXN", 2, "Mexican Peso";
+    Mxv => "MXV", 2, "Mexican Unidad de Inversion (UDI)";
+    Myr => "MYR", 2, "Malaysian Ringgit";
+    Mzn => "MZN", 2, "Mozambique Metical";
+    Nad => "NAD", 2, "Namibia Dollar";
+    Ngn => "NGN", 2, "Naira";
+    Nio => "NIO", 2, "Cordoba Oro";
+    Nok => "NOK", 2, "Norwegian Krone";
+    Npr => "NPR", 2, "Nepalese Rupee";
+    Nzd => "NZD", 2, "New Zealand Dollar";
+    Omr => "OMR", 3, "Rial Omani";
+    Pab => "PAB", 2, "Balboa";
+    Pen => "PEN", 2, "Sol";
+    Pgk => "PGK", 2, "Kina";
+    Php => "PHP", 2, "Philippine Peso";
+    Pkr => "PKR", 2, "Pakistan Rupee";
+    Pln => "PLN", 2, "Zloty";
+    Pyg => "PYG", 0, "Guarani";
+    Qar => "QAR", 2, "Qatari Rial";
+    Ron => "RON", 2, "Romanian Leu";
+    Rsd => "RSD", 2, "Serbian Dinar";
+    Rub => "RUB", 2, "Russian Ruble";
+    Rwf => "RWF", 0, "Rwanda Franc";
+    Sar => "SAR", 2, "Saudi Riyal";
+    Sbd => "SBD", 2, "Solomon Islands Dollar";
+    Scr => "SCR", 2, "Seychelles Rupee";
+    Sdg => "SDG", 2, "Sudanese Pound";
+    Sek => "SEK", 2, "Swedish Krona";
+    Sgd => "SGD", 2, "Singapore Dollar";
+    Shp => "SHP", 2, "Saint Helena Pound";
+    Sle => "SLE", 2, "Leone";
+    Sos => "SOS", 2, "Somali Shilling";
+    Srd => "SRD", 2, "Surinam Dollar";
+    Ssp => "SSP", 2, "South Sudanese Pound";
+    Stn => "STN", 2, "Dobra";
+    Svc => "SVC", 2, "El Salvador Colon";
+    Syp => "SYP", 2, "Syrian Pound";
+    Szl => "SZL", 2, "Lilangeni";
+    Thb => "THB", 2, "Baht";
+    Tjs => "TJS", 2, "Somoni";
+    Tmt => "TMT", 2, "Turkmenistan New Manat";
+    Tnd => "TND", 3, "Tunisian Dinar";
+    Top => "TOP", 2, "Pa’anga";
+    Try => "TRY", 2, "Turkish Lira";
+    Ttd => "TTD", 2, "Trinidad and Tobago Dollar";
+    Twd => "TWD", 2, "New Taiwan Dollar";
+    Tzs => "TZS", 2, "Tanzanian Shilling";
+    Uah => "UAH", 2, "Hryvnia";
+    Ugx => "UGX", 0, "Uganda Shilling";
+    Usd => "USD", 2, "US Dollar";
+    Usn => "USN", 2, "US Dollar (Next day)";
+    Uyi => "UYI", 0, "Uruguay Peso en Unidades Indexadas (UI)";
+    Uyu => "UYU", 2, "Peso Uruguayo";
+    Uyw => "UYW", 4, "Unidad Previsional";
+    Uzs => "UZS", 2, "Uzbekistan Sum";
+    Ves => "VES", 2, "Bolívar Soberano";
+    Vnd => "VND", 0, "Dong";
+    Vuv => "VUV", 0, "Vatu";
+    Wst => "WST", 2, "Tala";
+    Xaf => "XAF", 0, "CFA Franc BEAC";
+    Xcd => "XCD", 2, "East Caribbean Dollar";
+    Xof => "XOF", 0, "CFA Franc BCEAO";
+    Xpf => "XPF", 0, "CFP Franc";
+    Xtr => "XTR", 0, "Telegram Stars";
+    Yer => "YER", 2, "Yemeni Rial";
+    Zar => "ZAR", 2, "Rand";
+    Zmw => "ZMW", 2, "Zambian Kwacha";
+    Zwl => "ZWL", 2, "Zimbabwe Dollar";
+}
+
+
+/// How amounts of a currency are rendered by Telegram clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrencyFormat {
+    /// Symbol printed next to the amount, e.g. “$”
+    pub symbol: &'static str,
+    /// True, if the symbol goes before the amount
+    pub symbol_left: bool,
+    /// True, if the symbol is separated from the amount by a space
+    pub space_between: bool,
+    /// Separator of the groups of thousands
+    pub thousands_sep: &'static str,
+    /// Separator of the fractional part
+    pub decimal_sep: &'static str,
+}
+
+impl Currency {
+    /// Formatting conventions of the currency, following Telegram's
+    /// `currencies.json` for the most used currencies. Other currencies are
+    /// rendered as the ISO code followed by the amount, e.g. “AED 1,234.50”.
+    pub fn format(self) -> CurrencyFormat {
+        let left = |symbol, space_between| CurrencyFormat {
+            symbol,
+            symbol_left: true,
+            space_between,
+            thousands_sep: ",",
+            decimal_sep: ".",
+        };
+        match self {
+            Currency::Usd => left("$", false),
+            Currency::Gbp => left("£", false),
+            Currency::Jpy => left("¥", false),
+            Currency::Cny => left("CN¥", false),
+            Currency::Inr => left("₹", false),
+            Currency::Krw => left("₩", false),
+            Currency::Cad => left("CA$", false),
+            Currency::Aud => left("AU$", false),
+            Currency::Hkd => left("HK$", false),
+            Currency::Nzd => left("NZ$", false),
+            Currency::Mxn => left("MX$", false),
+            Currency::Xtr => left("⭐", true),
+            Currency::Brl => CurrencyFormat {
+                thousands_sep: ".",
+                decimal_sep: ",",
+                ..left("R$", true)
+            },
+            Currency::Chf => CurrencyFormat {
+                thousands_sep: "'",
+                ..left("CHF", true)
+            },
+            Currency::Eur => CurrencyFormat {
+                symbol: "€",
+                symbol_left: false,
+                space_between: true,
+                thousands_sep: " ",
+                decimal_sep: ",",
+            },
+            Currency::Rub => CurrencyFormat {
+                symbol: "RUB",
+                symbol_left: false,
+                space_between: true,
+                thousands_sep: " ",
+                decimal_sep: ",",
+            },
+            Currency::Pln => CurrencyFormat {
+                symbol: "zł",
+                symbol_left: false,
+                space_between: true,
+                thousands_sep: " ",
+                decimal_sep: ",",
+            },
+            other => left(other.code(), true),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+
+/// Error returned when parsing an unknown currency code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCurrency(pub String);
+
+impl fmt::Display for UnknownCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown currency “{}”", self.0)
+    }
+}
+
+impl Error for UnknownCurrency {}
+
+impl FromStr for Currency {
+    type Err = UnknownCurrency;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::from_code(s).ok_or_else(|| UnknownCurrency(s.to_string()))
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(de::Error::custom)
+    }
+}
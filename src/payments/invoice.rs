@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+
+use super::currency::Currency;
+use super::money::{Money, MoneyError};
+use crate::validate::{Rule, ValidationError};
+use crate::SendInvoice;
+
+
+/// Reasons for a `SendInvoice` request to be rejected by the Bot API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvoiceError {
+    /// The title is not 1-32 characters long.
+    TitleLength(usize),
+    /// The description is not 1-255 characters long.
+    DescriptionLength(usize),
+    /// The payload is not 1-128 bytes long.
+    PayloadLength(usize),
+    /// The currency is not an ISO 4217 code.
+    UnknownCurrency(String),
+    /// The price breakdown is empty.
+    NoPrices,
+    /// An invoice in Telegram Stars has more than one price portion.
+    StarsPriceCount(usize),
+    /// A payment provider token is missing for an invoice in a regular currency,
+    /// or given for an invoice in Telegram Stars.
+    ProviderToken,
+    /// The total price is zero or negative.
+    NonPositiveTotal(i64),
+    /// The total price does not fit in 64 bits.
+    TotalOverflow,
+    /// The invoice is flexible, but no shipping address is requested, so the bot
+    /// would never receive a shipping query to price the delivery.
+    FlexibleWithoutShippingAddress,
+    /// Photo dimensions are given without a photo URL.
+    PhotoWithoutUrl,
+}
+
+impl InvoiceError {
+    /// Name of the field of `SendInvoice` the error is about.
+    pub fn field(&self) -> &'static str {
+        match self {
+            InvoiceError::TitleLength(_) => "title",
+            InvoiceError::DescriptionLength(_) => "description",
+            InvoiceError::PayloadLength(_) => "payload",
+            InvoiceError::UnknownCurrency(_) => "currency",
+            InvoiceError::NoPrices
+            | InvoiceError::StarsPriceCount(_)
+            | InvoiceError::NonPositiveTotal(_)
+            | InvoiceError::TotalOverflow => "prices",
+            InvoiceError::ProviderToken => "provider_token",
+            InvoiceError::FlexibleWithoutShippingAddress => "is_flexible",
+            InvoiceError::PhotoWithoutUrl => "photo_url",
+        }
+    }
+}
+
+impl fmt::Display for InvoiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvoiceError::TitleLength(n) => write!(f, "title must be 1-32 characters, got {}", n),
+            InvoiceError::DescriptionLength(n) => write!(f, "description must be 1-255 characters, got {}", n),
+            InvoiceError::PayloadLength(n) => write!(f, "payload must be 1-128 bytes, got {}", n),
+            InvoiceError::UnknownCurrency(code) => write!(f, "unknown currency “{}”", code),
+            InvoiceError::NoPrices => f.write_str("prices must not be empty"),
+            InvoiceError::StarsPriceCount(n) => {
+                write!(f, "invoices in Telegram Stars must have exactly one price, got {}", n)
+            }
+            InvoiceError::ProviderToken => {
+                f.write_str("provider_token is required, except for invoices in Telegram Stars")
+            }
+            InvoiceError::NonPositiveTotal(total) => write!(f, "total price must be positive, got {}", total),
+            InvoiceError::TotalOverflow => f.write_str("total price overflows"),
+            InvoiceError::FlexibleWithoutShippingAddress => {
+                f.write_str("is_flexible requires need_shipping_address")
+            }
+            InvoiceError::PhotoWithoutUrl => f.write_str("photo size and dimensions require photo_url"),
+        }
+    }
+}
+
+impl Error for InvoiceError {}
+
+
+impl SendInvoice {
+    /// Total price of the invoice.
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        let currency = self.currency.parse::<Currency>()?;
+        Money::total(&self.prices, currency)
+    }
+
+    /// Checks the invoice against the limits of the Bot API documentation.
+    pub fn check_invoice(&self) -> Result<(), InvoiceError> {
+        let title = self.title.chars().count();
+        if !(1..=32).contains(&title) {
+            return Err(InvoiceError::TitleLength(title));
+        }
+        let description = self.description.chars().count();
+        if !(1..=255).contains(&description) {
+            return Err(InvoiceError::DescriptionLength(description));
+        }
+        if !(1..=128).contains(&self.payload.len()) {
+            return Err(InvoiceError::PayloadLength(self.payload.len()));
+        }
+        if self.prices.is_empty() {
+            return Err(InvoiceError::NoPrices);
+        }
+        let total = match self.total() {
+            Ok(total) if total.amount() <= 0 => return Err(InvoiceError::NonPositiveTotal(total.amount())),
+            Ok(total) => total,
+            Err(MoneyError::UnknownCurrency(code)) => return Err(InvoiceError::UnknownCurrency(code)),
+            Err(_) => return Err(InvoiceError::TotalOverflow),
+        };
+        let stars = total.currency() == Currency::Xtr;
+        if stars && self.prices.len() != 1 {
+            return Err(InvoiceError::StarsPriceCount(self.prices.len()));
+        }
+        let has_token = self.provider_token.as_ref().is_some_and(|token| !token.is_empty());
+        if stars == has_token {
+            return Err(InvoiceError::ProviderToken);
+        }
+        if !stars && self.is_flexible == Some(true) && self.need_shipping_address != Some(true) {
+            return Err(InvoiceError::FlexibleWithoutShippingAddress);
+        }
+        let has_photo_details = self.photo_size.is_some() || self.photo_width.is_some() || self.photo_height.is_some();
+        if has_photo_details && self.photo_url.is_none() {
+            return Err(InvoiceError::PhotoWithoutUrl);
+        }
+        Ok(())
+    }
+}
+
+/// The checks of `SendInvoice::check_invoice`, for its `Validate` impl.
+pub(crate) fn check_invoice(invoice: &SendInvoice) -> Result<(), ValidationError> {
+    invoice.check_invoice().map_err(|err| ValidationError::new(err.field(), Rule::Invoice(err)))
+}
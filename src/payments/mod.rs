@@ -0,0 +1,19 @@
+//! Payments helpers.
+//!
+//! Amounts in the payment objects are integers in the smallest units of the
+//! currency, whose number of decimal digits differs per currency. `Currency` and
+//! `Money` keep track of it, format amounts the way Telegram clients do and
+//! validate invoices before they are sent. `Checkout` tracks orders from the
+//! invoice to the successful payment.
+
+mod checkout;
+mod currency;
+mod invoice;
+mod money;
+
+pub use self::checkout::{AvailabilityCheck, Checkout, CheckoutError, Order, OrderStage, PaymentRecord,
+                         ShippingResolver};
+pub use self::currency::{Currency, CurrencyFormat, UnknownCurrency};
+pub use self::invoice::InvoiceError;
+pub(crate) use self::invoice::check_invoice;
+pub use self::money::{Money, MoneyError};
@@ -0,0 +1,195 @@
+use std::error::Error;
+use std::fmt;
+
+use super::currency::{Currency, UnknownCurrency};
+use crate::{Invoice, LabeledPrice, PreCheckoutQuery, SuccessfulPayment};
+
+
+/// An amount of money in the smallest units of its currency, the way the Bot API
+/// passes it around: US$ 1.45 is `Money::new(145, Currency::Usd)` and ¥145 is
+/// `Money::new(145, Currency::Jpy)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount from the smallest units of the currency.
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    /// Creates an amount from the three-letter currency code used by the Bot API.
+    pub fn from_code(amount: i64, currency: &str) -> Result<Self, MoneyError> {
+        let currency = currency.parse::<Currency>()?;
+        Ok(Money::new(amount, currency))
+    }
+
+    /// Parses a decimal amount in units of the currency, e.g. “12.5” for
+    /// US$ 12.50 or “1,234” for US$ 1234. More fractional digits than the
+    /// currency has are rejected.
+    pub fn parse(s: &str, currency: Currency) -> Result<Self, MoneyError> {
+        Money::parse_with(s, currency, '.')
+    }
+
+    /// Parses a decimal amount whose decimal separator is `decimal`, e.g. `,`
+    /// for “1.234,5”. The other of `.` and `,` is read as the thousands
+    /// separator, and must then be between groups of three digits.
+    pub fn parse_with(s: &str, currency: Currency, decimal: char) -> Result<Self, MoneyError> {
+        let invalid = || MoneyError::InvalidAmount(s.to_string());
+        let thousands = if decimal == ',' { '.' } else { ',' };
+        let trimmed = s.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let (whole, fraction) = digits.split_once(decimal).unwrap_or((digits, ""));
+        let mut groups = whole.split(thousands);
+        let first = groups.next().unwrap_or("");
+        let mut groups = groups.peekable();
+        if groups.peek().is_some() && (first.is_empty() || first.len() > 3 || groups.any(|group| group.len() != 3)) {
+            return Err(invalid());
+        }
+        let whole: String = whole.chars().filter(|&c| c != thousands).collect();
+        let exponent = currency.exponent() as usize;
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !all_digits(&whole) || !all_digits(fraction) || fraction.len() > exponent {
+            return Err(invalid());
+        }
+        let scale = 10i64.pow(currency.exponent());
+        let whole: i64 = whole.parse().map_err(|_| MoneyError::Overflow)?;
+        let fraction: i64 = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<i64>().map_err(|_| invalid())? * 10i64.pow((exponent - fraction.len()) as u32)
+        };
+        let amount = whole
+            .checked_mul(scale)
+            .and_then(|amount| amount.checked_add(fraction))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(if negative { -amount } else { amount }, currency))
+    }
+
+    /// Amount in the smallest units of the currency.
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// Currency of the amount.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Adds two amounts of the same currency.
+    pub fn checked_add(self, other: Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(self.currency, other.currency));
+        }
+        let amount = self.amount.checked_add(other.amount).ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Total of the price portions, in the given currency.
+    pub fn total(prices: &[LabeledPrice], currency: Currency) -> Result<Money, MoneyError> {
+        prices.iter().try_fold(Money::new(0, currency), |total, price| {
+            total.checked_add(Money::new(price.amount, currency))
+        })
+    }
+
+    /// Creates a price portion with the given label.
+    pub fn labeled<S: Into<String>>(self, label: S) -> LabeledPrice {
+        LabeledPrice {
+            label: label.into(),
+            amount: self.amount,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    /// Renders the amount like Telegram clients do, e.g. “$1,234.50”, “¥1,235”
+    /// or “1 234,50 €”.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = self.currency.format();
+        let exponent = self.currency.exponent();
+        let scale = 10u64.pow(exponent);
+        let abs = self.amount.unsigned_abs();
+        let whole = (abs / scale).to_string();
+
+        let mut number = String::new();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                number.push_str(format.thousands_sep);
+            }
+            number.push(digit);
+        }
+        if exponent > 0 {
+            number.push_str(format.decimal_sep);
+            number.push_str(&format!("{:0width$}", abs % scale, width = exponent as usize));
+        }
+
+        let space = if format.space_between { " " } else { "" };
+        let sign = if self.amount < 0 { "-" } else { "" };
+        if format.symbol_left {
+            write!(f, "{}{}{}{}", sign, format.symbol, space, number)
+        } else {
+            write!(f, "{}{}{}{}", sign, number, space, format.symbol)
+        }
+    }
+}
+
+
+/// Errors of money arithmetic and parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The currency code is not an ISO 4217 code.
+    UnknownCurrency(String),
+    /// The string is not a decimal amount of the currency.
+    InvalidAmount(String),
+    /// The amounts have different currencies.
+    CurrencyMismatch(Currency, Currency),
+    /// The amount does not fit in 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoneyError::UnknownCurrency(code) => write!(f, "unknown currency “{}”", code),
+            MoneyError::InvalidAmount(s) => write!(f, "invalid amount “{}”", s),
+            MoneyError::CurrencyMismatch(a, b) => write!(f, "can't mix {} and {}", a, b),
+            MoneyError::Overflow => f.write_str("amount overflow"),
+        }
+    }
+}
+
+impl Error for MoneyError {}
+
+impl From<UnknownCurrency> for MoneyError {
+    fn from(e: UnknownCurrency) -> Self {
+        MoneyError::UnknownCurrency(e.0)
+    }
+}
+
+
+impl Invoice {
+    /// Total price of the invoice.
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        Money::from_code(self.total_amount, &self.currency)
+    }
+}
+
+impl SuccessfulPayment {
+    /// Total price paid.
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        Money::from_code(self.total_amount, &self.currency)
+    }
+}
+
+impl PreCheckoutQuery {
+    /// Total price of the order.
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        Money::from_code(self.total_amount, &self.currency)
+    }
+}
@@ -0,0 +1,84 @@
+//! Picking among the sizes of a photo.
+//!
+//! Telegram sends a photo as the list of its sizes, in no order that can be
+//! relied upon, sometimes with the same file more than once.
+
+use super::*;
+
+
+/// The sizes of one photo: `Message::photo`, `Message::new_chat_photo`,
+/// `Game::photo`, each photo of `UserProfilePhotos::photos`, or
+/// `ChatPhoto::sizes`.
+pub trait PhotoSizes {
+    /// The size with the most pixels.
+    fn largest(&self) -> Option<&PhotoSize>;
+
+    /// The size with the fewest pixels.
+    fn smallest(&self) -> Option<&PhotoSize>;
+
+    /// The largest size fitting in `width` × `height`, `None` if they're all
+    /// bigger.
+    fn fitting(&self, width: i64, height: i64) -> Option<&PhotoSize>;
+
+    /// The largest size of at most `max_bytes`. Sizes of unknown size aren't
+    /// picked.
+    fn within_bytes(&self, max_bytes: u64) -> Option<&PhotoSize>;
+
+    /// The sizes, smallest first, each file once.
+    fn deduped(&self) -> Vec<&PhotoSize>;
+}
+
+impl PhotoSizes for [PhotoSize] {
+    fn largest(&self) -> Option<&PhotoSize> {
+        self.iter().max_by_key(|size| order(size))
+    }
+
+    fn smallest(&self) -> Option<&PhotoSize> {
+        self.iter().min_by_key(|size| order(size))
+    }
+
+    fn fitting(&self, width: i64, height: i64) -> Option<&PhotoSize> {
+        self.iter()
+            .filter(|size| size.width <= width && size.height <= height)
+            .max_by_key(|size| order(size))
+    }
+
+    fn within_bytes(&self, max_bytes: u64) -> Option<&PhotoSize> {
+        self.iter()
+            .filter(|size| size.file_size.is_some_and(|bytes| bytes as u64 <= max_bytes))
+            .max_by_key(|size| order(size))
+    }
+
+    fn deduped(&self) -> Vec<&PhotoSize> {
+        let mut sizes: Vec<&PhotoSize> = self.iter().collect();
+        sizes.sort_by_key(|size| order(size));
+        let mut seen = std::collections::HashSet::new();
+        sizes.retain(|size| seen.insert(&size.file_unique_id));
+        sizes
+    }
+}
+
+/// Orders sizes by pixels, then by bytes.
+fn order(size: &PhotoSize) -> (i64, i64) {
+    (size.width * size.height, size.file_size.unwrap_or(0))
+}
+
+
+impl ChatPhoto {
+    /// The small (160×160) and big (640×640) sizes of the photo, whose byte
+    /// sizes aren't known.
+    pub fn sizes(&self) -> [PhotoSize; 2] {
+        let size = |file_id: &str, file_unique_id: &str, side| PhotoSize {
+            file_id: file_id.to_string(),
+            file_unique_id: file_unique_id.to_string(),
+            width: side,
+            height: side,
+            file_size: None,
+            extra: Default::default(),
+        };
+        [
+            size(&self.small_file_id, &self.small_file_unique_id, 160),
+            size(&self.big_file_id, &self.big_file_unique_id, 640),
+        ]
+    }
+}
@@ -0,0 +1,374 @@
+//! Scheduling of requests under the flood limits of Telegram.
+//!
+//! Telegram accepts about 30 messages per second from a bot, 1 per second in a
+//! private chat and 20 per minute in a group, and answers the requests over
+//! those limits with a `retry_after` in their `ResponseParameters`. The
+//! `Scheduler` queues requests and hands them out as the limits allow:
+//!
+//! ```text
+//! loop {
+//!     match scheduler.poll() {
+//!         Next::Ready(request) => send it, and pass it back to
+//!                                 `Scheduler::flood_wait` on a flood error,
+//!         Next::Wait(delay) => sleep for `delay`, or until more requests come,
+//!         Next::Idle => wait for more requests,
+//!     }
+//! }
+//! ```
+//!
+//! Answers to queries go first, as the clients stop waiting for them after a
+//! few seconds. The scheduler does no I/O and reads the time from a `Clock`,
+//! so it runs on any runtime and can be driven by a `VirtualClock` in tests.
+
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::*;
+
+
+/// Source of the current time, as the time elapsed since an arbitrary origin.
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// The time of the system, from the creation of the clock on.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// The time of the system, from the Unix epoch on, for times that are saved
+/// and read back by another process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnixClock;
+
+impl Clock for UnixClock {
+    fn now(&self) -> Duration {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+    }
+}
+
+/// A clock that only moves when told to.
+#[derive(Debug, Clone, Default)]
+pub struct VirtualClock {
+    now: Cell<Duration>,
+}
+
+impl VirtualClock {
+    /// A clock standing at its origin.
+    pub fn new() -> Self {
+        VirtualClock::default()
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+
+/// At most `count` requests every `per`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rate {
+    pub count: usize,
+    pub per: Duration,
+}
+
+impl Rate {
+    pub fn new(count: usize, per: Duration) -> Self {
+        Rate { count, per }
+    }
+}
+
+/// The limits the scheduler keeps to, by default those documented in the Bot
+/// FAQ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Requests of the bot as a whole
+    pub global: Rate,
+    /// Requests sent to the same private chat
+    pub private_chat: Rate,
+    /// Requests sent to the same group, supergroup or channel
+    pub group: Rate,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            global: Rate::new(30, Duration::from_secs(1)),
+            private_chat: Rate::new(1, Duration::from_secs(1)),
+            group: Rate::new(20, Duration::from_secs(60)),
+        }
+    }
+}
+
+
+/// Order in which the queued requests are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Priority {
+    /// Answers to queries, which have deadlines.
+    Urgent,
+    Normal,
+}
+
+impl Priority {
+    /// Priority of the requests of `method`.
+    pub fn of(method: &str) -> Priority {
+        match method {
+            "answerCallbackQuery" | "answerInlineQuery" | "answerPreCheckoutQuery" | "answerShippingQuery"
+            | "answerWebAppQuery" => Priority::Urgent,
+            _ => Priority::Normal,
+        }
+    }
+}
+
+/// What the scheduler has to send.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Next {
+    /// A request to send now.
+    Ready(Request),
+    /// Nothing can be sent before `Duration` from now.
+    Wait(Duration),
+    /// Nothing is queued.
+    Idle,
+}
+
+
+/// A chat, as requests identify it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ChatKey {
+    Id(i64),
+    Username(String),
+}
+
+impl From<PolymorphChatId> for ChatKey {
+    fn from(chat_id: PolymorphChatId) -> Self {
+        match chat_id {
+            PolymorphChatId::Integer(id) => ChatKey::Id(id),
+            PolymorphChatId::String(username) => ChatKey::Username(username),
+        }
+    }
+}
+
+/// Times of the requests sent within the last period of a rate.
+#[derive(Debug, Default)]
+struct Window {
+    sent: VecDeque<Duration>,
+}
+
+impl Window {
+    /// When the next request can be sent.
+    fn free_at(&self, rate: Rate, now: Duration) -> Duration {
+        let recent = self.sent.iter().rev().take_while(|&&sent| sent + rate.per > now).count();
+        if recent < rate.count {
+            now
+        } else {
+            self.sent[self.sent.len() - rate.count] + rate.per
+        }
+    }
+
+    /// Records a request sent at `now`, forgetting those older than `period`.
+    fn record(&mut self, now: Duration, period: Duration) {
+        while self.sent.front().is_some_and(|&sent| sent + period <= now) {
+            self.sent.pop_front();
+        }
+        self.sent.push_back(now);
+    }
+}
+
+/// Queue of requests handed out within the flood limits.
+#[derive(Debug)]
+pub struct Scheduler<C: Clock = SystemClock> {
+    clock: C,
+    limits: Limits,
+    /// Requests by priority, with the chat they're sent to.
+    queues: [VecDeque<(Option<ChatKey>, Request)>; 2],
+    global: Window,
+    chats: HashMap<ChatKey, Window>,
+    chat_types: HashMap<i64, ChatType>,
+    /// End of the flood waits, of a chat or, for `None`, of the whole bot.
+    blocked: HashMap<Option<ChatKey>, Duration>,
+}
+
+impl Scheduler<SystemClock> {
+    /// A scheduler keeping to the default limits.
+    pub fn new() -> Self {
+        Scheduler::with_clock(SystemClock::new(), Limits::default())
+    }
+}
+
+impl Default for Scheduler<SystemClock> {
+    fn default() -> Self {
+        Scheduler::new()
+    }
+}
+
+impl<C: Clock> Scheduler<C> {
+    pub fn with_clock(clock: C, limits: Limits) -> Self {
+        Scheduler {
+            clock,
+            limits,
+            queues: [VecDeque::new(), VecDeque::new()],
+            global: Window::default(),
+            chats: HashMap::new(),
+            chat_types: HashMap::new(),
+            blocked: HashMap::new(),
+        }
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Number of queued requests.
+    pub fn len(&self) -> usize {
+        self.queues.iter().map(VecDeque::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Queues `request` after the requests of the same priority.
+    pub fn push(&mut self, request: Request) {
+        let chat = request.chat_id().map(ChatKey::from);
+        self.queues[Priority::of(request.method()) as usize].push_back((chat, request));
+    }
+
+    /// Records the type of `chat`, which decides the limit of the requests
+    /// sent to it. Without it, chats with a positive id are taken for private
+    /// chats and the other ones for groups.
+    pub fn learn_chat(&mut self, chat: &Chat) {
+        self.chat_types.insert(chat.id, chat.ty.clone());
+    }
+
+    /// Handles a flood error: nothing is sent to the chat of `request`, or by
+    /// the bot at all for requests not sent to a chat, for `retry_after`
+    /// seconds, and `request` is queued again ahead of the others. Gives
+    /// `request` back if `parameters` has no `retry_after`.
+    pub fn flood_wait(&mut self, request: Request, parameters: &ResponseParameters) -> Result<(), Request> {
+        let retry_after = match parameters.retry_after {
+            Some(seconds) => Duration::from_secs(seconds.max(0) as u64),
+            None => return Err(request),
+        };
+        let chat = request.chat_id().map(ChatKey::from);
+        let until = self.clock.now() + retry_after;
+        let blocked = self.blocked.entry(chat.clone()).or_insert(until);
+        *blocked = (*blocked).max(until);
+        self.queues[Priority::of(request.method()) as usize].push_front((chat, request));
+        Ok(())
+    }
+
+    /// The next request to send, if the limits allow sending one now.
+    pub fn poll(&mut self) -> Next {
+        if self.is_empty() {
+            return Next::Idle;
+        }
+        let now = self.clock.now();
+        self.blocked.retain(|_, until| *until > now);
+        let global = self.global.free_at(self.limits.global, now).max(self.blocked_until(None));
+        if global > now {
+            return Next::Wait(global - now);
+        }
+
+        let mut earliest: Option<Duration> = None;
+        for priority in 0..self.queues.len() {
+            // Requests to a chat are sent in order, the ones after a request
+            // that has to wait wait too.
+            let mut waiting = HashSet::new();
+            let mut ready = None;
+            for (i, (chat, _)) in self.queues[priority].iter().enumerate() {
+                let chat = match chat {
+                    Some(chat) => chat,
+                    None => {
+                        ready = Some(i);
+                        break;
+                    }
+                };
+                if waiting.contains(chat) {
+                    continue;
+                }
+                let free_at = self.chat_free_at(chat, now);
+                if free_at <= now {
+                    ready = Some(i);
+                    break;
+                }
+                waiting.insert(chat.clone());
+                earliest = Some(earliest.map_or(free_at, |earliest| earliest.min(free_at)));
+            }
+            if let Some(i) = ready {
+                let (chat, request) = self.queues[priority].remove(i).unwrap();
+                self.global.record(now, self.limits.global.per);
+                if let Some(chat) = chat {
+                    let period = self.chat_rate(&chat).per;
+                    self.chats.entry(chat).or_default().record(now, period);
+                }
+                self.forget_idle_chats(now);
+                return Next::Ready(request);
+            }
+        }
+        // Some request is queued, so some chat has to wait.
+        Next::Wait(earliest.unwrap() - now)
+    }
+
+    fn blocked_until(&self, chat: Option<&ChatKey>) -> Duration {
+        self.blocked.get(&chat.cloned()).copied().unwrap_or_default()
+    }
+
+    fn chat_free_at(&self, chat: &ChatKey, now: Duration) -> Duration {
+        let rate = self.chat_rate(chat);
+        let free_at = self.chats.get(chat).map_or(now, |window| window.free_at(rate, now));
+        free_at.max(self.blocked_until(Some(chat)))
+    }
+
+    fn chat_rate(&self, chat: &ChatKey) -> Rate {
+        let private = match chat {
+            ChatKey::Id(id) => match self.chat_types.get(id) {
+                Some(ty) => *ty == ChatType::Private,
+                None => *id > 0,
+            },
+            ChatKey::Username(_) => false,
+        };
+        if private {
+            self.limits.private_chat
+        } else {
+            self.limits.group
+        }
+    }
+
+    /// Drops the windows of the chats nothing was sent to for a whole period.
+    fn forget_idle_chats(&mut self, now: Duration) {
+        let longest = self.limits.private_chat.per.max(self.limits.group.per);
+        self.chats.retain(|_, window| window.sent.back().is_some_and(|&sent| sent + longest > now));
+    }
+}
@@ -0,0 +1,123 @@
+//! Requests answering the objects received in updates, with the ids of the
+//! chat, the message or the query filled in.
+
+use super::*;
+
+
+impl Message {
+    /// Sends `text` to the chat of the message, as a reply to it. Replies stay
+    /// in the forum topic and go through the business connection of the
+    /// message.
+    pub fn reply_text<S: Into<String>>(&self, text: S) -> SendMessage {
+        let mut request = SendMessage::new(self.chat.id, text).reply_parameters(ReplyParameters::new(self.message_id));
+        if self.is_topic_message == Some(true) {
+            request.message_thread_id = self.message_thread_id;
+        }
+        request.business_connection_id = self.business_connection_id.clone();
+        request
+    }
+
+    /// Asks the sender of the message for an answer: replies with `text` and
+    /// opens the reply interface of the sender, and of no one else in groups.
+    pub fn prompt<S: Into<String>>(&self, text: S) -> SendMessage {
+        self.reply_text(text).reply_markup(ForceReply::new().selective(true))
+    }
+
+    /// Forwards the message to `chat_id`.
+    pub fn forward_to<C: Into<PolymorphChatId>>(&self, chat_id: C) -> ForwardMessage {
+        ForwardMessage::new(chat_id, self.chat.id, self.message_id)
+    }
+
+    /// Copies the message to `chat_id`, without a link to the original.
+    pub fn copy_to<C: Into<PolymorphChatId>>(&self, chat_id: C) -> CopyMessage {
+        CopyMessage::new(chat_id, self.chat.id, self.message_id)
+    }
+
+    /// Replaces the text of the message.
+    pub fn edit_text<S: Into<String>>(&self, text: S) -> EditMessageText {
+        let mut request = EditMessageText::new(self, text);
+        request.business_connection_id = self.business_connection_id.clone();
+        request
+    }
+
+    /// Replaces the caption of the message.
+    pub fn edit_caption<S: Into<String>>(&self, caption: S) -> EditMessageCaption {
+        let mut request = EditMessageCaption::new(self).caption(caption);
+        request.business_connection_id = self.business_connection_id.clone();
+        request
+    }
+
+    /// Replaces the inline keyboard of the message.
+    pub fn edit_reply_markup(&self, reply_markup: InlineKeyboardMarkup) -> EditMessageReplyMarkup {
+        let mut request = EditMessageReplyMarkup::new(self).reply_markup(reply_markup);
+        request.business_connection_id = self.business_connection_id.clone();
+        request
+    }
+
+    /// Deletes the message.
+    pub fn delete(&self) -> DeleteMessage {
+        DeleteMessage::new(self.chat.id, self.message_id)
+    }
+}
+
+
+impl CallbackQuery {
+    /// Answers the query, which stops the progress bar of the client.
+    pub fn answer(&self) -> AnswerCallbackQuery {
+        AnswerCallbackQuery::new(&self.id[..])
+    }
+
+    /// Replaces the text of the message the query comes from, `None` if the
+    /// query doesn't say where the message is.
+    pub fn edit_text<S: Into<String>>(&self, text: S) -> Option<EditMessageText> {
+        Some(EditMessageText::new(MessageTarget::from_callback_query(self)?, text))
+    }
+
+    /// Replaces the caption of the message the query comes from, `None` if the
+    /// query doesn't say where the message is.
+    pub fn edit_caption<S: Into<String>>(&self, caption: S) -> Option<EditMessageCaption> {
+        Some(EditMessageCaption::new(MessageTarget::from_callback_query(self)?).caption(caption))
+    }
+
+    /// Replaces the inline keyboard of the message the query comes from,
+    /// `None` if the query doesn't say where the message is.
+    pub fn edit_reply_markup(&self, reply_markup: InlineKeyboardMarkup) -> Option<EditMessageReplyMarkup> {
+        let target = MessageTarget::from_callback_query(self)?;
+        Some(EditMessageReplyMarkup::new(target).reply_markup(reply_markup))
+    }
+}
+
+
+impl InlineQuery {
+    /// Answers the query with `results`.
+    pub fn answer(&self, results: Vec<InlineQueryResult>) -> AnswerInlineQuery {
+        AnswerInlineQuery::new(&self.id[..], results)
+    }
+}
+
+
+impl ShippingQuery {
+    /// Answers the query with the shipping options available for the address.
+    pub fn answer(&self, shipping_options: Vec<ShippingOption>) -> AnswerShippingQuery {
+        AnswerShippingQuery::new(&self.id[..], true).shipping_options(shipping_options)
+    }
+
+    /// Answers that the order can't be shipped to the address, telling the
+    /// user why.
+    pub fn reject<S: Into<String>>(&self, error_message: S) -> AnswerShippingQuery {
+        AnswerShippingQuery::new(&self.id[..], false).error_message(error_message)
+    }
+}
+
+
+impl PreCheckoutQuery {
+    /// Confirms that the order can proceed.
+    pub fn approve(&self) -> AnswerPreCheckoutQuery {
+        AnswerPreCheckoutQuery::new(&self.id[..], true)
+    }
+
+    /// Answers that the order can't proceed, telling the user why.
+    pub fn reject<S: Into<String>>(&self, error_message: S) -> AnswerPreCheckoutQuery {
+        AnswerPreCheckoutQuery::new(&self.id[..], false).error_message(error_message)
+    }
+}
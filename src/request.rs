@@ -0,0 +1,47 @@
+//! Requests of any method, for the code queueing and sending them.
+
+use serde_json::{Map, Value};
+
+use super::*;
+
+
+/// A request of some method: its name and its parameters as a JSON object,
+/// ready to be sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    method: &'static str,
+    params: Map<String, Value>,
+}
+
+impl Request {
+    /// The request calling `M::NAME` with the parameters in `method`.
+    pub fn new<M: Method>(method: &M) -> Self {
+        let params = match serde_json::to_value(method) {
+            Ok(Value::Object(params)) => params,
+            // The method structs serialize to objects, with string keys only.
+            _ => unreachable!("{} didn't serialize to an object", M::NAME),
+        };
+        Request {
+            method: M::NAME,
+            params,
+        }
+    }
+
+    /// Name of the method, as used in the request URL.
+    pub fn method(&self) -> &'static str {
+        self.method
+    }
+
+    pub fn params(&self) -> &Map<String, Value> {
+        &self.params
+    }
+
+    pub fn params_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.params
+    }
+
+    /// The chat the request is sent to, if it has a `chat_id` parameter.
+    pub fn chat_id(&self) -> Option<PolymorphChatId> {
+        serde_json::from_value(self.params.get("chat_id")?.clone()).ok()
+    }
+}
@@ -0,0 +1,218 @@
+//! Sending received messages again as new messages, rather than forwarding
+//! them.
+
+use std::error::Error;
+use std::fmt;
+
+use super::*;
+
+
+/// A request sending a message of some kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SendRequest {
+    Text(SendMessage),
+    Photo(SendPhoto),
+    Video(SendVideo),
+    Animation(SendAnimation),
+    Audio(SendAudio),
+    Document(SendDocument),
+    Voice(SendVoice),
+    VideoNote(SendVideoNote),
+    Sticker(SendSticker),
+    Location(SendLocation),
+    Venue(SendVenue),
+    Contact(SendContact),
+    Poll(SendPoll),
+}
+
+impl SendRequest {
+    /// The request, for the code queueing and sending requests of any method.
+    pub fn to_request(&self) -> Request {
+        match self {
+            SendRequest::Text(request) => Request::new(request),
+            SendRequest::Photo(request) => Request::new(request),
+            SendRequest::Video(request) => Request::new(request),
+            SendRequest::Animation(request) => Request::new(request),
+            SendRequest::Audio(request) => Request::new(request),
+            SendRequest::Document(request) => Request::new(request),
+            SendRequest::Voice(request) => Request::new(request),
+            SendRequest::VideoNote(request) => Request::new(request),
+            SendRequest::Sticker(request) => Request::new(request),
+            SendRequest::Location(request) => Request::new(request),
+            SendRequest::Venue(request) => Request::new(request),
+            SendRequest::Contact(request) => Request::new(request),
+            SendRequest::Poll(request) => Request::new(request),
+        }
+    }
+}
+
+/// A message that can't be sent again, with what it holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotResendable {
+    pub content: &'static str,
+}
+
+impl fmt::Display for NotResendable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a message with {} can't be sent again", self.content)
+    }
+}
+
+impl Error for NotResendable {}
+
+/// Copies the caption of the message, with its formatting as entities, and
+/// the inline keyboard.
+macro_rules! caption {
+    ($request:expr, $message:expr) => {{
+        let mut request = $request;
+        request.caption = $message.caption.clone();
+        request.caption_entities = $message.caption_entities.clone();
+        request.reply_markup = $message.reply_markup.clone().map(Into::into);
+        request
+    }};
+}
+
+impl Message {
+    /// The request sending the content of the message to `chat_id` as a new
+    /// message: the same text or caption, with the same formatting, the same
+    /// files by file id and the same inline keyboard.
+    pub fn to_send_request<C: Into<PolymorphChatId>>(&self, chat_id: C) -> Result<SendRequest, NotResendable> {
+        let chat_id = chat_id.into();
+        let markup = self.reply_markup.clone().map(PolymorphReplyMarkup::from);
+        if let Some(text) = &self.text {
+            let mut request = SendMessage::new(chat_id, text.clone());
+            request.entities = self.entities.clone();
+            request.link_preview_options = self.link_preview_options.clone();
+            request.reply_markup = markup;
+            return Ok(SendRequest::Text(request));
+        }
+        if let Some(sizes) = &self.photo {
+            let photo = sizes.largest().ok_or(NotResendable { content: "no photo size" })?;
+            let mut request = caption!(SendPhoto::new(chat_id, photo.file_id.clone()), self);
+            request.show_caption_above_media = self.show_caption_above_media;
+            request.has_spoiler = self.has_media_spoiler;
+            return Ok(SendRequest::Photo(request));
+        }
+        if let Some(video) = &self.video {
+            let request = SendVideo::new(chat_id, video.file_id.clone())
+                .duration(video.duration)
+                .width(video.width)
+                .height(video.height);
+            let mut request = caption!(request, self);
+            request.show_caption_above_media = self.show_caption_above_media;
+            request.has_spoiler = self.has_media_spoiler;
+            return Ok(SendRequest::Video(request));
+        }
+        if let Some(animation) = &self.animation {
+            let request = SendAnimation::new(chat_id, animation.file_id.clone())
+                .duration(animation.duration)
+                .width(animation.width)
+                .height(animation.height);
+            let mut request = caption!(request, self);
+            request.show_caption_above_media = self.show_caption_above_media;
+            request.has_spoiler = self.has_media_spoiler;
+            return Ok(SendRequest::Animation(request));
+        }
+        if let Some(audio) = &self.audio {
+            let mut request = caption!(SendAudio::new(chat_id, audio.file_id.clone()).duration(audio.duration), self);
+            request.performer = audio.performer.clone();
+            request.title = audio.title.clone();
+            return Ok(SendRequest::Audio(request));
+        }
+        if let Some(document) = &self.document {
+            return Ok(SendRequest::Document(caption!(SendDocument::new(chat_id, document.file_id.clone()), self)));
+        }
+        if let Some(voice) = &self.voice {
+            let request = SendVoice::new(chat_id, voice.file_id.clone()).duration(voice.duration);
+            return Ok(SendRequest::Voice(caption!(request, self)));
+        }
+        if let Some(note) = &self.video_note {
+            let mut request = SendVideoNote::new(chat_id, note.file_id.clone()).duration(note.duration).length(note.length);
+            request.reply_markup = markup;
+            return Ok(SendRequest::VideoNote(request));
+        }
+        if let Some(sticker) = &self.sticker {
+            let mut request = SendSticker::new(chat_id, sticker.file_id.clone());
+            request.reply_markup = markup;
+            return Ok(SendRequest::Sticker(request));
+        }
+        // Venues have a location too.
+        if let Some(venue) = &self.venue {
+            let location = &venue.location;
+            let (title, address) = (venue.title.clone(), venue.address.clone());
+            let mut request = SendVenue::new(chat_id, location.latitude, location.longitude, title, address);
+            request.foursquare_id = venue.foursquare_id.clone();
+            request.foursquare_type = venue.foursquare_type.clone();
+            request.google_place_id = venue.google_place_id.clone();
+            request.google_place_type = venue.google_place_type.clone();
+            request.reply_markup = markup;
+            return Ok(SendRequest::Venue(request));
+        }
+        if let Some(location) = &self.location {
+            let mut request = SendLocation::new(chat_id, location.latitude, location.longitude);
+            request.horizontal_accuracy = location.horizontal_accuracy;
+            request.reply_markup = markup;
+            return Ok(SendRequest::Location(request));
+        }
+        if let Some(contact) = &self.contact {
+            let mut request = SendContact::new(chat_id, contact.phone_number.clone(), contact.first_name.clone());
+            request.last_name = contact.last_name.clone();
+            request.vcard = contact.vcard.clone();
+            request.reply_markup = markup;
+            return Ok(SendRequest::Contact(request));
+        }
+        if let Some(poll) = &self.poll {
+            return resend_poll(poll, chat_id, markup).map(SendRequest::Poll);
+        }
+        Err(NotResendable { content: self.content() })
+    }
+
+    /// What a message that can't be sent again holds.
+    fn content(&self) -> &'static str {
+        if self.invoice.is_some() {
+            "an invoice"
+        } else if self.game.is_some() {
+            "a game"
+        } else if self.dice.is_some() {
+            "a dice, whose value would change"
+        } else if self.story.is_some() {
+            "a story"
+        } else if self.paid_media.is_some() {
+            "paid media"
+        } else if self.giveaway.is_some() || self.giveaway_winners.is_some() {
+            "a giveaway"
+        } else {
+            "a service message"
+        }
+    }
+}
+
+fn resend_poll(
+    poll: &Poll,
+    chat_id: PolymorphChatId,
+    markup: Option<PolymorphReplyMarkup>,
+) -> Result<SendPoll, NotResendable> {
+    let options = poll
+        .options
+        .iter()
+        .map(|option| {
+            let mut input = InputPollOption::new(option.text.clone());
+            input.text_entities = option.text_entities.clone();
+            input
+        })
+        .collect();
+    let mut request = SendPoll::new(chat_id, poll.question.clone(), options);
+    request.question_entities = poll.question_entities.clone();
+    request.is_anonymous = Some(poll.is_anonymous);
+    request.ty = Some(poll.ty.clone());
+    request.allows_multiple_answers = Some(poll.allows_multiple_answers);
+    if poll.ty == PollType::Quiz {
+        // Only the polls of the bot, or closed ones, tell the correct option.
+        let correct_option_id = poll.correct_option_id.ok_or(NotResendable { content: "a quiz without its answer" })?;
+        request.correct_option_id = Some(correct_option_id);
+        request.explanation = poll.explanation.clone();
+        request.explanation_entities = poll.explanation_entities.clone();
+    }
+    request.reply_markup = markup;
+    Ok(request)
+}
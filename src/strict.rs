@@ -0,0 +1,83 @@
+//! Strict deserialization, for contract tests against real Bot API payloads.
+//!
+//! With the `strict` feature every struct rejects the keys it has no field for,
+//! every union the subtypes it doesn't know, and every string enum the values
+//! it doesn't know, so a corpus of recorded updates fails as soon as Telegram
+//! sends something the models don't know. `strict` takes precedence over
+//! `preserve-unknown`.
+//!
+//! The methods acting on a `MessageTarget` are the exception: serde can't deny
+//! the unknown keys of a struct with a flattened field, so they ignore them
+//! like without `strict`. The functions here report
+//! where the unknown key is:
+//!
+//! ```text
+//! message.entities[0].language: unknown field `language`, expected ...
+//! ```
+
+use std::error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::de;
+
+/// A deserialization error and the JSON path it happened at.
+#[derive(Debug)]
+pub struct Error {
+    path: String,
+    inner: serde_json::Error,
+}
+
+impl Error {
+    /// Path of the offending value, e.g. `message.chat.type`, or `.` for the
+    /// root.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn inner(&self) -> &serde_json::Error {
+        &self.inner
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.inner)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = err.path().to_string();
+        let inner = err.into_inner();
+        let path = match de::inner_path(&inner.to_string()) {
+            Some(within) => de::join_paths(&path, &within),
+            None => path,
+        };
+        Error { path, inner }
+    }
+}
+
+/// Deserializes `T` from JSON text.
+pub fn from_str<T: DeserializeOwned>(json: &str) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end().map_err(|inner| Error {
+        path: ".".to_string(),
+        inner,
+    })?;
+    Ok(value)
+}
+
+/// Deserializes `T` from an already parsed JSON value.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+    Ok(serde_path_to_error::deserialize(value)?)
+}
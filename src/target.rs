@@ -0,0 +1,91 @@
+//! The message the editing methods and the game score methods act on.
+
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+
+/// Message to act on: a message in a chat, identified by the chat and its id
+/// in the chat, or a message sent via the bot in inline mode, identified by
+/// its inline message id. Serialized as the `chat_id` and `message_id` fields
+/// or as the `inline_message_id` field of the request.
+///
+/// The chat is given by its id or the username of a channel, except for the
+/// game score methods, which only take the integer id of the chat and use a
+/// `MessageTarget<i64>`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum MessageTarget<C = PolymorphChatId> {
+    /// A message in a chat.
+    Chat {
+        /// Unique identifier for the target chat, or username of the target
+        /// channel (in the format @channelusername) where it's accepted
+        chat_id: C,
+        /// Identifier of the message
+        message_id: i64,
+    },
+    /// A message sent via the bot in inline mode, by its inline message id.
+    Inline(String),
+}
+
+impl<C: From<i64>> MessageTarget<C> {
+    /// The message of a callback query, `None` if the query has neither the
+    /// message nor its inline message id.
+    pub fn from_callback_query(query: &CallbackQuery) -> Option<Self> {
+        match (&query.message, &query.inline_message_id) {
+            (Some(MaybeInaccessibleMessage::Message(message)), _) => Some(MessageTarget::from(&**message)),
+            (Some(MaybeInaccessibleMessage::InaccessibleMessage(message)), _) => Some(MessageTarget::Chat {
+                chat_id: C::from(message.chat.id),
+                message_id: message.message_id,
+            }),
+            (_, Some(id)) => Some(MessageTarget::Inline(id.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, C: From<i64>> From<&'a Message> for MessageTarget<C> {
+    fn from(message: &'a Message) -> Self {
+        MessageTarget::Chat {
+            chat_id: C::from(message.chat.id),
+            message_id: message.message_id,
+        }
+    }
+}
+
+impl<C: Serialize> Serialize for MessageTarget<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MessageTarget::Chat { chat_id, message_id } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("chat_id", chat_id)?;
+                map.serialize_entry("message_id", message_id)?;
+                map.end()
+            }
+            MessageTarget::Inline(inline_message_id) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("inline_message_id", inline_message_id)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de, C: Deserialize<'de>> Deserialize<'de> for MessageTarget<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields<C> {
+            chat_id: Option<C>,
+            message_id: Option<i64>,
+            inline_message_id: Option<String>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        match (fields.chat_id, fields.message_id, fields.inline_message_id) {
+            (Some(chat_id), Some(message_id), None) => Ok(MessageTarget::Chat { chat_id, message_id }),
+            (None, None, Some(id)) => Ok(MessageTarget::Inline(id)),
+            _ => Err(D::Error::custom("expected either chat_id and message_id, or inline_message_id")),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! JSON the models don't know about. String enums keep the values they don't
+//! know in their `Unknown` variant, unless `strict` rejects them. With the `preserve-unknown` feature every
+//! object also keeps its unknown keys in `extra`, and unions their unknown
+//! subtypes in `Unknown`, so that re-serializing an update doesn't lose
+//! anything Telegram added after this version of the models. Without it,
+//! `extra` stays empty and unknown subtypes are errors; the fields and variants
+//! are there either way, so that the feature doesn't change the types.
+
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use serde_json::{Map, Value};
+
+/// The keys of an object its struct has no field for.
+pub type Extra = Raw<Map<String, Value>>;
+
+/// JSON kept as it was received. It gives the models a `PartialOrd` despite
+/// holding JSON values: equal values compare as equal, other ones are
+/// unordered.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Raw<T>(pub T);
+
+impl<T: PartialEq> PartialOrd for Raw<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Deref for Raw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Raw<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
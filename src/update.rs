@@ -0,0 +1,112 @@
+//! Accessors for what updates of any kind have in common.
+
+use super::*;
+
+
+impl Update {
+    /// The new or edited message, channel post or business message the update
+    /// is about.
+    pub fn message(&self) -> Option<&Message> {
+        self.message
+            .as_ref()
+            .or(self.edited_message.as_ref())
+            .or(self.channel_post.as_ref())
+            .or(self.edited_channel_post.as_ref())
+            .or(self.business_message.as_ref())
+            .or(self.edited_business_message.as_ref())
+            .map(|message| &**message)
+    }
+
+    /// The chat the update comes from, if any.
+    pub fn chat(&self) -> Option<&Chat> {
+        if let Some(message) = self.message() {
+            return Some(&message.chat);
+        }
+        if let Some(query) = &self.callback_query {
+            return match query.message.as_ref()? {
+                MaybeInaccessibleMessage::Message(message) => Some(&message.chat),
+                MaybeInaccessibleMessage::InaccessibleMessage(message) => Some(&message.chat),
+                MaybeInaccessibleMessage::Unknown(_) => None,
+            };
+        }
+        let member = self.my_chat_member.as_ref().or(self.chat_member.as_ref());
+        member
+            .map(|member| &member.chat)
+            .or(self.chat_join_request.as_ref().map(|request| &request.chat))
+            .or(self.message_reaction.as_ref().map(|reaction| &reaction.chat))
+            .or(self.message_reaction_count.as_ref().map(|reactions| &reactions.chat))
+            .or(self.deleted_business_messages.as_ref().map(|deleted| &deleted.chat))
+            .or(self.chat_boost.as_ref().map(|boost| &boost.chat))
+            .or(self.removed_chat_boost.as_ref().map(|boost| &boost.chat))
+            .or(self.poll_answer.as_ref().and_then(|answer| answer.voter_chat.as_ref()))
+    }
+
+    /// The user who caused the update, if known.
+    pub fn user(&self) -> Option<&User> {
+        if let Some(message) = self.message() {
+            return message.from.as_ref();
+        }
+        let member = self.my_chat_member.as_ref().or(self.chat_member.as_ref());
+        self.callback_query
+            .as_ref()
+            .map(|query| &query.from)
+            .or(self.inline_query.as_ref().map(|query| &query.from))
+            .or(self.chosen_inline_result.as_ref().map(|result| &result.from))
+            .or(self.shipping_query.as_ref().map(|query| &query.from))
+            .or(self.pre_checkout_query.as_ref().map(|query| &query.from))
+            .or(self.purchased_paid_media.as_ref().map(|purchase| &purchase.from))
+            .or(member.map(|member| &member.from))
+            .or(self.chat_join_request.as_ref().map(|request| &request.from))
+            .or(self.business_connection.as_ref().map(|connection| &connection.user))
+            .or(self.message_reaction.as_ref().and_then(|reaction| reaction.user.as_ref()))
+            .or(self.poll_answer.as_ref().and_then(|answer| answer.user.as_ref()))
+    }
+}
+
+
+impl Message {
+    /// The text of the message, or the caption of its media.
+    pub fn text_or_caption(&self) -> Option<&str> {
+        self.text.as_deref().or(self.caption.as_deref())
+    }
+
+    /// Whether the message carries media: a photo, video, animation, audio,
+    /// voice or video note, document, sticker or paid media.
+    pub fn has_media(&self) -> bool {
+        self.photo.is_some()
+            || self.video.is_some()
+            || self.animation.is_some()
+            || self.audio.is_some()
+            || self.voice.is_some()
+            || self.video_note.is_some()
+            || self.document.is_some()
+            || self.sticker.is_some()
+            || self.paid_media.is_some()
+    }
+
+    /// The command the text starts with.
+    pub fn command(&self) -> Option<Command<'_>> {
+        let text = self.text.as_deref()?.strip_prefix('/')?;
+        let (command, args) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        let (name, bot) = match command.split_once('@') {
+            Some((name, bot)) => (name, Some(bot)),
+            None => (command, None),
+        };
+        if name.is_empty() {
+            return None;
+        }
+        Some(Command { name, bot, args: args.trim_start() })
+    }
+}
+
+
+/// A command sent to a bot, like `/start@bot_name args`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command<'a> {
+    /// Name of the command, without the `/`
+    pub name: &'a str,
+    /// Username of the bot the command is addressed to, if any
+    pub bot: Option<&'a str>,
+    /// The rest of the text
+    pub args: &'a str,
+}
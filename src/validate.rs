@@ -0,0 +1,162 @@
+//! Checks of the limits the Bot API documents for the parameters of its
+//! methods, so that a request breaking them fails before it is sent:
+//!
+//! ```text
+//! media[3].caption: must be 0-1024 characters, got 1100
+//! ```
+//!
+//! Limits on text that is parsed for entities are only checked when the text
+//! has no parse mode, as its markup doesn't count.
+
+use std::error;
+use std::fmt;
+
+use crate::payments::InvoiceError;
+
+/// A request or an object that can be checked before it is sent.
+///
+/// The length limits of text sent with a parse mode, or of a caption sent with
+/// one, are not checked: they apply to the text once its markup is parsed, so
+/// the length of the raw text says nothing about them.
+pub trait Validate {
+    /// Checks the limits of the fields, and of the objects the fields hold.
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// A broken limit, and the path of the field breaking it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    path: String,
+    rule: Rule,
+}
+
+/// A limit of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// The text must be `min` to `max` characters long.
+    Characters { min: u64, max: u64, actual: u64 },
+    /// The text must be `min` to `max` bytes long in UTF-8.
+    Bytes { min: u64, max: u64, actual: u64 },
+    /// The array must have `min` to `max` items.
+    Items { min: u64, max: u64, actual: u64 },
+    /// The number must be between `min` and `max`.
+    Range { min: u64, max: u64, actual: f64 },
+    /// Only A-Z, a-z, 0-9, `_` and `-` are allowed.
+    SafeCharacters,
+    /// The invoice doesn't pass `SendInvoice::check_invoice`.
+    Invoice(InvoiceError),
+}
+
+impl ValidationError {
+    pub(crate) fn new(path: &str, rule: Rule) -> Self {
+        ValidationError {
+            path: path.to_string(),
+            rule,
+        }
+    }
+
+    /// Path of the field, e.g. `results[2].reply_markup.inline_keyboard[0][1].callback_data`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Prefixes the path with the field or the index holding the value.
+    fn within(mut self, segment: &str) -> Self {
+        if !self.path.starts_with('[') {
+            self.path.insert(0, '.');
+        }
+        self.path.insert_str(0, segment);
+        self
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.rule {
+            Rule::Characters { min, max, actual } => write!(f, "must be {}-{} characters, got {}", min, max, actual),
+            Rule::Bytes { min, max, actual } => write!(f, "must be {}-{} bytes, got {}", min, max, actual),
+            Rule::Items { min, max, actual } => write!(f, "must have {}-{} items, got {}", min, max, actual),
+            Rule::Range { min, max, actual } => write!(f, "must be between {} and {}, got {}", min, max, actual),
+            Rule::SafeCharacters => f.write_str("only A-Z, a-z, 0-9, _ and - are allowed"),
+            Rule::Invoice(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl error::Error for ValidationError {}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.as_ref().map_or(Ok(()), Validate::validate)
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, item) in self.iter().enumerate() {
+            item.validate().map_err(|err| err.within(&format!("[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn nested<T: Validate>(field: &str, value: &T) -> Result<(), ValidationError> {
+    value.validate().map_err(|err| err.within(field))
+}
+
+pub(crate) fn characters(field: &str, value: &str, min: u64, max: u64) -> Result<(), ValidationError> {
+    let actual = value.chars().count() as u64;
+    check(field, (min..=max).contains(&actual), Rule::Characters { min, max, actual })
+}
+
+pub(crate) fn bytes(field: &str, value: &str, min: u64, max: u64) -> Result<(), ValidationError> {
+    let actual = value.len() as u64;
+    check(field, (min..=max).contains(&actual), Rule::Bytes { min, max, actual })
+}
+
+pub(crate) fn items<T>(field: &str, value: &[T], min: u64, max: u64) -> Result<(), ValidationError> {
+    let actual = value.len() as u64;
+    check(field, (min..=max).contains(&actual), Rule::Items { min, max, actual })
+}
+
+pub(crate) fn range<T: Number>(field: &str, value: T, min: u32, max: u32) -> Result<(), ValidationError> {
+    let valid = value >= T::from(min) && value <= T::from(max);
+    check(field, valid, Rule::Range { min: min.into(), max: max.into(), actual: value.to_f64() })
+}
+
+/// The integers and floats of the parameters.
+pub(crate) trait Number: PartialOrd + From<u32> {
+    fn to_f64(self) -> f64;
+}
+
+impl Number for i64 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Number for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+pub(crate) fn safe_characters(field: &str, value: &str) -> Result<(), ValidationError> {
+    let valid = value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    check(field, valid, Rule::SafeCharacters)
+}
+
+fn check(field: &str, valid: bool, rule: Rule) -> Result<(), ValidationError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ValidationError::new(field, rule))
+    }
+}
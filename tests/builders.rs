@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::{BotCommandScope, BotCommandScopeDefault, GetMe, InlineKeyboardButton, InlineKeyboardMarkup,
+                    InlineQueryResultArticle, InputMediaVideo, InputTextMessageContent, LabeledPrice,
+                    PolymorphChatId, ReplyKeyboardRemove, SendMessage, ShippingOption};
+
+#[test]
+fn fills_the_required_parameters() {
+    let request = SendMessage::new("@channel", "Hello").parse_mode("HTML").disable_notification(true);
+    assert_eq!(request.chat_id, PolymorphChatId::String("@channel".to_string()));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"chat_id": "@channel", "text": "Hello", "parse_mode": "HTML", "disable_notification": true})
+    );
+
+    let request = SendMessage::new(42, "Bye").reply_markup(ReplyKeyboardRemove::new());
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"chat_id": 42, "text": "Bye", "reply_markup": {"remove_keyboard": true}})
+    );
+}
+
+#[test]
+fn fills_the_tags_of_subtypes() {
+    let button = InlineKeyboardButton::new("Open").url("https://example.com");
+    let article = InlineQueryResultArticle::new("1", "Title", InputTextMessageContent::new("Text"))
+        .reply_markup(InlineKeyboardMarkup::new(vec![vec![button]]));
+    assert_eq!(
+        serde_json::to_value(&article).unwrap(),
+        json!({
+            "type": "article",
+            "id": "1",
+            "title": "Title",
+            "input_message_content": {"message_text": "Text"},
+            "reply_markup": {"inline_keyboard": [[{"text": "Open", "url": "https://example.com"}]]},
+        })
+    );
+
+    let video = InputMediaVideo::new("file_id").supports_streaming(true);
+    assert_eq!(
+        serde_json::to_value(&video).unwrap(),
+        json!({"type": "video", "media": "file_id", "supports_streaming": true})
+    );
+    let scope: BotCommandScope = serde_json::from_value(json!({"type": "default"})).unwrap();
+    assert_eq!(BotCommandScope::from(BotCommandScopeDefault::default()), scope);
+}
+
+#[test]
+fn defaults_the_structs_without_required_fields() {
+    assert_eq!(GetMe::default(), GetMe::new());
+    assert_eq!(serde_json::to_value(GetMe::default()).unwrap(), json!({}));
+
+    let option = ShippingOption::new("express", "Express", vec![LabeledPrice::new("Shipping", 500)]);
+    assert_eq!(
+        serde_json::to_value(&option).unwrap(),
+        json!({"id": "express", "title": "Express", "prices": [{"label": "Shipping", "amount": 500}]})
+    );
+}
@@ -0,0 +1,49 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use serde_json::{json, Value};
+use tg_bot_models::{Message, Update};
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Polls `future` once, which is enough for futures that don't wait.
+pub fn now<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(Noop));
+    match pin!(future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+/// `value` with the keys of `fields` added, replacing the ones it has.
+pub fn with(mut value: Value, fields: Value) -> Value {
+    if let (Value::Object(object), Value::Object(fields)) = (&mut value, fields) {
+        object.extend(fields);
+    }
+    value
+}
+
+/// JSON of the message 1 in `chat`, with `fields` added.
+pub fn message_json(chat: Value, fields: Value) -> Value {
+    with(json!({"message_id": 1, "date": 1717171717, "chat": chat}), fields)
+}
+
+/// The message 1 in `chat`, with `fields` added.
+pub fn message(chat: Value, fields: Value) -> Message {
+    serde_json::from_value(message_json(chat, fields)).unwrap()
+}
+
+/// The update 1 with the message 1 in `chat`, with `fields` added.
+pub fn message_update(chat: Value, fields: Value) -> Update {
+    serde_json::from_value(json!({"update_id": 1, "message": message_json(chat, fields)})).unwrap()
+}
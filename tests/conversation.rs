@@ -0,0 +1,137 @@
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use std::fs;
+use std::time::Duration;
+
+use tg_bot_models::conversation::{Conversations, Handled, Input, JsonFileStorage, Key, MemoryStorage, State,
+                                  Transition};
+use tg_bot_models::ratelimit::VirtualClock;
+use tg_bot_models::{PolymorphReplyMarkup, Update};
+
+/// A sign-up asking for a name, then an age.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum SignUp {
+    Name,
+    Age { name: String },
+}
+
+impl State for SignUp {
+    fn next(self, input: Input<'_>) -> Transition<Self> {
+        let message = match input {
+            Input::Message(message) => message,
+            Input::CallbackQuery(_) => return Transition::to(self),
+        };
+        let text = input.text().unwrap_or_default();
+        match self {
+            SignUp::Name => Transition::to(SignUp::Age { name: text.to_string() }).send(&message.prompt("How old are you?")),
+            SignUp::Age { name } => match text.parse::<u8>() {
+                Ok(age) => Transition::end().send(&message.reply_text(format!("Welcome {}, {}!", name, age))),
+                Err(_) => Transition::to(SignUp::Age { name }).send(&message.prompt("A number, please.")),
+            },
+        }
+    }
+}
+
+fn text(user_id: i64, text: &str) -> Update {
+    let from = json!({"id": user_id, "is_bot": false, "first_name": "Ann"});
+    common::message_update(json!({"id": -100, "type": "group"}), json!({"from": from, "text": text}))
+}
+
+fn texts(handled: Handled) -> Vec<String> {
+    let requests = match handled {
+        Handled::Continued(requests) | Handled::Ended(requests) => requests,
+        other => panic!("expected requests, got {:?}", other),
+    };
+    requests.iter().map(|request| request.params()["text"].as_str().unwrap().to_string()).collect()
+}
+
+#[test]
+fn moves_on_with_the_messages_of_the_user() {
+    let mut conversations = Conversations::new(MemoryStorage::new());
+    let ann = Key::new(-100, 1);
+    conversations.start(ann, SignUp::Name).unwrap();
+
+    // Other users of the chat aren't part of the conversation.
+    assert_eq!(conversations.handle(&text(2, "Bob")), Ok(Handled::Ignored));
+
+    let handled = conversations.handle(&text(1, "Ann")).unwrap();
+    match &handled {
+        Handled::Continued(requests) => {
+            let markup: PolymorphReplyMarkup = serde_json::from_value(requests[0].params()["reply_markup"].clone()).unwrap();
+            assert!(matches!(markup, PolymorphReplyMarkup::ForceReply(ref reply) if reply.selective == Some(true)));
+        }
+        other => panic!("expected a prompt, got {:?}", other),
+    }
+    assert_eq!(conversations.state(ann), Ok(Some(SignUp::Age { name: "Ann".to_string() })));
+    assert_eq!(texts(conversations.handle(&text(1, "old")).unwrap()), vec!["A number, please."]);
+    assert_eq!(texts(conversations.handle(&text(1, "30")).unwrap()), vec!["Welcome Ann, 30!"]);
+    assert_eq!(conversations.state(ann), Ok(None));
+    assert!(conversations.storage().is_empty());
+}
+
+#[test]
+fn times_out_and_cancels() {
+    let clock = VirtualClock::new();
+    let mut conversations = Conversations::with_clock(MemoryStorage::new(), &clock)
+        .timeout(Duration::from_secs(60))
+        .cancel_commands(vec!["stop"]);
+    let ann = Key::new(-100, 1);
+    conversations.start(ann, SignUp::Name).unwrap();
+    clock.advance(Duration::from_secs(59));
+    assert!(matches!(conversations.handle(&text(1, "Ann")), Ok(Handled::Continued(_))));
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(conversations.state(ann), Ok(None));
+    assert_eq!(conversations.handle(&text(1, "30")), Ok(Handled::TimedOut));
+    assert_eq!(conversations.handle(&text(1, "30")), Ok(Handled::Ignored));
+
+    // Only the configured commands cancel.
+    conversations.start(ann, SignUp::Name).unwrap();
+    assert!(matches!(conversations.handle(&text(1, "/cancel")), Ok(Handled::Continued(_))));
+    assert_eq!(conversations.handle(&text(1, "/stop@my_bot")), Ok(Handled::Cancelled));
+    assert_eq!(conversations.state(ann), Ok(None));
+
+    conversations.start(ann, SignUp::Name).unwrap();
+    assert_eq!(conversations.cancel(ann), Ok(true));
+    assert_eq!(conversations.cancel(ann), Ok(false));
+}
+
+#[test]
+fn keeps_states_in_json_files() {
+    let path = std::env::temp_dir().join(format!("conversations-{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let clock = VirtualClock::new();
+    let ann = Key::new(-100, 1);
+    let bob = Key::new(-100, 2);
+
+    let mut conversations = Conversations::with_clock(JsonFileStorage::open(&path).unwrap(), &clock);
+    conversations.start(ann, SignUp::Name).unwrap();
+    conversations.start(bob, SignUp::Name).unwrap();
+    conversations.handle(&text(1, "Ann")).unwrap();
+    conversations.cancel(bob).unwrap();
+
+    let conversations: Conversations<SignUp, _, _> = Conversations::with_clock(JsonFileStorage::open(&path).unwrap(), &clock);
+    assert_eq!(conversations.state(ann).unwrap(), Some(SignUp::Age { name: "Ann".to_string() }));
+    assert_eq!(conversations.state(bob).unwrap(), None);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn keeps_states_in_files_of_any_extension() {
+    let path = std::env::temp_dir().join(format!("conversations-{}.tmp", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let clock = VirtualClock::new();
+    let ann = Key::new(-100, 1);
+
+    let mut conversations = Conversations::with_clock(JsonFileStorage::open(&path).unwrap(), &clock);
+    conversations.start(ann, SignUp::Name).unwrap();
+    let conversations: Conversations<SignUp, _, _> = Conversations::with_clock(JsonFileStorage::open(&path).unwrap(), &clock);
+    assert_eq!(conversations.state(ann).unwrap(), Some(SignUp::Name));
+    assert!(!path.with_extension("tmp.tmp").exists());
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,152 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::now;
+use tg_bot_models::dispatch::filter::{self, Filter};
+use tg_bot_models::dispatch::{Dispatched, Dispatcher, Failures, Flow, HandlerError, Route};
+use tg_bot_models::{ChatType, Update, UpdateKind};
+
+fn message(chat: serde_json::Value, fields: serde_json::Value) -> Update {
+    let from = json!({"from": {"id": 42, "is_bot": false, "first_name": "Ann"}});
+    common::message_update(chat, common::with(from, fields))
+}
+
+fn private(fields: serde_json::Value) -> Update {
+    message(json!({"id": 42, "type": "private"}), fields)
+}
+
+fn callback_query(data: &str) -> Update {
+    serde_json::from_value(json!({
+        "update_id": 2,
+        "callback_query": {
+            "id": "q1",
+            "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+            "chat_instance": "1",
+            "data": data,
+        },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn filters_updates() {
+    let start = private(json!({"text": "/start@my_bot now"}));
+    assert!(filter::command("start").matches(&start));
+    assert!(filter::command_of("start", "My_Bot").matches(&start));
+    assert!(!filter::command_of("start", "other_bot").matches(&start));
+    assert!(!filter::command("help").matches(&start));
+    assert!(filter::kind(UpdateKind::Message).matches(&start));
+    assert!(filter::chat_type(ChatType::Private).and(filter::users(vec![42])).matches(&start));
+    assert!(!filter::chats(vec![1, 2]).or(filter::has_media()).matches(&start));
+    assert!(filter::has_media().not().matches(&start));
+
+    let photo = message(
+        json!({"id": -100, "type": "supergroup"}),
+        json!({"photo": [{"file_id": "a", "file_unique_id": "b", "width": 1, "height": 1}], "caption": "Cat"}),
+    );
+    assert!(filter::has_media().and(filter::chats(vec![-100])).matches(&photo));
+    assert!(!filter::chat_type(ChatType::Private).matches(&photo));
+
+    let query = callback_query("page:2");
+    assert!(filter::callback_data("page:").matches(&query));
+    assert!(!filter::callback_data("item:").matches(&query));
+    assert!(filter::users(vec![7]).matches(&query));
+    assert!(filter::kind(UpdateKind::CallbackQuery).matches(&query));
+    assert!(!filter::chat_type(ChatType::Private).matches(&query));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn filters_texts_on_regexes() {
+    let filter = filter::text_matches(regex::Regex::new(r"^\d+$").unwrap());
+    assert!(filter.matches(&private(json!({"text": "123"}))));
+    assert!(!filter.matches(&private(json!({"text": "12a"}))));
+}
+
+#[test]
+fn routes_by_priority_and_flow() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let logger = |name: &'static str, flow: Flow| {
+        let log = log.clone();
+        move |_: &Update| {
+            log.lock().unwrap().push(name);
+            Ok(flow)
+        }
+    };
+    let mut dispatcher = Dispatcher::new();
+    dispatcher
+        .route(Route::new(filter::any(), logger("fallback", Flow::Stop)).priority(-1))
+        .route(Route::new(filter::command("start"), logger("start", Flow::Stop)))
+        .route(Route::new(filter::kind(UpdateKind::Message), logger("log", Flow::Continue)).priority(10));
+
+    let dispatched = now(dispatcher.dispatch(private(json!({"text": "/start"}))));
+    assert_eq!(dispatched.handled_by, vec![2, 1]);
+    let dispatched = now(dispatcher.dispatch(callback_query("x")));
+    assert_eq!(dispatched.handled_by, vec![0]);
+    assert_eq!(*log.lock().unwrap(), vec!["log", "start", "fallback"]);
+
+    let dispatched = now(Dispatcher::new().dispatch(callback_query("x")));
+    assert!(dispatched.is_unhandled());
+}
+
+#[test]
+fn runs_async_handlers() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.route(Route::new_async(filter::callback_data("page:"), |update: Arc<Update>| async move {
+        assert_eq!(update.callback_query.as_ref().unwrap().id, "q1");
+        Ok(Flow::Continue)
+    }));
+    dispatcher.route(Route::new(filter::any(), |_: &Update| Ok(Flow::Stop)));
+    assert_eq!(now(dispatcher.dispatch(callback_query("page:1"))).handled_by, vec![0, 1]);
+}
+
+#[test]
+fn isolates_failing_handlers() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher
+        .route(Route::new(filter::command("fail"), |_: &Update| Err("no database".into())))
+        .route(Route::new(filter::command("panic"), |_: &Update| panic!("out of bounds")))
+        .route(Route::new_async(filter::callback_data("panic"), |_: Arc<Update>| async move {
+            panic!("async out of bounds")
+        }))
+        .route(Route::new(filter::any(), |_: &Update| Ok(Flow::Stop)));
+
+    let failures = |dispatched: &Dispatched| {
+        dispatched.failures.iter().map(|(index, err)| (*index, err.to_string())).collect::<Vec<_>>()
+    };
+    // The later routes still run after a failure.
+    let dispatched = now(dispatcher.dispatch(private(json!({"text": "/fail"}))));
+    assert_eq!(dispatched.handled_by, vec![0, 3]);
+    assert_eq!(failures(&dispatched), vec![(0, "no database".to_string())]);
+    let dispatched = now(dispatcher.dispatch(private(json!({"text": "/panic"}))));
+    assert_eq!(dispatched.handled_by, vec![1, 3]);
+    assert!(matches!(dispatched.failures[..], [(1, HandlerError::Panicked(ref message))] if message == "out of bounds"));
+    let dispatched = now(dispatcher.dispatch(callback_query("panic")));
+    assert_eq!(dispatched.handled_by, vec![2, 3]);
+    assert_eq!(failures(&dispatched), vec![(2, "handler panicked: async out of bounds".to_string())]);
+
+    // The dispatcher still works after the failures.
+    let dispatched = now(dispatcher.dispatch(private(json!({"text": "hi"}))));
+    assert_eq!(dispatched.handled_by, vec![3]);
+    assert!(dispatched.failures.is_empty());
+}
+
+#[test]
+fn collects_every_failure() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher
+        .route(Route::new(filter::any(), |_: &Update| Err("no database".into())))
+        .route(Route::new(filter::any(), |_: &Update| panic!("out of bounds")))
+        .route(Route::new(filter::any(), |_: &Update| Ok(Flow::Continue)));
+
+    let dispatched = now(dispatcher.dispatch(private(json!({"text": "hi"}))));
+    assert_eq!(dispatched.handled_by, vec![0, 1, 2]);
+    let failures = Failures(dispatched.failures);
+    assert_eq!(failures.0.iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(failures.to_string(), "no database; handler panicked: out of bounds");
+}
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use std::fs;
+use std::io::{self, Cursor};
+
+use common::now;
+use tg_bot_models::download::{DownloadError, Downloader, FileEndpoint, Source};
+use tg_bot_models::middleware::service_fn;
+use tg_bot_models::{File, PhotoSize, Request};
+
+fn file(size: Option<i64>, path: &str) -> File {
+    serde_json::from_value(json!({"file_id": "f", "file_unique_id": "u", "file_size": size, "file_path": path})).unwrap()
+}
+
+/// Serves `body` for the URL of `photos/file_1.jpg`.
+fn server(body: &'static [u8]) -> impl Fn(&str) -> io::Result<Cursor<&'static [u8]>> {
+    move |url: &str| {
+        assert_eq!(url, "https://api.telegram.org/file/bot123:ABC/photos/file_1.jpg");
+        Ok(Cursor::new(body))
+    }
+}
+
+#[test]
+fn builds_urls() {
+    let endpoint = FileEndpoint::telegram("123:ABC");
+    assert_eq!(endpoint.url("photos/file_1.jpg"), "https://api.telegram.org/file/bot123:ABC/photos/file_1.jpg");
+    let source = endpoint.source(&file(None, "photos/file_1.jpg")).unwrap();
+    assert_eq!(format!("{:?}", source), "Url(\"https://api.telegram.org/file/bot<token>/photos/file_1.jpg\")");
+    assert!(!format!("{:?}", endpoint).contains("ABC"));
+
+    let local = FileEndpoint::new("http://localhost:8081/", "123:ABC");
+    assert_eq!(local.url("voice/file_2.oga"), "http://localhost:8081/file/bot123:ABC/voice/file_2.oga");
+    assert_eq!(
+        local.source(&file(None, "/var/lib/telegram-bot-api/123:ABC/voice/file_2.oga")),
+        Some(Source::Local("/var/lib/telegram-bot-api/123:ABC/voice/file_2.oga".into()))
+    );
+    assert_eq!(local.source(&serde_json::from_value(json!({"file_id": "f", "file_unique_id": "u"})).unwrap()), None);
+}
+
+#[test]
+fn checks_sizes() {
+    let downloader = Downloader::new(FileEndpoint::telegram("123:ABC"), server(b"JPEG data"));
+    let mut out = Vec::new();
+    assert_eq!(downloader.download(&file(Some(9), "photos/file_1.jpg"), &mut out).unwrap(), 9);
+    assert_eq!(out, b"JPEG data");
+    // Without a size, the whole file is read.
+    assert_eq!(downloader.download(&file(None, "photos/file_1.jpg"), &mut Vec::new()).unwrap(), 9);
+
+    let err = downloader.download(&file(Some(4), "photos/file_1.jpg"), &mut Vec::new()).unwrap_err();
+    assert!(matches!(err, DownloadError::SizeMismatch { expected: 4, actual: 5 }));
+    let err = downloader.download(&file(Some(20), "photos/file_1.jpg"), &mut Vec::new()).unwrap_err();
+    assert!(matches!(err, DownloadError::SizeMismatch { expected: 20, actual: 9 }));
+
+    let downloader = downloader.max_size(5);
+    let err = downloader.download(&file(None, "photos/file_1.jpg"), &mut Vec::new()).unwrap_err();
+    assert!(matches!(err, DownloadError::TooLarge { max: 5 }));
+    let err = downloader.download(&file(Some(9), "photos/file_1.jpg"), &mut Vec::new()).unwrap_err();
+    assert_eq!(err.to_string(), "the file is over 5 bytes");
+}
+
+#[test]
+fn reads_files_of_local_servers() {
+    let path = std::env::temp_dir().join(format!("download-{}.oga", std::process::id()));
+    fs::write(&path, b"OggS").unwrap();
+    let never = |_: &str| -> io::Result<Cursor<Vec<u8>>> { panic!("local files aren't fetched") };
+    let downloader = Downloader::new(FileEndpoint::new("http://localhost:8081", "123:ABC"), never);
+    let mut out = Vec::new();
+    assert_eq!(downloader.download(&file(Some(4), path.to_str().unwrap()), &mut out).unwrap(), 4);
+    assert_eq!(out, b"OggS");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn gets_the_path_then_downloads() {
+    let requests = service_fn(|request: Request| async move {
+        assert_eq!(request.method(), "getFile");
+        match request.params()["file_id"].as_str() {
+            Some("photo") => Ok(json!({"file_id": "photo", "file_unique_id": "u", "file_path": "photos/file_1.jpg"})),
+            _ => Err("Bad Request: invalid file_id".to_string()),
+        }
+    });
+    let downloader = Downloader::new(FileEndpoint::telegram("123:ABC"), server(b"JPEG data"));
+    let photo: PhotoSize =
+        serde_json::from_value(json!({"file_id": "photo", "file_unique_id": "u", "width": 1, "height": 1, "file_size": 9}))
+            .unwrap();
+    let mut out = Vec::new();
+    assert_eq!(now(downloader.download_object(&requests, &photo, &mut out)).unwrap(), 9);
+    assert_eq!(out, b"JPEG data");
+
+    // The size of the object is checked when getFile doesn't tell it.
+    let mut photo = photo;
+    photo.file_size = Some(10);
+    let err = now(downloader.download_object(&requests, &photo, &mut Vec::new())).unwrap_err();
+    assert!(matches!(err, DownloadError::SizeMismatch { expected: 10, actual: 9 }));
+
+    photo.file_id = "gone".to_string();
+    let err = now(downloader.download_object(&requests, &photo, &mut Vec::new())).unwrap_err();
+    assert_eq!(err.to_string(), "getFile failed: Bad Request: invalid file_id");
+}
@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use tg_bot_models::{FileLike, Message, PassportFile};
+
+fn message(fields: serde_json::Value) -> Message {
+    common::message(json!({"id": 1, "type": "private", "first_name": "Ann"}), fields)
+}
+
+fn ids(message: &Message) -> Vec<&str> {
+    message.files().into_iter().map(FileLike::file_id).collect()
+}
+
+#[test]
+fn describes_files() {
+    let message = message(json!({
+        "document": {
+            "file_id": "doc",
+            "file_unique_id": "d",
+            "file_size": 2048,
+            "mime_type": "application/pdf",
+            "thumbnail": {"file_id": "thumb", "file_unique_id": "t", "width": 90, "height": 90},
+        },
+    }));
+    let document = message.document.as_ref().unwrap();
+    assert_eq!(document.file_id(), "doc");
+    assert_eq!(document.file_size(), Some(2048));
+    assert_eq!(document.mime_type(), Some("application/pdf"));
+    assert_eq!(document.thumb().map(FileLike::file_id), Some("thumb"));
+    assert_eq!(document.thumb().unwrap().thumb().map(FileLike::file_id), None);
+
+    let file: PassportFile =
+        serde_json::from_value(json!({"file_id": "scan", "file_unique_id": "s", "file_size": 10, "file_date": 1})).unwrap();
+    assert_eq!((file.file_size(), file.mime_type()), (Some(10), None));
+}
+
+#[test]
+fn lists_the_files_of_messages() {
+    let size = |id: &str| json!({"file_id": id, "file_unique_id": id, "width": 90, "height": 90});
+    let photo = message(json!({"photo": [size("small"), size("large")], "caption": "Hi"}));
+    assert_eq!(ids(&photo), ["small", "large"]);
+
+    let sticker = message(json!({
+        "sticker": {
+            "file_id": "sticker",
+            "file_unique_id": "s",
+            "type": "regular",
+            "width": 512,
+            "height": 512,
+            "is_animated": false,
+            "is_video": false,
+            "thumbnail": size("sticker thumb"),
+            "premium_animation": {"file_id": "premium", "file_unique_id": "p"},
+        },
+    }));
+    assert_eq!(ids(&sticker), ["sticker", "sticker thumb", "premium"]);
+
+    let gif = json!({"file_id": "gif", "file_unique_id": "g", "width": 1, "height": 1, "duration": 2});
+    let animation = message(json!({"animation": gif, "document": {"file_id": "gif", "file_unique_id": "g"}}));
+    assert_eq!(ids(&animation), ["gif"]);
+
+    let video = json!({"file_id": "video", "file_unique_id": "v", "width": 1, "height": 1, "duration": 3});
+    let paid = message(json!({
+        "paid_media": {
+            "star_count": 5,
+            "paid_media": [
+                {"type": "preview"},
+                {"type": "photo", "photo": [size("paid photo")]},
+                {"type": "video", "video": video},
+            ],
+        },
+    }));
+    assert_eq!(ids(&paid), ["paid photo", "video"]);
+
+    let scan = |id: &str| json!({"file_id": id, "file_unique_id": id, "file_size": 10, "file_date": 1});
+    let passport = message(json!({
+        "passport_data": {
+            "data": [{
+                "type": "passport",
+                "hash": "h",
+                "front_side": scan("front"),
+                "selfie": scan("selfie"),
+                "translation": [scan("translation")],
+            }],
+            "credentials": {"data": "d", "hash": "h", "secret": "s"},
+        },
+    }));
+    assert_eq!(ids(&passport), ["front", "selfie", "translation"]);
+
+    assert!(message(json!({"text": "no files"})).files().is_empty());
+}
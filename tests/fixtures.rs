@@ -0,0 +1,141 @@
+//! Golden fixtures: every file in `tests/fixtures/<Type>/` must deserialize
+//! into `Type` and serialize back to the same JSON, which catches changes to
+//! the wire format of the models.
+
+extern crate serde;
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tg_bot_models::*;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+fn round_trip<T: DeserializeOwned + Serialize>(path: &Path) -> Result<(), String> {
+    let expected: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let parsed: T = serde_json::from_value(expected.clone()).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let actual = serde_json::to_value(&parsed).unwrap();
+    if actual != expected {
+        return Err(format!("{}: serialized as {}", path.display(), actual));
+    }
+    Ok(())
+}
+
+macro_rules! fixtures {
+    ($($ty:ident),* $(,)*) => {
+        const TYPES: &[&str] = &[$(stringify!($ty)),*];
+
+        fn check(ty: &str, path: &Path) -> Result<(), String> {
+            match ty {
+                $(stringify!($ty) => round_trip::<$ty>(path),)*
+                _ => Err(format!("{}: no type named {}", path.display(), ty)),
+            }
+        }
+    };
+}
+
+fixtures!(
+    Update, WebhookInfo, User, Chat, ChatFullInfo, Message, MessageId, InaccessibleMessage,
+    MaybeInaccessibleMessage, MessageEntity, TextQuote, ExternalReplyInfo, ReplyParameters, MessageOrigin,
+    MessageOriginUser, MessageOriginHiddenUser, MessageOriginChat, MessageOriginChannel, PhotoSize, Animation,
+    Audio, Document, Story, Video, VideoNote, Voice, PaidMediaInfo, PaidMedia, PaidMediaPreview, PaidMediaPhoto,
+    PaidMediaVideo, Contact, Dice, PollOption, InputPollOption, PollAnswer, Poll, Location, Venue, WebAppData,
+    ProximityAlertTriggered, MessageAutoDeleteTimerChanged, ChatBoostAdded, BackgroundFill, BackgroundFillSolid,
+    BackgroundFillGradient, BackgroundFillFreeformGradient, BackgroundType, BackgroundTypeFill,
+    BackgroundTypeWallpaper, BackgroundTypePattern, BackgroundTypeChatTheme, ChatBackground, ForumTopicCreated,
+    ForumTopicClosed, ForumTopicEdited, ForumTopicReopened, GeneralForumTopicHidden, GeneralForumTopicUnhidden,
+    SharedUser, UsersShared, ChatShared, WriteAccessAllowed, VideoChatScheduled, VideoChatStarted, VideoChatEnded,
+    VideoChatParticipantsInvited, GiveawayCreated, Giveaway, GiveawayWinners, GiveawayCompleted, LinkPreviewOptions,
+    UserProfilePhotos, File, WebAppInfo, ReplyKeyboardMarkup, KeyboardButton, KeyboardButtonRequestUsers,
+    KeyboardButtonRequestChat, KeyboardButtonPollType, ReplyKeyboardRemove, InlineKeyboardMarkup,
+    InlineKeyboardButton, LoginUrl, SwitchInlineQueryChosenChat, CallbackQuery, ForceReply, ChatPhoto,
+    ChatInviteLink, ChatAdministratorRights, ChatMemberUpdated, ChatMember, ChatMemberOwner,
+    ChatMemberAdministrator, ChatMemberMember, ChatMemberRestricted, ChatMemberLeft, ChatMemberBanned,
+    ChatJoinRequest, ChatPermissions, Birthdate, BusinessIntro, BusinessLocation, BusinessOpeningHoursInterval,
+    BusinessOpeningHours, ChatLocation, ReactionType, ReactionTypeEmoji, ReactionTypeCustomEmoji, ReactionTypePaid,
+    ReactionCount, MessageReactionUpdated, MessageReactionCountUpdated, ForumTopic, BotCommand, BotCommandScope,
+    BotCommandScopeDefault, BotCommandScopeAllPrivateChats, BotCommandScopeAllGroupChats,
+    BotCommandScopeAllChatAdministrators, BotCommandScopeChat, BotCommandScopeChatAdministrators,
+    BotCommandScopeChatMember, BotName, BotDescription, BotShortDescription, MenuButton, MenuButtonCommands,
+    MenuButtonWebApp, MenuButtonDefault, ChatBoostSource, ChatBoostSourcePremium, ChatBoostSourceGiftCode,
+    ChatBoostSourceGiveaway, ChatBoost, ChatBoostUpdated, ChatBoostRemoved, UserChatBoosts, BusinessConnection,
+    BusinessMessagesDeleted, ResponseParameters, InputMedia, InputMediaPhoto, InputMediaVideo, InputMediaAnimation,
+    InputMediaAudio, InputMediaDocument, InputPaidMedia, InputPaidMediaPhoto, InputPaidMediaVideo, Sticker,
+    StickerSet, MaskPosition, InputSticker, InlineQuery, InlineQueryResultsButton, InlineQueryResult,
+    InlineQueryResultArticle, InlineQueryResultPhoto, InlineQueryResultGif, InlineQueryResultMpeg4Gif,
+    InlineQueryResultVideo, InlineQueryResultAudio, InlineQueryResultVoice, InlineQueryResultDocument,
+    InlineQueryResultLocation, InlineQueryResultVenue, InlineQueryResultContact, InlineQueryResultGame,
+    InlineQueryResultCachedPhoto, InlineQueryResultCachedGif, InlineQueryResultCachedMpeg4Gif,
+    InlineQueryResultCachedSticker, InlineQueryResultCachedDocument, InlineQueryResultCachedVideo,
+    InlineQueryResultCachedVoice, InlineQueryResultCachedAudio, InputMessageContent, InputTextMessageContent,
+    InputLocationMessageContent, InputVenueMessageContent, InputContactMessageContent, InputInvoiceMessageContent,
+    ChosenInlineResult, SentWebAppMessage, LabeledPrice, Invoice, ShippingAddress, OrderInfo, ShippingOption,
+    SuccessfulPayment, RefundedPayment, ShippingQuery, PreCheckoutQuery, PaidMediaPurchased, RevenueWithdrawalState,
+    RevenueWithdrawalStatePending, RevenueWithdrawalStateSucceeded, RevenueWithdrawalStateFailed,
+    TransactionPartner, TransactionPartnerUser, TransactionPartnerFragment, TransactionPartnerTelegramAds,
+    TransactionPartnerOther, StarTransaction, StarTransactions, PassportData, PassportFile,
+    EncryptedPassportElement, EncryptedCredentials, PassportElementError, PassportElementErrorDataField,
+    PassportElementErrorFrontSide, PassportElementErrorReverseSide, PassportElementErrorSelfie,
+    PassportElementErrorFile, PassportElementErrorFiles, PassportElementErrorTranslationFile,
+    PassportElementErrorTranslationFiles, PassportElementErrorUnspecified, Game, CallbackGame, GameHighScore,
+    GetUpdates, SetWebhook, DeleteWebhook, GetWebhookInfo, GetMe, LogOut, Close, SendMessage, ForwardMessage,
+    ForwardMessages, CopyMessage, CopyMessages, SendPhoto, SendAudio, SendDocument, SendVideo, SendAnimation,
+    SendVoice, SendVideoNote, SendPaidMedia, SendMediaGroup, SendLocation, SendVenue, SendContact, SendPoll,
+    SendDice, SendChatAction, SetMessageReaction, GetUserProfilePhotos, GetFile, BanChatMember, UnbanChatMember,
+    RestrictChatMember, PromoteChatMember, SetChatAdministratorCustomTitle, BanChatSenderChat, UnbanChatSenderChat,
+    SetChatPermissions, ExportChatInviteLink, CreateChatInviteLink, EditChatInviteLink,
+    CreateChatSubscriptionInviteLink, EditChatSubscriptionInviteLink, RevokeChatInviteLink, ApproveChatJoinRequest,
+    DeclineChatJoinRequest, SetChatPhoto, DeleteChatPhoto, SetChatTitle, SetChatDescription, PinChatMessage,
+    UnpinChatMessage, UnpinAllChatMessages, LeaveChat, GetChat, GetChatAdministrators, GetChatMemberCount,
+    GetChatMember, SetChatStickerSet, DeleteChatStickerSet, GetForumTopicIconStickers, CreateForumTopic,
+    EditForumTopic, CloseForumTopic, ReopenForumTopic, DeleteForumTopic, UnpinAllForumTopicMessages,
+    EditGeneralForumTopic, CloseGeneralForumTopic, ReopenGeneralForumTopic, HideGeneralForumTopic,
+    UnhideGeneralForumTopic, UnpinAllGeneralForumTopicMessages, AnswerCallbackQuery, GetUserChatBoosts,
+    GetBusinessConnection, SetMyCommands, DeleteMyCommands, GetMyCommands, SetMyName, GetMyName, SetMyDescription,
+    GetMyDescription, SetMyShortDescription, GetMyShortDescription, SetChatMenuButton, GetChatMenuButton,
+    SetMyDefaultAdministratorRights, GetMyDefaultAdministratorRights, EditMessageText, EditMessageCaption,
+    EditMessageMedia, EditMessageLiveLocation, StopMessageLiveLocation, EditMessageReplyMarkup, StopPoll,
+    DeleteMessage, DeleteMessages, SendSticker, GetStickerSet, GetCustomEmojiStickers, UploadStickerFile,
+    CreateNewStickerSet, AddStickerToSet, SetStickerPositionInSet, DeleteStickerFromSet, ReplaceStickerInSet,
+    SetStickerEmojiList, SetStickerKeywords, SetStickerMaskPosition, SetStickerSetTitle, SetStickerSetThumbnail,
+    SetCustomEmojiStickerSetThumbnail, DeleteStickerSet, AnswerInlineQuery, AnswerWebAppQuery, SendInvoice,
+    CreateInvoiceLink, AnswerShippingQuery, AnswerPreCheckoutQuery, GetStarTransactions, RefundStarPayment,
+    SetPassportDataErrors, SendGame, SetGameScore, GetGameHighScores,
+);
+
+#[test]
+fn fixtures_round_trip() {
+    let mut dirs: Vec<_> = fs::read_dir(FIXTURES).unwrap().map(|entry| entry.unwrap().path()).collect();
+    dirs.sort();
+    let mut count = 0;
+    let mut failures = Vec::new();
+    for dir in dirs {
+        let ty = dir.file_name().unwrap().to_str().unwrap().to_string();
+        for file in fs::read_dir(&dir).unwrap() {
+            count += 1;
+            if let Err(err) = check(&ty, &file.unwrap().path()) {
+                failures.push(err);
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} of {} fixtures failed:\n{}", failures.len(), count, failures.join("\n"));
+}
+
+#[test]
+fn every_type_has_fixtures() {
+    let spec: Value = serde_json::from_str(&fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/api/botapi.json")).unwrap()).unwrap();
+    let types = spec["types"].as_array().unwrap().iter().map(|ty| ty["name"].as_str().unwrap().to_string());
+    let methods = spec["methods"].as_array().unwrap().iter().map(|method| {
+        let name = method["name"].as_str().unwrap();
+        name[..1].to_uppercase() + &name[1..]
+    });
+    for name in types.chain(methods) {
+        assert!(TYPES.contains(&&name[..]), "{} is missing from the fixtures! list", name);
+        assert!(Path::new(FIXTURES).join(&name).is_dir(), "no fixtures for {}", name);
+    }
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::inline::{InlineAnswerBuilder, InlineAnswerError, MAX_RESULTS};
+use tg_bot_models::validate::Rule;
+use tg_bot_models::{InlineQuery, InlineQueryResultArticle, InlineQueryResultPhoto, InputTextMessageContent};
+
+fn query(offset: &str) -> InlineQuery {
+    serde_json::from_value(json!({
+        "id": "q",
+        "from": {"id": 1, "is_bot": false, "first_name": "Ann"},
+        "query": "cats",
+        "offset": offset,
+    }))
+    .unwrap()
+}
+
+fn article(id: usize) -> InlineQueryResultArticle {
+    InlineQueryResultArticle::new(id.to_string(), format!("Cat {}", id), InputTextMessageContent::new("meow"))
+}
+
+fn ids(builder: InlineAnswerBuilder) -> (Vec<String>, Option<String>) {
+    let answer = builder.build();
+    let ids = answer.results.iter().map(|result| result.id().unwrap().to_string()).collect();
+    (ids, answer.next_offset)
+}
+
+#[test]
+fn sets_the_type_of_results() {
+    let answer = InlineAnswerBuilder::new(&query(""))
+        .is_personal(true)
+        .result(InlineQueryResultPhoto::new("p", "https://example.com/cat.jpg", "https://example.com/thumb.jpg"))
+        .unwrap()
+        .build();
+    let json = serde_json::to_value(&answer).unwrap();
+    assert_eq!(json["inline_query_id"], "q");
+    assert_eq!(json["results"][0]["type"], "photo");
+    assert_eq!(json["is_personal"], true);
+}
+
+#[test]
+fn checks_ids_and_the_number_of_results() {
+    let builder = InlineAnswerBuilder::new(&query("")).result(article(1)).unwrap();
+    assert_eq!(builder.clone().result(article(1)).unwrap_err(), InlineAnswerError::DuplicateId("1".to_string()));
+
+    let long = InlineQueryResultArticle::new("x".repeat(65), "Long", InputTextMessageContent::new("meow"));
+    match builder.clone().result(long).unwrap_err() {
+        InlineAnswerError::Id(err) => {
+            assert_eq!(err.path(), "results[1].id");
+            assert_eq!(err.rule(), &Rule::Bytes { min: 1, max: 64, actual: 65 });
+        }
+        err => panic!("unexpected {:?}", err),
+    }
+
+    let full = (2..=MAX_RESULTS).try_fold(builder, |builder, id| builder.result(article(id))).unwrap();
+    let err = full.result(article(51)).unwrap_err();
+    assert_eq!(err.to_string(), "an answer has at most 50 results");
+}
+
+#[test]
+fn pages_through_results() {
+    let all = || (0..25).map(article);
+    let (first, next) = ids(InlineAnswerBuilder::new(&query("")).page_size(10).page(all()).unwrap());
+    assert_eq!(first.len(), 10);
+    assert_eq!((&first[0][..], next.as_deref()), ("0", Some("10")));
+
+    let (third, next) = ids(InlineAnswerBuilder::new(&query("20")).page_size(10).page(all()).unwrap());
+    assert_eq!(third, ["20", "21", "22", "23", "24"]);
+    assert_eq!(next, None);
+
+    // Results added first take places of the page.
+    let builder = InlineAnswerBuilder::new(&query("")).page_size(10).result(article(100)).unwrap();
+    let (ids, next) = ids(builder.page(all()).unwrap());
+    assert_eq!((ids.len(), &ids[9][..], next.as_deref()), (10, "8", Some("9")));
+
+    // A page full of other results still has one paged result.
+    let builder = InlineAnswerBuilder::new(&query("9")).page_size(2).result(article(100)).unwrap();
+    let answer = builder.result(article(101)).unwrap().page(all()).unwrap().build();
+    assert_eq!((answer.results.len(), answer.results[2].id(), answer.next_offset.as_deref()), (3, Some("9"), Some("10")));
+
+    // Offsets the builder didn't give start over.
+    assert_eq!(InlineAnswerBuilder::new(&query("cursor")).offset(), 0);
+}
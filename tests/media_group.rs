@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use std::time::Duration;
+
+use tg_bot_models::media_group::{MediaGroups, Next, UnsupportedMedia};
+use tg_bot_models::ratelimit::VirtualClock;
+use tg_bot_models::{InputMedia, Message};
+
+fn message(message_id: i64, chat_id: i64, fields: serde_json::Value) -> Message {
+    common::message(json!({"id": chat_id, "type": "private"}), common::with(json!({"message_id": message_id}), fields))
+}
+
+fn photo(message_id: i64, group: &str) -> Message {
+    message(
+        message_id,
+        1,
+        json!({
+            "media_group_id": group,
+            "photo": [
+                {"file_id": "small", "file_unique_id": "s", "width": 90, "height": 60},
+                {"file_id": format!("large{}", message_id), "file_unique_id": "l", "width": 1280, "height": 853},
+            ],
+        }),
+    )
+}
+
+fn ids(next: Next) -> Vec<i64> {
+    match next {
+        Next::Ready(album) => album.messages.iter().map(|message| message.message_id).collect(),
+        other => panic!("expected an album, got {:?}", other),
+    }
+}
+
+#[test]
+fn waits_for_a_quiet_period() {
+    let clock = VirtualClock::new();
+    let mut groups = MediaGroups::with_clock(&clock, Duration::from_secs(1));
+    assert_eq!(groups.poll(), Next::Idle);
+
+    let alone = message(1, 1, json!({"text": "Hi"}));
+    assert_eq!(groups.push(alone.clone()), Some(alone));
+    assert_eq!(groups.push(photo(3, "a")), None);
+    clock.advance(Duration::from_millis(600));
+    assert_eq!(groups.push(photo(2, "a")), None);
+    assert_eq!(groups.push(photo(5, "b")), None);
+    assert_eq!(groups.len(), 2);
+
+    clock.advance(Duration::from_millis(600));
+    assert_eq!(groups.poll(), Next::Wait(Duration::from_millis(400)));
+    clock.advance(Duration::from_millis(400));
+    // Sorted, although they came out of order.
+    assert_eq!(ids(groups.poll()), vec![2, 3]);
+    assert_eq!(ids(groups.poll()), vec![5]);
+    assert_eq!(groups.poll(), Next::Idle);
+
+    groups.push(photo(6, "c"));
+    assert_eq!(groups.flush().len(), 1);
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn replaces_messages_received_twice() {
+    let clock = VirtualClock::new();
+    let mut groups = MediaGroups::with_clock(&clock, Duration::from_secs(1));
+    groups.push(photo(1, "a"));
+    groups.push(photo(2, "a"));
+    let mut edited = photo(1, "a");
+    edited.caption = Some("Holidays".to_string());
+    groups.push(edited);
+
+    clock.advance(Duration::from_secs(1));
+    match groups.poll() {
+        Next::Ready(album) => {
+            assert_eq!(album.messages.iter().map(|message| message.message_id).collect::<Vec<_>>(), vec![1, 2]);
+            assert_eq!(album.messages[0].caption.as_deref(), Some("Holidays"));
+        }
+        other => panic!("expected an album, got {:?}", other),
+    }
+}
+
+#[test]
+fn sends_albums_again() {
+    let clock = VirtualClock::new();
+    let mut groups = MediaGroups::with_clock(&clock, Duration::from_secs(1));
+    let mut first = photo(1, "a");
+    first.caption = Some("Holidays".to_string());
+    groups.push(first);
+    groups.push(message(
+        2,
+        1,
+        json!({
+            "media_group_id": "a",
+            "video": {"file_id": "video", "file_unique_id": "v", "width": 640, "height": 480, "duration": 9},
+        }),
+    ));
+    clock.advance(Duration::from_secs(1));
+    let album = match groups.poll() {
+        Next::Ready(album) => album,
+        other => panic!("expected an album, got {:?}", other),
+    };
+    assert_eq!(album.chat().id, 1);
+
+    let request = album.to_send_request(-100).unwrap();
+    assert_eq!(
+        serde_json::to_value(&request.media).unwrap(),
+        json!([
+            {"type": "photo", "media": "large1", "caption": "Holidays"},
+            {"type": "video", "media": "video", "width": 640, "height": 480, "duration": 9},
+        ])
+    );
+    assert!(matches!(request.media[1], InputMedia::InputMediaVideo(_)));
+
+    groups.push(message(3, 1, json!({"media_group_id": "b", "text": "?"})));
+    assert_eq!(groups.flush()[0].input_media(), Err(UnsupportedMedia { message_id: 3 }));
+}
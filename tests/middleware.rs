@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::now;
+use tg_bot_models::dispatch::{filter, Dispatcher, Flow, Route};
+use tg_bot_models::middleware::{service_fn, AccessLayer, I18nLayer, Incoming, LogLayer, Metrics, MetricsLayer,
+                                ReportLayer, Service, ServiceExt};
+use tg_bot_models::{GetMe, Request, SendMessage, Update};
+
+fn update(user_id: i64, language: &str, text: &str) -> Incoming {
+    let from = json!({"id": user_id, "is_bot": false, "first_name": "Ann", "language_code": language});
+    let mut update = common::message_update(json!({"id": user_id, "type": "private"}), json!({"from": from, "text": text}));
+    update.update_id = 9;
+    update.into()
+}
+
+#[test]
+fn sets_locales_and_drops_updates_of_other_users() {
+    let locales = Arc::new(Mutex::new(Vec::new()));
+    let seen = locales.clone();
+    let service = service_fn(move |incoming: Incoming| {
+        seen.lock().unwrap().push(incoming.locale.unwrap());
+        async { Ok::<(), String>(()) }
+    })
+    .with(I18nLayer::new(vec!["en", "pt-BR", "de"], "en"))
+    .with(AccessLayer::allow(vec![1, 2, 3, 4]));
+
+    for (user_id, language) in [(1, "de"), (2, "pt-br"), (3, "de-AT"), (4, "fr"), (5, "de")] {
+        now(service.call(update(user_id, language, "hi"))).unwrap();
+    }
+    assert_eq!(*locales.lock().unwrap(), vec!["de", "pt-BR", "de", "en"]);
+
+    let deny = AccessLayer::deny(vec![5]);
+    assert!(!deny.allows(update(5, "en", "hi").update.user()));
+    assert!(deny.allows(None));
+}
+
+#[test]
+fn logs_and_measures_requests() {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let log = lines.clone();
+    let metrics = Arc::new(Metrics::new());
+    let client = service_fn(|request: Request| async move {
+        match request.method() {
+            "getMe" => Ok(json!({"id": 1})),
+            _ => Err("Bad Request: chat not found".to_string()),
+        }
+    })
+    .with(MetricsLayer::new(metrics.clone()))
+    .with(LogLayer::new(move |line: &str| log.lock().unwrap().push(line.to_string())));
+
+    now(client.call(Request::new(&GetMe::new()))).unwrap();
+    now(client.call(Request::new(&GetMe::new()))).unwrap();
+    now(client.call(Request::new(&SendMessage::new(1, "Hi")))).unwrap_err();
+
+    assert_eq!((metrics.get("getMe").calls, metrics.get("getMe").errors), (2, 0));
+    assert_eq!((metrics.get("sendMessage").calls, metrics.get("sendMessage").errors), (1, 1));
+    assert_eq!(metrics.snapshot().len(), 2);
+    assert_eq!(*lines.lock().unwrap(), vec!["getMe: ok", "getMe: ok", "sendMessage: Bad Request: chat not found"]);
+}
+
+#[test]
+fn reports_failed_updates_to_the_admins() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let outbox = sent.clone();
+    let client = Arc::new(service_fn(move |request: Request| {
+        outbox.lock().unwrap().push(request);
+        async { Ok::<_, String>(json!(true)) }
+    }));
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let log = lines.clone();
+
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.route(Route::new(filter::command("fail"), |_: &Update| Err("no database".into())));
+    dispatcher.route(Route::new(filter::any(), |_: &Update| Ok(Flow::Stop)));
+    let updates = dispatcher
+        .with(ReportLayer::new(client, -100))
+        .with(LogLayer::new(move |line: &str| log.lock().unwrap().push(line.to_string())));
+
+    now(updates.call(update(1, "en", "hi"))).unwrap();
+    let err = now(updates.call(update(1, "en", "/fail"))).unwrap_err();
+    assert_eq!(err.to_string(), "no database");
+
+    let sent = sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].params()["chat_id"], json!(-100));
+    assert_eq!(sent[0].params()["text"], json!("Update 9 failed: no database"));
+    assert_eq!(
+        *lines.lock().unwrap(),
+        vec!["update 9 (Message) from user 1 in chat 1", "update 9 (Message) from user 1 in chat 1: no database"]
+    );
+}
+
+#[test]
+fn cuts_long_reports_short() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let outbox = sent.clone();
+    let client = Arc::new(service_fn(move |request: Request| {
+        outbox.lock().unwrap().push(request);
+        async { Ok::<_, String>(json!(true)) }
+    }));
+    let updates = service_fn(|_: Incoming| async { Err::<(), _>("é".repeat(5000)) }).with(ReportLayer::new(client, -100));
+
+    now(updates.call(update(1, "en", "hi"))).unwrap_err();
+    let text = sent.lock().unwrap()[0].params()["text"].as_str().unwrap().to_string();
+    assert_eq!(text.chars().count(), 4096);
+    assert!(text.starts_with("Update 9 failed: éé"));
+    assert!(text.ends_with("é…"));
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use tg_bot_models::migration::{MemoryStore, Migration, Migrations};
+use tg_bot_models::{ForwardMessage, Message, PolymorphChatId, Request, ReplyParameters, ResponseParameters, SendMessage};
+
+fn service_message(chat_id: i64, field: &str, other_id: i64) -> Message {
+    let mut fields = json!({});
+    fields[field] = json!(other_id);
+    common::message(json!({"id": chat_id, "type": "group"}), fields)
+}
+
+#[test]
+fn learns_from_service_messages() {
+    let mut migrations = Migrations::new();
+    let migration = Migration { from: -1, to: -1001 };
+    assert_eq!(migrations.learn_message(&service_message(-1, "migrate_to_chat_id", -1001)), Ok(Some(migration)));
+    // The supergroup announces the same migration, already known.
+    assert_eq!(migrations.learn_message(&service_message(-1001, "migrate_from_chat_id", -1)), Ok(None));
+    assert_eq!(migrations.learn_message(&service_message(-2, "message_id", 1)), Ok(None));
+    assert_eq!(migrations.resolve(-1), -1001);
+    assert_eq!(migrations.resolve(-2), -2);
+    assert_eq!(migrations.store().migrations, vec![migration]);
+}
+
+#[test]
+fn learns_from_error_responses() {
+    let mut migrations = Migrations::new();
+    let parameters: ResponseParameters = serde_json::from_value(json!({"migrate_to_chat_id": -1001})).unwrap();
+    let request = Request::new(&SendMessage::new(-1, "Hi"));
+    assert_eq!(migrations.learn_error(&request, &parameters), Ok(Some(Migration { from: -1, to: -1001 })));
+
+    let parameters: ResponseParameters = serde_json::from_value(json!({"retry_after": 5})).unwrap();
+    let request = Request::new(&SendMessage::new(-2, "Hi"));
+    assert_eq!(migrations.learn_error(&request, &parameters), Ok(None));
+}
+
+#[test]
+fn rewrites_chat_ids() {
+    let store = MemoryStore {
+        migrations: vec![Migration { from: -1, to: -1001 }],
+    };
+    let migrations = Migrations::load(store).unwrap();
+
+    let mut request = Request::new(&ForwardMessage::new(-1, -1, 7));
+    assert!(migrations.rewrite(&mut request));
+    assert_eq!(request.params()["chat_id"], json!(-1001));
+    assert_eq!(request.params()["from_chat_id"], json!(-1001));
+    assert!(!migrations.rewrite(&mut request));
+
+    let mut message = SendMessage::new("@channel", "Hi");
+    assert!(!migrations.rewrite_method(&mut message).unwrap());
+    let mut message = SendMessage::new(-1, "Hi");
+    assert!(migrations.rewrite_method(&mut message).unwrap());
+    assert_eq!(message.chat_id, PolymorphChatId::Integer(-1001));
+
+    let mut reply = SendMessage::new(5, "Hi").reply_parameters(ReplyParameters::new(3).chat_id(-1));
+    assert!(migrations.rewrite_method(&mut reply).unwrap());
+    assert_eq!(reply.chat_id, PolymorphChatId::Integer(5));
+    assert_eq!(reply.reply_parameters.unwrap().chat_id, Some(PolymorphChatId::Integer(-1001)));
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "passport")]
+
+extern crate aes;
+extern crate base64;
+extern crate cbc;
+extern crate rand;
+extern crate rsa;
+extern crate sha1;
+extern crate sha2;
+extern crate tg_bot_models;
+
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockEncryptMut, KeyIvInit};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rand::RngCore;
+use rsa::{Oaep, RsaPublicKey};
+use sha2::{Digest, Sha256, Sha512};
+
+use tg_bot_models::passport::{self, ElementData, FileCredentials, PassportError};
+use tg_bot_models::{EncryptedCredentials, EncryptedPassportElement, PassportElementType};
+
+const KEY: &str = include_str!("data/passport_key.pem");
+
+/// Encrypts `plain` the way Telegram does, returning `(data, hash, secret)`.
+fn encrypt(plain: &[u8]) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let mut rng = rand::thread_rng();
+    let mut secret = vec![0; 32];
+    rng.fill_bytes(&mut secret);
+    let padding = 32 + (16 - plain.len() % 16) % 16;
+    let mut data = vec![0; padding];
+    rng.fill_bytes(&mut data);
+    data[0] = padding as u8;
+    data.extend_from_slice(plain);
+    let hash = Sha256::digest(&data).to_vec();
+    let digest = Sha512::new().chain_update(&secret).chain_update(&hash).finalize();
+    let len = data.len();
+    cbc::Encryptor::<aes::Aes256>::new(digest[..32].into(), digest[32..48].into())
+        .encrypt_padded_mut::<NoPadding>(&mut data, len)
+        .unwrap();
+    (data, hash, secret)
+}
+
+fn element(ty: PassportElementType, data: Option<&[u8]>) -> EncryptedPassportElement {
+    EncryptedPassportElement {
+        ty,
+        data: data.map(|d| BASE64.encode(d)),
+        phone_number: None,
+        email: None,
+        files: None,
+        front_side: None,
+        reverse_side: None,
+        selfie: None,
+        translation: None,
+        hash: String::new(),
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn decrypts_credentials_elements_and_files() {
+    let key = passport::private_key_from_pem(KEY).unwrap();
+
+    let details = r#"{"first_name":"Ivan","last_name":"Ivanov","birth_date":"01.01.1990","gender":"male","country_code":"RU","residence_country_code":"RU","first_name_native":"Иван","last_name_native":"Иванов"}"#.as_bytes();
+    let (details_data, details_hash, details_secret) = encrypt(details);
+    let address = r#"{"street_line1":"Lenina 1","city":"Moscow","country_code":"RU","post_code":"101000"}"#.as_bytes();
+    let (address_data, address_hash, address_secret) = encrypt(address);
+    let scan = b"\xff\xd8\xff\xe0 not really a jpeg";
+    let (scan_data, scan_hash, scan_secret) = encrypt(scan);
+
+    let credentials = format!(
+        r#"{{"secure_data":{{"personal_details":{{"data":{{"data_hash":"{}","secret":"{}"}}}},"address":{{"data":{{"data_hash":"{}","secret":"{}"}}}},"utility_bill":{{"files":[{{"file_hash":"{}","secret":"{}"}}]}}}},"nonce":"abc"}}"#,
+        BASE64.encode(&details_hash),
+        BASE64.encode(&details_secret),
+        BASE64.encode(&address_hash),
+        BASE64.encode(&address_secret),
+        BASE64.encode(&scan_hash),
+        BASE64.encode(&scan_secret),
+    );
+    let (data, hash, secret) = encrypt(credentials.as_bytes());
+    let public = RsaPublicKey::from(&key);
+    let secret = public
+        .encrypt(&mut rand::thread_rng(), Oaep::new::<sha1::Sha1>(), &secret)
+        .unwrap();
+    let encrypted = EncryptedCredentials {
+        data: BASE64.encode(&data),
+        hash: BASE64.encode(&hash),
+        secret: BASE64.encode(&secret),
+        extra: Default::default(),
+    };
+
+    let credentials = encrypted.decrypt(&key).unwrap();
+    assert_eq!(credentials.nonce, "abc");
+
+    match element(PassportElementType::PersonalDetails, Some(&details_data)).decrypt_data(&credentials) {
+        Ok(Some(ElementData::PersonalDetails(details))) => {
+            assert_eq!(details.first_name_native, "Иван");
+            assert_eq!(details.middle_name, None);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match element(PassportElementType::Address, Some(&address_data)).decrypt_data(&credentials) {
+        Ok(Some(ElementData::ResidentialAddress(address))) => assert_eq!(address.city, "Moscow"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(element(PassportElementType::Email, None).decrypt_data(&credentials).unwrap().is_none());
+    let unknown = element(PassportElementType::Unknown("bank_card".to_string()), None);
+    assert!(unknown.decrypt_data(&credentials).unwrap().is_none());
+
+    let file_credentials = &credentials.secure_data.utility_bill.as_ref().unwrap().files.as_ref().unwrap()[0];
+    assert_eq!(passport::decrypt_file(&scan_data, file_credentials).unwrap(), scan);
+}
+
+#[test]
+fn rejects_tampered_data() {
+    let (mut data, hash, secret) = encrypt(b"{}");
+    data[0] ^= 1;
+    let credentials = FileCredentials {
+        file_hash: BASE64.encode(&hash),
+        secret: BASE64.encode(&secret),
+    };
+    match passport::decrypt_file(&data, &credentials) {
+        Err(PassportError::HashMismatch) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
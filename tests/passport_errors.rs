@@ -0,0 +1,89 @@
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::passport::{Credentials, InvalidPassportError, PassportIssue};
+use tg_bot_models::{EncryptedPassportElement, PassportElementErrorReverseSide, PassportElementType,
+                    SetPassportDataErrors};
+
+#[test]
+fn reverse_side_only_for_two_sided_documents() {
+    assert!(PassportElementErrorReverseSide::new(PassportElementType::IdentityCard, "h", "blurry").is_ok());
+    assert_eq!(
+        PassportElementErrorReverseSide::new(PassportElementType::Passport, "h", "blurry"),
+        Err(InvalidPassportError::UnsupportedSource {
+            ty: PassportElementType::Passport,
+            source: "reverse_side",
+        })
+    );
+}
+
+#[test]
+fn builds_request_from_issues() {
+    let credentials: Credentials = serde_json::from_str(
+        r#"{
+            "secure_data": {
+                "driver_license": {
+                    "data": {"data_hash": "ZGF0YQ==", "secret": "c2VjcmV0"},
+                    "front_side": {"file_hash": "ZnJvbnQ=", "secret": "c2VjcmV0"}
+                },
+                "utility_bill": {
+                    "files": [
+                        {"file_hash": "MQ==", "secret": "c2VjcmV0"},
+                        {"file_hash": "Mg==", "secret": "c2VjcmV0"}
+                    ]
+                }
+            },
+            "nonce": "n"
+        }"#,
+    )
+    .unwrap();
+    let license: EncryptedPassportElement =
+        serde_json::from_str(r#"{"type": "driver_license", "data": "", "hash": "ZWxlbWVudA=="}"#).unwrap();
+    let bill: EncryptedPassportElement =
+        serde_json::from_str(r#"{"type": "utility_bill", "files": [], "hash": "YmlsbA=="}"#).unwrap();
+
+    let request = SetPassportDataErrors::from_issues(
+        7,
+        &credentials,
+        vec![
+            (&license, PassportIssue::DataField {
+                field_name: "document_no".to_string(),
+                message: "Wrong number".to_string(),
+            }),
+            (&license, PassportIssue::FrontSide("Blurry".to_string())),
+            (&bill, PassportIssue::File { index: 1, message: "Expired".to_string() }),
+            (&bill, PassportIssue::Unspecified("Outdated".to_string())),
+        ],
+    )
+    .unwrap();
+
+    let errors: Vec<serde_json::Value> = request
+        .errors
+        .iter()
+        .map(|error| match error {
+            tg_bot_models::PassportElementError::PassportElementErrorDataField(e) => serde_json::to_value(e),
+            tg_bot_models::PassportElementError::PassportElementErrorFrontSide(e) => serde_json::to_value(e),
+            tg_bot_models::PassportElementError::PassportElementErrorFile(e) => serde_json::to_value(e),
+            tg_bot_models::PassportElementError::PassportElementErrorUnspecified(e) => serde_json::to_value(e),
+            other => panic!("unexpected error {:?}", other),
+        })
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        serde_json::Value::Array(errors),
+        serde_json::json!([
+            {"source": "data", "type": "driver_license", "field_name": "document_no", "data_hash": "ZGF0YQ==", "message": "Wrong number"},
+            {"source": "front_side", "type": "driver_license", "file_hash": "ZnJvbnQ=", "message": "Blurry"},
+            {"source": "file", "type": "utility_bill", "file_hash": "Mg==", "message": "Expired"},
+            {"source": "unspecified", "type": "utility_bill", "element_hash": "YmlsbA==", "message": "Outdated"}
+        ])
+    );
+
+    assert_eq!(
+        SetPassportDataErrors::from_issues(7, &credentials, vec![(&bill, PassportIssue::Selfie("x".to_string()))]),
+        Err(InvalidPassportError::MissingHash {
+            ty: PassportElementType::UtilityBill,
+            source: "selfie",
+        })
+    );
+}
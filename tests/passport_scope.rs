@@ -0,0 +1,85 @@
+extern crate tg_bot_models;
+
+use tg_bot_models::passport::{PassportRequest, PassportScope, PassportScopeElementOne,
+                              PassportScopeElementOneOfSeveral, ScopeError};
+use tg_bot_models::PassportElementType;
+
+const PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----\nMIIB\n-----END PUBLIC KEY-----";
+
+#[test]
+fn generates_link() {
+    let mut details = PassportScopeElementOne::new(PassportElementType::PersonalDetails);
+    details.native_names = Some(true);
+    let scope = PassportScope::new(vec![details.into()]);
+    let link = PassportRequest::new(42, scope, PUBLIC_KEY, "nonce")
+        .callback_url("https://example.com/?a=1")
+        .link()
+        .unwrap();
+    assert_eq!(
+        link,
+        "tg://resolve?domain=telegrampassport&bot_id=42\
+         &scope=%7B%22data%22%3A%5B%7B%22type%22%3A%22personal_details%22%2C%22native_names%22%3Atrue%7D%5D%2C%22v%22%3A1%7D\
+         &public_key=-----BEGIN%20PUBLIC%20KEY-----%0AMIIB%0A-----END%20PUBLIC%20KEY-----\
+         &nonce=nonce&callback_url=https%3A%2F%2Fexample.com%2F%3Fa%3D1"
+    );
+}
+
+#[test]
+fn rejects_invalid_scopes() {
+    let mut email = PassportScopeElementOne::new(PassportElementType::Email);
+    email.selfie = Some(true);
+    assert_eq!(
+        PassportScope::new(vec![email.into()]).validate(),
+        Err(ScopeError::OptionNotAvailable { ty: PassportElementType::Email, option: "selfie" })
+    );
+
+    let documents = PassportScopeElementOneOfSeveral::new(vec![
+        PassportScopeElementOne::new(PassportElementType::Passport),
+        PassportScopeElementOne::new(PassportElementType::UtilityBill),
+    ]);
+    assert_eq!(
+        PassportScope::new(vec![documents.into()]).validate(),
+        Err(ScopeError::MixedOneOf(PassportElementType::UtilityBill))
+    );
+
+    let twice = PassportScope::new(vec![
+        PassportScopeElementOne::new(PassportElementType::Passport).into(),
+        PassportScopeElementOneOfSeveral::new(vec![
+            PassportScopeElementOne::new(PassportElementType::DriverLicense),
+            PassportScopeElementOne::new(PassportElementType::Passport),
+        ])
+        .into(),
+    ]);
+    assert_eq!(twice.validate(), Err(ScopeError::DuplicateType(PassportElementType::Passport)));
+
+    let single = PassportScopeElementOneOfSeveral::new(vec![PassportScopeElementOne::new(PassportElementType::Passport)]);
+    assert_eq!(
+        PassportScope::new(vec![single.into()]).validate(),
+        Err(ScopeError::SingleOneOf(PassportElementType::Passport))
+    );
+
+    let mut contacts = PassportScopeElementOneOfSeveral::new(vec![
+        PassportScopeElementOne::new(PassportElementType::Email),
+        PassportScopeElementOne::new(PassportElementType::PhoneNumber),
+    ]);
+    contacts.translation = Some(true);
+    assert_eq!(
+        PassportScope::new(vec![contacts.into()]).validate(),
+        Err(ScopeError::OptionNotAvailable { ty: PassportElementType::Email, option: "translation" })
+    );
+
+    let mut bills = PassportScopeElementOneOfSeveral::new(vec![
+        PassportScopeElementOne::new(PassportElementType::UtilityBill),
+        PassportScopeElementOne::new(PassportElementType::BankStatement),
+    ]);
+    bills.translation = Some(true);
+    assert_eq!(PassportScope::new(vec![bills.clone().into()]).validate(), Ok(()));
+    bills.selfie = Some(true);
+    assert_eq!(
+        PassportScope::new(vec![bills.into()]).validate(),
+        Err(ScopeError::OptionNotAvailable { ty: PassportElementType::UtilityBill, option: "selfie" })
+    );
+
+    let scope = PassportScope::new(vec![PassportScopeElementOne::new(PassportElementType::PhoneNumber).into()]);
+    assert_eq!(PassportRequest::new(1, scope, PUBLIC_KEY, "").link(), Err(ScopeError::EmptyNonce));
+}
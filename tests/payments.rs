@@ -0,0 +1,207 @@
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::payments::{Checkout, CheckoutError, Currency, InvoiceError, Money, MoneyError, Order,
+                              OrderStage};
+use tg_bot_models::{PolymorphChatId, PreCheckoutQuery, SendInvoice, ShippingAddress, ShippingOption,
+                    ShippingQuery, SuccessfulPayment};
+
+#[test]
+fn formats_amounts() {
+    assert_eq!(Money::new(145, Currency::Usd).to_string(), "$1.45");
+    assert_eq!(Money::new(123456789, Currency::Usd).to_string(), "$1,234,567.89");
+    assert_eq!(Money::new(-5, Currency::Usd).to_string(), "-$0.05");
+    assert_eq!(Money::new(1235, Currency::Jpy).to_string(), "¥1,235");
+    assert_eq!(Money::new(123450, Currency::Eur).to_string(), "1 234,50 €");
+    assert_eq!(Money::new(1500, Currency::Kwd).to_string(), "KWD 1.500");
+}
+
+#[test]
+fn parses_amounts() {
+    assert_eq!(Money::parse("12.5", Currency::Usd), Ok(Money::new(1250, Currency::Usd)));
+    assert_eq!(Money::parse("1,234", Currency::Kwd), Ok(Money::new(1234000, Currency::Kwd)));
+    assert_eq!(Money::parse("1,234,567.89", Currency::Usd), Ok(Money::new(123456789, Currency::Usd)));
+    assert_eq!(Money::parse("12,34", Currency::Usd), Err(MoneyError::InvalidAmount("12,34".to_string())));
+    assert_eq!(Money::parse_with("1,234", Currency::Kwd, ','), Ok(Money::new(1234, Currency::Kwd)));
+    assert_eq!(Money::parse_with("1.234,5", Currency::Eur, ','), Ok(Money::new(123450, Currency::Eur)));
+    assert_eq!(Money::parse("700", Currency::Jpy), Ok(Money::new(700, Currency::Jpy)));
+    assert_eq!(Money::parse("7.5", Currency::Jpy), Err(MoneyError::InvalidAmount("7.5".to_string())));
+    assert_eq!("XXX".parse::<Currency>().map_err(|e| e.0), Err("XXX".to_string()));
+    assert_eq!(Currency::Clf.exponent(), 4);
+}
+
+fn invoice() -> SendInvoice {
+    SendInvoice {
+        chat_id: PolymorphChatId::Integer(1),
+        message_thread_id: None,
+        title: "T-shirt".to_string(),
+        description: "Black T-shirt".to_string(),
+        payload: "order-1".to_string(),
+        provider_token: Some("token".to_string()),
+        currency: "USD".to_string(),
+        prices: vec![
+            Money::new(1999, Currency::Usd).labeled("T-shirt"),
+            Money::new(-500, Currency::Usd).labeled("Discount"),
+        ],
+        max_tip_amount: None,
+        suggested_tip_amounts: None,
+        start_parameter: Some("buy".to_string()),
+        provider_data: None,
+        photo_url: None,
+        photo_size: None,
+        photo_width: None,
+        photo_height: None,
+        need_name: None,
+        need_phone_number: None,
+        need_email: None,
+        need_shipping_address: None,
+        send_phone_number_to_provider: None,
+        send_email_to_provider: None,
+        is_flexible: None,
+        disable_notification: None,
+        protect_content: None,
+        message_effect_id: None,
+        reply_parameters: None,
+        reply_markup: None,
+    }
+}
+
+#[test]
+fn validates_invoices() {
+    let valid = invoice();
+    assert_eq!(valid.check_invoice(), Ok(()));
+    assert_eq!(valid.total(), Ok(Money::new(1499, Currency::Usd)));
+
+    let mut flexible = invoice();
+    flexible.is_flexible = Some(true);
+    assert_eq!(flexible.check_invoice(), Err(InvoiceError::FlexibleWithoutShippingAddress));
+    flexible.need_shipping_address = Some(true);
+    assert_eq!(flexible.check_invoice(), Ok(()));
+
+    let mut long_title = invoice();
+    long_title.title = "ж".repeat(33);
+    assert_eq!(long_title.check_invoice(), Err(InvoiceError::TitleLength(33)));
+
+    let mut free = invoice();
+    free.prices[1].amount = -1999;
+    assert_eq!(free.check_invoice(), Err(InvoiceError::NonPositiveTotal(0)));
+
+    let mut unknown = invoice();
+    unknown.currency = "ABC".to_string();
+    assert_eq!(unknown.check_invoice(), Err(InvoiceError::UnknownCurrency("ABC".to_string())));
+
+    let mut stars = invoice();
+    stars.currency = "XTR".to_string();
+    assert_eq!(stars.check_invoice(), Err(InvoiceError::StarsPriceCount(2)));
+    stars.prices = vec![Money::new(50, Currency::Xtr).labeled("T-shirt")];
+    assert_eq!(stars.check_invoice(), Err(InvoiceError::ProviderToken));
+    stars.provider_token = None;
+    assert_eq!(stars.check_invoice(), Ok(()));
+    assert_eq!(stars.total().map(|total| total.to_string()), Ok("⭐ 50".to_string()));
+}
+
+fn shipping(_: &Order, address: &ShippingAddress) -> Result<Vec<ShippingOption>, String> {
+    if address.country_code != "US" {
+        return Err("We only ship to the US".to_string());
+    }
+    Ok(vec![ShippingOption {
+        id: "ups".to_string(),
+        title: "UPS".to_string(),
+        prices: vec![Money::new(700, Currency::Usd).labeled("Delivery")],
+        extra: Default::default(),
+    }])
+}
+
+#[test]
+fn tracks_checkout() {
+    let mut checkout = Checkout::new(shipping, |_: &Order, _: &PreCheckoutQuery| Ok(()));
+    let mut flexible = invoice();
+    flexible.is_flexible = Some(true);
+    flexible.need_shipping_address = Some(true);
+    checkout.invoice(flexible.clone()).unwrap();
+    assert_eq!(checkout.invoice(flexible), Err(CheckoutError::DuplicatePayload("order-1".to_string())));
+
+    let user = r#"{"id": 5, "is_bot": false, "first_name": "Ann"}"#;
+    let query: ShippingQuery = serde_json::from_str(&format!(
+        r#"{{"id": "s1", "from": {}, "invoice_payload": "order-1", "shipping_address": {{
+            "country_code": "DE", "state": "", "city": "Berlin", "street_line1": "Main 1",
+            "street_line2": "", "post_code": "10115"}}}}"#,
+        user
+    ))
+    .unwrap();
+    let answer = checkout.shipping_query(&query);
+    assert!(!answer.ok);
+    assert_eq!(answer.error_message.as_deref(), Some("We only ship to the US"));
+
+    let mut query = query;
+    query.shipping_address.country_code = "US".to_string();
+    let answer = checkout.shipping_query(&query);
+    assert!(answer.ok);
+    assert_eq!(answer.shipping_options.unwrap()[0].id, "ups");
+    assert_eq!(checkout.order("order-1").unwrap().stage, OrderStage::ShippingQuoted);
+
+    let pre_checkout = |total: i64| -> PreCheckoutQuery {
+        serde_json::from_str(&format!(
+            r#"{{"id": "p1", "from": {}, "currency": "USD", "total_amount": {},
+                "invoice_payload": "order-1", "shipping_option_id": "ups"}}"#,
+            user, total
+        ))
+        .unwrap()
+    };
+    assert!(!checkout.pre_checkout_query(&pre_checkout(1499)).ok);
+    assert!(checkout.pre_checkout_query(&pre_checkout(2199)).ok);
+
+    let payment: SuccessfulPayment = serde_json::from_str(
+        r#"{"currency": "USD", "total_amount": 2199, "invoice_payload": "order-1",
+            "shipping_option_id": "ups", "telegram_payment_charge_id": "tg-1",
+            "provider_payment_charge_id": "pr-1"}"#,
+    )
+    .unwrap();
+    let record = checkout.successful_payment(&payment).unwrap();
+    assert_eq!(record.total, Money::new(2199, Currency::Usd));
+    assert_eq!(record.tip, Money::new(0, Currency::Usd));
+    assert_eq!(record.user.unwrap().id, 5);
+    assert_eq!(record.shipping_option.unwrap().id, "ups");
+    assert_eq!((record.telegram_payment_charge_id.as_str(), record.provider_payment_charge_id.as_str()), ("tg-1", "pr-1"));
+    assert!(checkout.order("order-1").is_none());
+}
+
+#[test]
+fn accepts_tips_up_to_the_maximum() {
+    let mut checkout = Checkout::new(shipping, |_: &Order, _: &PreCheckoutQuery| Ok(()));
+    let mut tipped = invoice();
+    tipped.max_tip_amount = Some(300);
+    tipped.suggested_tip_amounts = Some(vec![100, 300]);
+    checkout.invoice(tipped).unwrap();
+
+    let pre_checkout = |total: i64| -> PreCheckoutQuery {
+        serde_json::from_str(&format!(
+            r#"{{"id": "p1", "from": {{"id": 5, "is_bot": false, "first_name": "Ann"}}, "currency": "USD",
+                "total_amount": {}, "invoice_payload": "order-1"}}"#,
+            total
+        ))
+        .unwrap()
+    };
+    assert!(!checkout.pre_checkout_query(&pre_checkout(1400)).ok);
+    assert!(!checkout.pre_checkout_query(&pre_checkout(1800)).ok);
+    assert!(checkout.pre_checkout_query(&pre_checkout(1599)).ok);
+
+    let payment = |total: i64| -> SuccessfulPayment {
+        serde_json::from_str(&format!(
+            r#"{{"currency": "USD", "total_amount": {}, "invoice_payload": "order-1",
+                "telegram_payment_charge_id": "tg-1", "provider_payment_charge_id": "pr-1"}}"#,
+            total
+        ))
+        .unwrap()
+    };
+    assert_eq!(
+        checkout.successful_payment(&payment(1800)),
+        Err(CheckoutError::AmountMismatch {
+            expected: Money::new(1499, Currency::Usd),
+            actual: 1800,
+            currency: "USD".to_string(),
+        })
+    );
+    let record = checkout.successful_payment(&payment(1599)).unwrap();
+    assert_eq!((record.total, record.tip), (Money::new(1599, Currency::Usd), Money::new(100, Currency::Usd)));
+}
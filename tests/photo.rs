@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::{ChatPhoto, PhotoSize, PhotoSizes};
+
+fn size(id: &str, width: i64, height: i64, bytes: Option<i64>) -> PhotoSize {
+    serde_json::from_value(json!({
+        "file_id": id,
+        "file_unique_id": id,
+        "width": width,
+        "height": height,
+        "file_size": bytes,
+    }))
+    .unwrap()
+}
+
+fn id(size: Option<&PhotoSize>) -> Option<&str> {
+    size.map(|size| &size.file_id[..])
+}
+
+#[test]
+fn picks_sizes() {
+    let sizes = [
+        size("m", 320, 213, Some(20_000)),
+        size("s", 90, 60, Some(1_500)),
+        size("x", 1280, 853, Some(120_000)),
+        size("l", 800, 533, None),
+    ];
+    assert_eq!(id(sizes.largest()), Some("x"));
+    assert_eq!(id(sizes.smallest()), Some("s"));
+    assert_eq!(id(sizes.fitting(800, 800)), Some("l"));
+    assert_eq!(id(sizes.fitting(320, 200)), Some("s"));
+    assert_eq!(id(sizes.fitting(50, 50)), None);
+    // The size of `l` isn't known.
+    assert_eq!(id(sizes.within_bytes(100_000)), Some("m"));
+    assert_eq!(id(sizes.within_bytes(1_000)), None);
+    assert_eq!(id(Vec::<PhotoSize>::new().largest()), None);
+}
+
+#[test]
+fn dedupes_sizes() {
+    let sizes = [size("b", 800, 533, None), size("a", 90, 60, None), size("b", 800, 533, None)];
+    let deduped: Vec<&str> = sizes.deduped().iter().map(|size| &size.file_id[..]).collect();
+    assert_eq!(deduped, ["a", "b"]);
+}
+
+#[test]
+fn sizes_chat_photos() {
+    let photo: ChatPhoto = serde_json::from_value(json!({
+        "small_file_id": "small",
+        "small_file_unique_id": "s",
+        "big_file_id": "big",
+        "big_file_unique_id": "b",
+    }))
+    .unwrap();
+    let sizes = photo.sizes();
+    assert_eq!(id(sizes.largest()), Some("big"));
+    assert_eq!(id(sizes.fitting(200, 200)), Some("small"));
+    assert_eq!((sizes[0].width, sizes[1].height), (160, 640));
+}
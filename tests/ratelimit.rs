@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use std::time::Duration;
+
+use tg_bot_models::ratelimit::{Limits, Next, Rate, Scheduler, VirtualClock};
+use tg_bot_models::{AnswerCallbackQuery, Request, ResponseParameters, SendMessage};
+
+fn send(chat_id: i64, text: &str) -> Request {
+    Request::new(&SendMessage::new(chat_id, text))
+}
+
+fn text(next: Next) -> String {
+    match next {
+        Next::Ready(request) => request.params()["text"].as_str().unwrap().to_string(),
+        other => panic!("expected a request, got {:?}", other),
+    }
+}
+
+#[test]
+fn keeps_to_the_limit_of_private_chats() {
+    let clock = VirtualClock::new();
+    let mut scheduler = Scheduler::with_clock(&clock, Limits::default());
+    scheduler.push(send(1, "a"));
+    scheduler.push(send(1, "b"));
+    scheduler.push(send(2, "c"));
+
+    assert_eq!(text(scheduler.poll()), "a");
+    // The second message to chat 1 waits, the one to chat 2 doesn't.
+    assert_eq!(text(scheduler.poll()), "c");
+    assert_eq!(scheduler.poll(), Next::Wait(Duration::from_secs(1)));
+    clock.advance(Duration::from_millis(999));
+    assert_eq!(scheduler.poll(), Next::Wait(Duration::from_millis(1)));
+    clock.advance(Duration::from_millis(1));
+    assert_eq!(text(scheduler.poll()), "b");
+    assert_eq!(scheduler.poll(), Next::Idle);
+}
+
+#[test]
+fn keeps_to_the_limits_of_groups_and_of_the_bot() {
+    let clock = VirtualClock::new();
+    let limits = Limits {
+        global: Rate::new(3, Duration::from_secs(1)),
+        ..Limits::default()
+    };
+    let mut scheduler = Scheduler::with_clock(&clock, limits);
+    for i in 0..21 {
+        scheduler.push(send(-100, &i.to_string()));
+    }
+    for i in 0..3 {
+        assert_eq!(text(scheduler.poll()), i.to_string());
+    }
+    assert_eq!(scheduler.poll(), Next::Wait(Duration::from_secs(1)));
+
+    for i in 3..20 {
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(text(scheduler.poll()), i.to_string());
+    }
+    // 20 messages were sent to the group within the last minute, the first
+    // one 18 seconds ago.
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(scheduler.poll(), Next::Wait(Duration::from_secs(60 - 18)));
+    clock.advance(Duration::from_secs(42));
+    assert_eq!(text(scheduler.poll()), "20");
+}
+
+#[test]
+fn sends_answers_first() {
+    let clock = VirtualClock::new();
+    let mut scheduler = Scheduler::with_clock(&clock, Limits::default());
+    scheduler.push(send(1, "a"));
+    scheduler.push(Request::new(&AnswerCallbackQuery::new("q1")));
+    match scheduler.poll() {
+        Next::Ready(request) => assert_eq!(request.method(), "answerCallbackQuery"),
+        other => panic!("expected the answer, got {:?}", other),
+    }
+    assert_eq!(text(scheduler.poll()), "a");
+}
+
+#[test]
+fn waits_after_flood_errors() {
+    let clock = VirtualClock::new();
+    let mut scheduler = Scheduler::with_clock(&clock, Limits::default());
+    scheduler.push(send(-100, "a"));
+    scheduler.push(send(-100, "b"));
+    scheduler.push(send(-200, "c"));
+    let request = match scheduler.poll() {
+        Next::Ready(request) => request,
+        other => panic!("expected a request, got {:?}", other),
+    };
+
+    let parameters: ResponseParameters = serde_json::from_value(json!({"retry_after": 5})).unwrap();
+    assert_eq!(scheduler.flood_wait(request, &parameters), Ok(()));
+    assert_eq!(text(scheduler.poll()), "c");
+    assert_eq!(scheduler.poll(), Next::Wait(Duration::from_secs(5)));
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(text(scheduler.poll()), "a");
+    assert_eq!(text(scheduler.poll()), "b");
+
+    let parameters: ResponseParameters = serde_json::from_value(json!({"migrate_to_chat_id": -300})).unwrap();
+    assert!(scheduler.flood_wait(send(-100, "d"), &parameters).is_err());
+}
@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use tg_bot_models::{CallbackQuery, InlineKeyboardMarkup, Message, MessageTarget, PolymorphChatId,
+                    PolymorphFromChatId};
+
+fn message() -> Message {
+    let chat = json!({"id": -100, "type": "supergroup", "is_forum": true});
+    common::message(chat, json!({"message_id": 7, "message_thread_id": 3, "is_topic_message": true}))
+}
+
+fn callback_query(fields: serde_json::Value) -> CallbackQuery {
+    let query = json!({
+        "id": "q1",
+        "from": {"id": 42, "is_bot": false, "first_name": "Ann"},
+        "chat_instance": "1",
+    });
+    serde_json::from_value(common::with(query, fields)).unwrap()
+}
+
+#[test]
+fn replies_in_the_topic_of_the_message() {
+    let request = message().reply_text("Hi");
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"chat_id": -100, "message_thread_id": 3, "text": "Hi", "reply_parameters": {"message_id": 7}})
+    );
+}
+
+#[test]
+fn targets_the_message() {
+    let message = message();
+    let forward = message.forward_to("@archive");
+    assert_eq!(forward.chat_id, PolymorphChatId::String("@archive".to_string()));
+    assert_eq!(forward.from_chat_id, PolymorphFromChatId::Integer(-100));
+    assert_eq!(forward.message_id, 7);
+
+    let edit = message.edit_text("Edited");
+    assert_eq!(edit.target, MessageTarget::Chat { chat_id: PolymorphChatId::Integer(-100), message_id: 7 });
+    let delete = message.delete();
+    assert_eq!((delete.chat_id, delete.message_id), (PolymorphChatId::Integer(-100), 7));
+}
+
+#[test]
+fn edits_the_message_of_a_callback_query() {
+    let query = callback_query(json!({"message": {"message_id": 9, "date": 0, "chat": {"id": 5, "type": "private"}}}));
+    assert_eq!(serde_json::to_value(query.answer()).unwrap(), json!({"callback_query_id": "q1"}));
+    let edit = query.edit_reply_markup(InlineKeyboardMarkup::new(Vec::new())).unwrap();
+    assert_eq!(edit.target, MessageTarget::Chat { chat_id: PolymorphChatId::Integer(5), message_id: 9 });
+
+    let query = callback_query(json!({"inline_message_id": "im1"}));
+    let edit = query.edit_text("Edited").unwrap();
+    assert_eq!(edit.target, MessageTarget::Inline("im1".to_string()));
+
+    assert_eq!(callback_query(json!({})).edit_caption("Caption"), None);
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+mod common;
+
+use tg_bot_models::{Message, NotResendable, SendRequest};
+
+fn message(fields: serde_json::Value) -> Message {
+    common::message(json!({"id": -100, "type": "channel", "title": "Source"}), fields)
+}
+
+fn resent(fields: serde_json::Value) -> (&'static str, serde_json::Value) {
+    let request = message(fields).to_send_request(-200).unwrap().to_request();
+    (request.method(), serde_json::Value::Object(request.params().clone()))
+}
+
+#[test]
+fn resends_captions_and_keyboards() {
+    let keyboard = json!({"inline_keyboard": [[{"text": "Open", "url": "https://example.com"}]]});
+    let entities = json!([{"type": "bold", "offset": 0, "length": 4}]);
+    let (method, params) = resent(json!({
+        "photo": [
+            {"file_id": "small", "file_unique_id": "s", "width": 90, "height": 60},
+            {"file_id": "large", "file_unique_id": "l", "width": 1280, "height": 853},
+        ],
+        "caption": "Look here",
+        "caption_entities": entities,
+        "has_media_spoiler": true,
+        "reply_markup": keyboard,
+    }));
+    assert_eq!(method, "sendPhoto");
+    assert_eq!(
+        params,
+        json!({
+            "chat_id": -200,
+            "photo": "large",
+            "caption": "Look here",
+            "caption_entities": entities,
+            "has_spoiler": true,
+            "reply_markup": keyboard,
+        })
+    );
+
+    let (method, params) = resent(json!({"text": "Hi there", "entities": entities}));
+    assert_eq!(method, "sendMessage");
+    assert_eq!(params, json!({"chat_id": -200, "text": "Hi there", "entities": entities}));
+}
+
+#[test]
+fn resends_each_kind() {
+    let file = |extra: serde_json::Value| common::with(json!({"file_id": "f", "file_unique_id": "u"}), extra);
+    let location = json!({"latitude": 52.5, "longitude": 13.4});
+    let cases = vec![
+        (json!({"video": file(json!({"width": 1, "height": 1, "duration": 3}))}), "sendVideo"),
+        (json!({"animation": file(json!({"width": 1, "height": 1, "duration": 3}))}), "sendAnimation"),
+        (json!({"audio": file(json!({"duration": 3}))}), "sendAudio"),
+        (json!({"document": file(json!({}))}), "sendDocument"),
+        (json!({"voice": file(json!({"duration": 3}))}), "sendVoice"),
+        (json!({"video_note": file(json!({"length": 240, "duration": 3}))}), "sendVideoNote"),
+        (
+            json!({"sticker": file(json!({"type": "regular", "width": 512, "height": 512, "is_animated": false, "is_video": false}))}),
+            "sendSticker",
+        ),
+        (json!({"location": location}), "sendLocation"),
+        (json!({"location": location, "venue": {"location": location, "title": "Gate", "address": "Pariser Platz"}}), "sendVenue"),
+        (json!({"contact": {"phone_number": "+4930", "first_name": "Ann"}}), "sendContact"),
+    ];
+    for (fields, expected) in cases {
+        assert_eq!(resent(fields).0, expected);
+    }
+
+    let poll = json!({
+        "id": "p",
+        "question": "Which?",
+        "options": [{"text": "A", "voter_count": 3}, {"text": "B", "voter_count": 1}],
+        "total_voter_count": 4,
+        "is_closed": true,
+        "is_anonymous": true,
+        "type": "quiz",
+        "allows_multiple_answers": false,
+        "correct_option_id": 0,
+    });
+    let (method, params) = resent(json!({"poll": poll}));
+    assert_eq!(method, "sendPoll");
+    assert_eq!(params["options"], json!([{"text": "A"}, {"text": "B"}]));
+    assert_eq!(params["correct_option_id"], json!(0));
+    assert!(matches!(message(json!({"poll": poll})).to_send_request(-200), Ok(SendRequest::Poll(_))));
+}
+
+#[test]
+fn reports_what_cant_be_resent() {
+    let err = message(json!({"new_chat_title": "New"})).to_send_request(-200).unwrap_err();
+    assert_eq!(err, NotResendable { content: "a service message" });
+    assert_eq!(err.to_string(), "a message with a service message can't be sent again");
+
+    let invoice = json!({"title": "T", "description": "D", "start_parameter": "s", "currency": "XTR", "total_amount": 1});
+    assert_eq!(message(json!({"invoice": invoice})).to_send_request(-200).unwrap_err().content, "an invoice");
+    let dice = json!({"emoji": "🎲", "value": 6});
+    assert_eq!(
+        message(json!({"dice": dice})).to_send_request(-200).unwrap_err().content,
+        "a dice, whose value would change"
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "strict")]
+
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::{strict, Update};
+
+const UPDATE: &str = r#"{
+    "update_id": 1,
+    "message": {
+        "message_id": 2,
+        "date": 5,
+        "chat": {"id": 1, "type": "private", "first_name": "Ann"},
+        "text": "hi",
+        "entities": [{"type": "bold", "offset": 0, "length": 2}],
+        "forward_origin": {"type": "hidden_user", "date": 1, "sender_user_name": "Bob"}
+    }
+}"#;
+
+#[test]
+fn accepts_known_fields() {
+    let update: Update = strict::from_str(UPDATE).unwrap();
+    assert_eq!(update.message.unwrap().text.as_deref(), Some("hi"));
+}
+
+#[test]
+fn reports_the_path_of_unknown_fields() {
+    let json = UPDATE.replace(r#""offset": 0"#, r#""offset": 0, "colour": "red""#);
+    let err = strict::from_str::<Update>(&json).unwrap_err();
+    assert_eq!(err.path(), "message.entities[0].colour");
+    assert!(err.to_string().contains("unknown field `colour`"), "{}", err);
+
+    let json = UPDATE.replace(r#""sender_user_name": "Bob""#, r#""sender_user_name": "Bob", "is_bot": false"#);
+    let err = strict::from_str::<Update>(&json).unwrap_err();
+    assert_eq!(err.path(), "message.forward_origin.is_bot");
+    assert!(err.to_string().contains("is_bot: unknown field `is_bot`"), "{}", err);
+
+    let json = UPDATE.replace(r#""type": "hidden_user""#, r#""type": "robot""#);
+    let err = strict::from_str::<Update>(&json).unwrap_err();
+    assert_eq!(err.path(), "message.forward_origin");
+}
+
+#[test]
+fn rejects_unknown_enum_values() {
+    let json = UPDATE.replace(r#""type": "bold""#, r#""type": "sparkle""#);
+    let err = strict::from_str::<Update>(&json).unwrap_err();
+    assert_eq!(err.path(), "message.entities[0].type");
+}
+
+#[test]
+fn reports_paths_through_nested_unions() {
+    let json = r#"{
+        "update_id": 1,
+        "callback_query": {
+            "id": "c",
+            "from": {"id": 1, "is_bot": false, "first_name": "Ann"},
+            "chat_instance": "i",
+            "message": {
+                "message_id": 2,
+                "date": 5,
+                "chat": {"id": 1, "type": "private"},
+                "forward_origin": {"type": "hidden_user", "date": 1, "sender_user_name": "Bob", "is_bot": false}
+            }
+        }
+    }"#;
+    let err = strict::from_str::<Update>(json).unwrap_err();
+    assert_eq!(err.path(), "callback_query.message.forward_origin.is_bot");
+}
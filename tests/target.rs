@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use tg_bot_models::{EditMessageText, GetGameHighScores, MessageTarget, PolymorphChatId, SetGameScore};
+
+#[test]
+fn serializes_the_fields_of_the_target() {
+    let target = MessageTarget::Chat { chat_id: PolymorphChatId::Integer(-100), message_id: 7 };
+    assert_eq!(
+        serde_json::to_value(EditMessageText::new(target, "Edited")).unwrap(),
+        json!({"chat_id": -100, "message_id": 7, "text": "Edited"})
+    );
+    assert_eq!(
+        serde_json::to_value(GetGameHighScores::new(42, MessageTarget::Inline("im1".to_string()))).unwrap(),
+        json!({"user_id": 42, "inline_message_id": "im1"})
+    );
+}
+
+#[test]
+fn rejects_incomplete_and_ambiguous_targets() {
+    let request: EditMessageText = serde_json::from_value(json!({"inline_message_id": "im1", "text": "a"})).unwrap();
+    assert_eq!(request.target, MessageTarget::Inline("im1".to_string()));
+
+    for fields in &[
+        json!({"chat_id": -100, "text": "a"}),
+        json!({"chat_id": -100, "message_id": 7, "inline_message_id": "im1", "text": "a"}),
+        json!({"text": "a"}),
+    ] {
+        assert!(serde_json::from_value::<EditMessageText>(fields.clone()).is_err(), "{}", fields);
+    }
+}
+
+#[test]
+fn game_scores_only_take_integer_chat_ids() {
+    let request = SetGameScore::new(42, 100, MessageTarget::Chat { chat_id: -100, message_id: 7 });
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"user_id": 42, "score": 100, "chat_id": -100, "message_id": 7})
+    );
+    let request: GetGameHighScores = serde_json::from_value(json!({"user_id": 42, "chat_id": -100, "message_id": 7})).unwrap();
+    assert_eq!(request.target, MessageTarget::Chat { chat_id: -100, message_id: 7 });
+
+    let username = json!({"user_id": 42, "chat_id": "@games", "message_id": 7});
+    assert!(serde_json::from_value::<GetGameHighScores>(username).is_err());
+}
@@ -0,0 +1,78 @@
+extern crate serde_json;
+extern crate tg_bot_models;
+
+use serde_json::json;
+use tg_bot_models::{InlineQueryResult, MaybeInaccessibleMessage, MessageOrigin};
+
+#[test]
+fn unions_are_told_apart_by_their_tags() {
+    let origin: MessageOrigin = serde_json::from_value(json!({"type": "hidden_user", "date": 1, "sender_user_name": "Ann"})).unwrap();
+    assert!(matches!(origin, MessageOrigin::MessageOriginHiddenUser(_)));
+    assert_eq!(serde_json::to_value(&origin).unwrap()["type"], "hidden_user");
+
+    let cached: InlineQueryResult = serde_json::from_value(json!({"type": "photo", "id": "1", "photo_file_id": "f"})).unwrap();
+    assert!(matches!(cached, InlineQueryResult::InlineQueryResultCachedPhoto(_)));
+    let photo: InlineQueryResult =
+        serde_json::from_value(json!({"type": "photo", "id": "1", "photo_url": "u", "thumbnail_url": "t"})).unwrap();
+    assert!(matches!(photo, InlineQueryResult::InlineQueryResultPhoto(_)));
+
+    let chat = json!({"id": 1, "type": "private"});
+    let inaccessible: MaybeInaccessibleMessage =
+        serde_json::from_value(json!({"chat": chat, "message_id": 2, "date": 0})).unwrap();
+    assert!(matches!(inaccessible, MaybeInaccessibleMessage::InaccessibleMessage(_)));
+    let message: MaybeInaccessibleMessage = serde_json::from_value(json!({"chat": chat, "message_id": 2, "date": 5})).unwrap();
+    assert!(matches!(message, MaybeInaccessibleMessage::Message(_)));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn keeps_unknown_enum_values() {
+    let entity: tg_bot_models::MessageEntity =
+        serde_json::from_value(json!({"type": "sparkle", "offset": 0, "length": 2})).unwrap();
+    assert_eq!(entity.ty, tg_bot_models::MessageEntityType::Unknown("sparkle".to_string()));
+    assert_eq!(serde_json::to_value(&entity).unwrap()["type"], "sparkle");
+}
+
+#[cfg(not(all(feature = "preserve-unknown", not(feature = "strict"))))]
+#[test]
+fn unknown_tags_are_errors() {
+    let result: Result<MessageOrigin, _> = serde_json::from_value(json!({"type": "robot", "date": 1}));
+    assert!(result.is_err());
+}
+
+#[cfg(not(any(feature = "preserve-unknown", feature = "strict")))]
+#[test]
+fn drops_unknown_fields() {
+    let chat: tg_bot_models::Chat = serde_json::from_value(json!({"id": 1, "type": "private", "is_new": true})).unwrap();
+    assert!(chat.extra.is_empty());
+    assert_eq!(serde_json::to_value(&chat).unwrap(), json!({"id": 1, "type": "private"}));
+}
+
+#[cfg(all(feature = "preserve-unknown", not(feature = "strict")))]
+#[test]
+fn keeps_unknown_fields_and_values() {
+    let update = json!({
+        "update_id": 1,
+        "future_field": {"a": 1},
+        "message": {
+            "message_id": 2,
+            "date": 5,
+            "chat": {"id": 1, "type": "private", "is_new": true},
+            "text": "hi",
+            "entities": [{"type": "sparkle", "offset": 0, "length": 2}],
+            "forward_origin": {"type": "robot", "date": 1}
+        }
+    });
+    let parsed: tg_bot_models::Update = serde_json::from_value(update).unwrap();
+    assert_eq!(parsed.extra["future_field"], json!({"a": 1}));
+    let message = parsed.message.as_ref().unwrap();
+    assert_eq!(message.chat.extra["is_new"], true);
+    assert_eq!(message.entities.as_ref().unwrap()[0].ty, tg_bot_models::MessageEntityType::Unknown("sparkle".to_string()));
+    assert!(matches!(message.forward_origin, Some(MessageOrigin::Unknown(_))));
+
+    let again = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(again["future_field"], json!({"a": 1}));
+    assert_eq!(again["message"]["chat"]["is_new"], true);
+    assert_eq!(again["message"]["entities"][0]["type"], "sparkle");
+    assert_eq!(again["message"]["forward_origin"], json!({"type": "robot", "date": 1}));
+}
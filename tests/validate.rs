@@ -0,0 +1,82 @@
+extern crate tg_bot_models;
+
+use tg_bot_models::payments::InvoiceError;
+use tg_bot_models::validate::Rule;
+use tg_bot_models::{AnswerInlineQuery, GetUpdates, InlineKeyboardButton, InlineKeyboardMarkup, InlineQueryResult,
+                    InlineQueryResultArticle, InlineQueryResultsButton, InputMedia, InputMediaPhoto,
+                    InputTextMessageContent, LabeledPrice, SendInvoice, SendMediaGroup, SendMessage, SetWebhook,
+                    Validate, ValidationError};
+
+fn article(id: &str) -> InlineQueryResult {
+    InlineQueryResultArticle::new(id, "Title", InputTextMessageContent::new("Text")).into()
+}
+
+fn rule(result: Result<(), ValidationError>) -> (String, Rule) {
+    let err = result.unwrap_err();
+    (err.path().to_string(), err.rule().clone())
+}
+
+#[test]
+fn checks_lengths_of_texts() {
+    assert_eq!(SendMessage::new(1, "Hello").validate(), Ok(()));
+    assert_eq!(
+        rule(SendMessage::new(1, "").validate()),
+        ("text".to_string(), Rule::Characters { min: 1, max: 4096, actual: 0 })
+    );
+    let long = "é".repeat(4097);
+    assert!(SendMessage::new(1, &long[..]).validate().is_err());
+    // The markup of formatted text doesn't count.
+    assert_eq!(SendMessage::new(1, &long[..]).parse_mode("HTML").validate(), Ok(()));
+}
+
+#[test]
+fn checks_numbers_and_arrays() {
+    assert_eq!(GetUpdates::new().limit(100).validate(), Ok(()));
+    assert_eq!(rule(GetUpdates::new().limit(0).validate()), ("limit".to_string(), Rule::Range { min: 1, max: 100, actual: 0.0 }));
+    assert_eq!(
+        rule(SetWebhook::new("https://example.com").max_connections(101).validate()).1,
+        Rule::Range { min: 1, max: 100, actual: 101.0 }
+    );
+
+    let photo = || InputMedia::from(InputMediaPhoto::new("file_id"));
+    assert_eq!(
+        rule(SendMediaGroup::new(1, vec![photo()]).validate()),
+        ("media".to_string(), Rule::Items { min: 2, max: 10, actual: 1 })
+    );
+    let results = (0..51).map(|i| article(&i.to_string())).collect();
+    assert_eq!(rule(AnswerInlineQuery::new("q", results).validate()).1, Rule::Items { min: 0, max: 50, actual: 51 });
+}
+
+#[test]
+fn reports_paths_of_nested_fields() {
+    let long_caption = InputMediaPhoto::new("file_id").caption("x".repeat(1025));
+    let media = vec![InputMediaPhoto::new("file_id").into(), long_caption.into()];
+    let err = SendMediaGroup::new(1, media).validate().unwrap_err();
+    assert_eq!(err.path(), "media[1].caption");
+    assert_eq!(err.to_string(), "media[1].caption: must be 0-1024 characters, got 1025");
+
+    let button = InlineKeyboardButton::new("Press").callback_data("x".repeat(65));
+    let keyboard = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::new("Ok")], vec![button]]);
+    let result = InlineQueryResultArticle::new("1", "Title", InputTextMessageContent::new("Text")).reply_markup(keyboard);
+    let query = AnswerInlineQuery::new("q", vec![article("0"), result.into()]);
+    assert_eq!(rule(query.validate()).0, "results[1].reply_markup.inline_keyboard[1][0].callback_data");
+
+    let query = AnswerInlineQuery::new("q", vec![article("")]);
+    assert_eq!(rule(query.validate()), ("results[0].id".to_string(), Rule::Bytes { min: 1, max: 64, actual: 0 }));
+
+    let button = InlineQueryResultsButton::new("Start").start_parameter("not allowed");
+    let query = AnswerInlineQuery::new("q", Vec::new()).button(button);
+    assert_eq!(rule(query.validate()), ("button.start_parameter".to_string(), Rule::SafeCharacters));
+}
+
+#[test]
+fn runs_the_invoice_checks() {
+    let invoice = SendInvoice::new(1, "Title", "Description", "payload", "XTR", vec![LabeledPrice::new("Total", 0)]);
+    assert_eq!(
+        rule(invoice.validate()),
+        ("prices".to_string(), Rule::Invoice(InvoiceError::NonPositiveTotal(0)))
+    );
+    let invoice = SendInvoice::new(1, "Title", "Description", "payload", "XTR", vec![LabeledPrice::new("Total", 5)]);
+    assert_eq!(invoice.validate(), Ok(()));
+    assert_eq!(invoice.check_invoice(), Ok(()));
+}